@@ -1,11 +1,11 @@
 use clap::Parser;
 use flatgeobuf::{FgbReader, FgbWriter, GeometryType, HttpFgbReader};
-use geozero::csv::{CsvReader, CsvWriter};
 use geozero::error::{GeozeroError, Result};
-use geozero::geojson::{GeoJsonLineReader, GeoJsonReader, GeoJsonWriter};
-use geozero::svg::SvgWriter;
-use geozero::wkt::{WktReader, WktWriter};
-use geozero::{FeatureProcessor, GeozeroDatasource};
+use geozero::{
+    Bounds, BoundsProcessor, CancellationToken, DriverOptions, DriverRegistry, FeatureProcessor,
+    FilterProcessor, GeozeroDatasource, Progress, ProgressProcessor,
+};
+use log::info;
 use std::ffi::OsStr;
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
@@ -13,7 +13,7 @@ use std::num::ParseFloatError;
 use std::path::{Path, PathBuf};
 use std::process::exit;
 
-#[derive(Parser)]
+#[derive(Parser, Clone)]
 #[command(about, version)]
 struct Cli {
     /// When processing CSV, the name of the column holding a WKT geometry.
@@ -24,6 +24,10 @@ struct Cli {
     #[arg(short, long, value_parser = parse_extent)]
     extent: Option<Extent>,
 
+    /// Log progress every N features
+    #[arg(long)]
+    progress: Option<u64>,
+
     /// The path or URL to the FlatGeobuf file to read
     input: String,
 
@@ -73,22 +77,9 @@ async fn transform<P: FeatureProcessor>(args: Cli, processor: &mut P) -> Result<
         };
         ds.process_features(processor).await
     } else {
-        let mut filein = BufReader::new(File::open(path_in)?);
         match path_in.extension().and_then(OsStr::to_str) {
-            Some("csv") => {
-                let geometry_column_name = args
-                    .csv_geometry_column
-                    .expect("must specify --csv-geometry-column=<column name> when parsing CSV");
-                let mut ds = CsvReader::new(&geometry_column_name, &mut filein);
-                GeozeroDatasource::process(&mut ds, processor)
-            }
-            Some("json") | Some("geojson") => {
-                GeozeroDatasource::process(&mut GeoJsonReader(filein), processor)
-            }
-            Some("jsonl") | Some("geojsonl") => {
-                GeozeroDatasource::process(&mut GeoJsonLineReader::new(filein), processor)
-            }
             Some("fgb") => {
+                let mut filein = BufReader::new(File::open(path_in)?);
                 let ds = FgbReader::open(&mut filein).map_err(fgb_to_geozero_err)?;
                 let mut ds = if let Some(bbox) = &args.extent {
                     ds.select_bbox(bbox.minx, bbox.miny, bbox.maxx, bbox.maxy)
@@ -98,42 +89,99 @@ async fn transform<P: FeatureProcessor>(args: Cli, processor: &mut P) -> Result<
                 };
                 ds.process_features(processor)
             }
-            Some("wkt") => GeozeroDatasource::process(&mut WktReader(&mut filein), processor),
-            _ => panic!("Unknown input file extension"),
+            // Formats without spatial index are filtered while streaming
+            _ => {
+                let options = driver_options(&args);
+                let mut ds = DriverRegistry::new().open_path(path_in, &options)?;
+                match options.extent {
+                    Some(bbox) => {
+                        let mut filter = FilterProcessor::with_bbox(processor, bbox);
+                        GeozeroDatasource::process(&mut ds, &mut filter)
+                    }
+                    None => GeozeroDatasource::process(&mut ds, processor),
+                }
+            }
         }
     }
 }
 
-async fn process(args: Cli) -> Result<()> {
+fn driver_options(args: &Cli) -> DriverOptions {
+    DriverOptions {
+        geometry_column: args.csv_geometry_column.clone(),
+        extent: args.extent.map(|extent| Bounds {
+            min_x: extent.minx,
+            min_y: extent.miny,
+            max_x: extent.maxx,
+            max_y: extent.maxy,
+            z: None,
+            m: None,
+        }),
+    }
+}
+
+/// Wrap `processor` for progress logging and cancellation
+fn with_progress<P: FeatureProcessor>(
+    args: &Cli,
+    processor: P,
+    cancellation: &CancellationToken,
+) -> ProgressProcessor<P, impl FnMut(&Progress)> {
+    let log_progress = args.progress.is_some();
+    let mut processor = ProgressProcessor::new(processor, move |progress: &Progress| {
+        if log_progress {
+            match progress.fraction() {
+                Some(fraction) => info!(
+                    "{} features ({:.1}%), {:.0} features/s",
+                    progress.features,
+                    fraction * 100.0,
+                    progress.features_per_sec()
+                ),
+                None => info!(
+                    "{} features, {:.0} features/s",
+                    progress.features,
+                    progress.features_per_sec()
+                ),
+            }
+        }
+    });
+    processor.set_interval(args.progress.unwrap_or(u64::MAX));
+    processor.set_total_bytes(std::fs::metadata(&args.input).ok().map(|meta| meta.len()));
+    processor.set_cancellation_token(cancellation.clone());
+    processor
+}
+
+async fn process(args: Cli, cancellation: CancellationToken) -> Result<()> {
     let mut fout = BufWriter::new(File::create(&args.dest)?);
     match args.dest.extension().and_then(OsStr::to_str) {
-        Some("csv") => transform(args, &mut CsvWriter::new(&mut fout)).await?,
-        Some("wkt") => transform(args, &mut WktWriter::new(&mut fout)).await?,
-        Some("json") | Some("geojson") => {
-            transform(args, &mut GeoJsonWriter::new(&mut fout)).await?
-        }
         Some("fgb") => {
             let mut fgb =
                 FgbWriter::create("fgb", GeometryType::Unknown).map_err(fgb_to_geozero_err)?;
-            transform(args, &mut fgb).await?;
+            transform(
+                args.clone(),
+                &mut with_progress(&args, &mut fgb, &cancellation),
+            )
+            .await?;
             fgb.write(&mut fout).map_err(fgb_to_geozero_err)?;
         }
-        Some("svg") => {
-            let mut processor = SvgWriter::new(&mut fout, true);
-            set_dimensions(&mut processor, args.extent);
+        _ => {
+            let mut options = driver_options(&args);
+            if args.dest.extension().and_then(OsStr::to_str) == Some("svg")
+                && options.extent.is_none()
+            {
+                options.extent = data_extent(args.clone(), &cancellation).await?;
+            }
+            let processor = DriverRegistry::new().create_writer(&args.dest, &mut fout, &options)?;
+            let mut processor = with_progress(&args, processor, &cancellation);
             transform(args, &mut processor).await?;
         }
-        _ => panic!("Unknown output file extension"),
     }
     Ok(())
 }
-fn set_dimensions(processor: &mut SvgWriter<&mut BufWriter<File>>, extent: Option<Extent>) {
-    if let Some(extent) = extent {
-        processor.set_dimensions(extent.minx, extent.miny, extent.maxx, extent.maxy, 800, 600);
-    } else {
-        // TODO: get image size as opts and full extent from data
-        processor.set_dimensions(-180.0, -90.0, 180.0, 90.0, 800, 600);
-    }
+
+/// Full extent of the input data, read in a separate pass
+async fn data_extent(args: Cli, cancellation: &CancellationToken) -> Result<Option<Bounds>> {
+    let mut processor = with_progress(&args, BoundsProcessor::new(), cancellation);
+    transform(args, &mut processor).await?;
+    Ok(processor.into_inner().bounds())
 }
 
 fn fgb_to_geozero_err(fgb_err: flatgeobuf::Error) -> GeozeroError {
@@ -162,10 +210,30 @@ async fn main() {
 
     let args = Cli::parse();
 
-    let result = process(args).await;
+    // Stop processing cleanly on Ctrl-C, exit immediately on a second Ctrl-C,
+    // e.g. while waiting for a remote file or writing FlatGeobuf output
+    let cancellation = CancellationToken::new();
+    let token = cancellation.clone();
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            token.cancel();
+        }
+        if tokio::signal::ctrl_c().await.is_ok() {
+            exit(130);
+        }
+    });
+
+    let result = process(args, cancellation).await;
 
-    if let Err(msg) = result {
-        println!("Processing failed: {msg}");
-        exit(1)
+    match result {
+        Err(err) if err.is_cancelled() => {
+            println!("Processing cancelled");
+            exit(130)
+        }
+        Err(msg) => {
+            println!("Processing failed: {msg}");
+            exit(1)
+        }
+        Ok(()) => {}
     }
 }
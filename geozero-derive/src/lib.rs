@@ -0,0 +1,170 @@
+//! Derive macros for the [GeoZero](https://docs.rs/geozero) API.
+//!
+//! Use the macros via the `with-derive` feature of `geozero`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Field, Fields, LitStr};
+
+/// Derive `FeatureProperties` for a struct with named fields.
+///
+/// Fields are processed as properties in declaration order. Their types have to
+/// implement `PropertyWriteType`. A field marked with `#[geozero(geometry)]`
+/// additionally derives `GeozeroGeometry` and `FeatureAccess`.
+///
+/// Field attributes:
+/// * `#[geozero(geometry)]`: Feature geometry implementing `GeozeroGeometry`
+/// * `#[geozero(rename = "name")]`: Property name, if different from the field name
+/// * `#[geozero(skip)]`: Don't process field as property
+///
+/// # Usage example:
+///
+/// ```
+/// use geozero::geojson::GeoJsonWriter;
+/// use geozero::{GeozeroDatasource, GeozeroFeature};
+///
+/// #[derive(GeozeroFeature)]
+/// struct City {
+///     name: String,
+///     #[geozero(rename = "pop")]
+///     population: u64,
+///     #[geozero(geometry)]
+///     location: geo_types::Geometry<f64>,
+/// }
+///
+/// let mut cities = vec![City {
+///     name: "Bern".to_string(),
+///     population: 134794,
+///     location: geo_types::Point::new(7.44, 46.95).into(),
+/// }];
+/// let mut json: Vec<u8> = Vec::new();
+/// cities.process(&mut GeoJsonWriter::new(&mut json)).unwrap();
+/// assert!(std::str::from_utf8(&json)
+///     .unwrap()
+///     .contains(r#""properties": {"name": "Bern", "pop": 134794}"#));
+/// ```
+#[proc_macro_derive(GeozeroFeature, attributes(geozero))]
+pub fn derive_geozero_feature(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_geozero_feature(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    geometry: bool,
+    skip: bool,
+    rename: Option<LitStr>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut attrs = FieldAttrs::default();
+        for attr in &field.attrs {
+            if !attr.path().is_ident("geozero") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("geometry") {
+                    attrs.geometry = true;
+                } else if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                } else if meta.path.is_ident("rename") {
+                    attrs.rename = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unsupported geozero attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+fn expand_geozero_feature(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "GeozeroFeature can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "GeozeroFeature can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut geometry = None;
+    let mut properties = Vec::new();
+    for field in fields {
+        let attrs = FieldAttrs::parse(field)?;
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        if attrs.geometry {
+            if geometry.is_some() {
+                return Err(Error::new_spanned(
+                    field,
+                    "only one field can be marked as #[geozero(geometry)]",
+                ));
+            }
+            geometry = Some(field);
+        } else if !attrs.skip {
+            let name = attrs
+                .rename
+                .map_or_else(|| ident.unraw().to_string(), |name| name.value());
+            let idx = properties.len();
+            properties.push(quote_spanned! {field.ty.span()=>
+                if ::geozero::PropertyWriteType::process_property(&self.#ident, &mut *processor, #idx, #name)? {
+                    return ::core::result::Result::Ok(true);
+                }
+            });
+        }
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut expanded = quote! {
+        impl #impl_generics ::geozero::FeatureProperties for #name #ty_generics #where_clause {
+            fn process_properties<P: ::geozero::PropertyProcessor>(
+                &self,
+                processor: &mut P,
+            ) -> ::geozero::error::Result<bool> {
+                #(#properties)*
+                ::core::result::Result::Ok(false)
+            }
+        }
+    };
+    if let Some(field) = geometry {
+        let ident = &field.ident;
+        expanded.extend(quote_spanned! {field.ty.span()=>
+            impl #impl_generics ::geozero::GeozeroGeometry for #name #ty_generics #where_clause {
+                fn process_geom<P: ::geozero::GeomProcessor>(
+                    &self,
+                    processor: &mut P,
+                ) -> ::geozero::error::Result<()> {
+                    ::geozero::GeozeroGeometry::process_geom(&self.#ident, processor)
+                }
+                fn dims(&self) -> ::geozero::CoordDimensions {
+                    ::geozero::GeozeroGeometry::dims(&self.#ident)
+                }
+                fn srid(&self) -> ::core::option::Option<i32> {
+                    ::geozero::GeozeroGeometry::srid(&self.#ident)
+                }
+            }
+
+            impl #impl_generics ::geozero::FeatureAccess for #name #ty_generics #where_clause {}
+        });
+    }
+    Ok(expanded)
+}
@@ -0,0 +1,109 @@
+use geozero::csv::CsvWriter;
+use geozero::error::Result;
+use geozero::geojson::GeoJsonWriter;
+use geozero::{
+    FeatureAccess, FeatureProperties, GeozeroDatasource, GeozeroFeature, GeozeroGeometry,
+};
+use std::str::from_utf8;
+
+#[derive(GeozeroFeature)]
+struct Place {
+    name: String,
+    #[geozero(rename = "pop")]
+    population: u32,
+    capital: bool,
+    zip: Option<u16>,
+    r#type: &'static str,
+    #[geozero(skip)]
+    #[allow(dead_code)]
+    internal_id: u64,
+    #[geozero(geometry)]
+    location: geo_types::Geometry<f64>,
+}
+
+fn places() -> Vec<Place> {
+    vec![
+        Place {
+            name: "Bern".to_string(),
+            population: 134794,
+            capital: true,
+            zip: Some(3000),
+            r#type: "city",
+            internal_id: 1,
+            location: geo_types::Point::new(7.44, 46.95).into(),
+        },
+        Place {
+            name: "Guarda".to_string(),
+            population: 161,
+            capital: false,
+            zip: None,
+            r#type: "village",
+            internal_id: 2,
+            location: geo_types::Point::new(10.15, 46.77).into(),
+        },
+    ]
+}
+
+#[test]
+fn properties() -> Result<()> {
+    let places = places();
+    assert_eq!(places[0].property::<u32>("pop")?, 134794);
+    assert_eq!(places[0].property::<String>("type")?, "city");
+    assert_eq!(places[1].property::<Option<u16>>("zip")?, None);
+    let properties = places[1].properties()?;
+    assert_eq!(properties.len(), 4);
+    assert!(!properties.contains_key("internal_id"));
+    Ok(())
+}
+
+#[test]
+fn geometry() -> Result<()> {
+    let place = &places()[0];
+    let bbox = place.bbox()?.unwrap();
+    assert_eq!((bbox.min_x, bbox.min_y), (7.44, 46.95));
+    Ok(())
+}
+
+#[test]
+fn to_geojson() -> Result<()> {
+    let mut json: Vec<u8> = Vec::new();
+    places()[0].process(&mut GeoJsonWriter::new(&mut json), 0)?;
+    assert_eq!(
+        from_utf8(&json).unwrap(),
+        r#"{"type": "Feature", "properties": {"name": "Bern", "pop": 134794, "capital": true, "zip": 3000, "type": "city"}, "geometry": {"type": "Point", "coordinates": [7.44,46.95]}}"#
+    );
+    Ok(())
+}
+
+#[test]
+fn to_csv() -> Result<()> {
+    let mut places = places();
+    places[1].zip = Some(7545);
+    let mut csv: Vec<u8> = Vec::new();
+    places.process(&mut CsvWriter::new(&mut csv))?;
+    assert_eq!(
+        from_utf8(&csv).unwrap(),
+        "geometry,name,pop,capital,zip,type
+POINT(7.44 46.95),Bern,134794,true,3000,city
+POINT(10.15 46.77),Guarda,161,false,7545,village
+"
+    );
+    Ok(())
+}
+
+#[derive(GeozeroFeature)]
+struct Measurement<T: geozero::PropertyWriteType> {
+    value: T,
+    unit: String,
+}
+
+#[test]
+fn properties_only() -> Result<()> {
+    let measurement = Measurement {
+        value: 1.5f64,
+        unit: "m".to_string(),
+    };
+    assert_eq!(measurement.property::<f64>("value")?, 1.5);
+    assert_eq!(measurement.property_n::<String>(1)?, "m");
+    Ok(())
+}
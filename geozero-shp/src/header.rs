@@ -1,6 +1,7 @@
 use crate::point_z::BBoxZ;
 use crate::Error;
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use geozero::GeometryType;
 use std::fmt;
 use std::io::Read;
 
@@ -153,6 +154,23 @@ impl ShapeType {
         )
     }
 
+    /// Returns the geometry type of shapes with this ShapeType
+    pub fn geometry_type(self) -> GeometryType {
+        match self {
+            ShapeType::Point | ShapeType::PointZ | ShapeType::PointM => GeometryType::Point,
+            ShapeType::Multipoint | ShapeType::MultipointZ | ShapeType::MultipointM => {
+                GeometryType::MultiPoint
+            }
+            ShapeType::Polyline | ShapeType::PolylineZ | ShapeType::PolylineM => {
+                GeometryType::MultiLineString
+            }
+            ShapeType::Polygon | ShapeType::PolygonZ | ShapeType::PolygonM => {
+                GeometryType::MultiPolygon
+            }
+            ShapeType::NullShape | ShapeType::Multipatch => GeometryType::Unknown,
+        }
+    }
+
     /// Returns true if the shape may have multiple parts
     pub fn is_multipart(self) -> bool {
         !matches!(
@@ -45,6 +45,26 @@ pub enum Error {
     MissingDbf,
     #[error("Index file missing")]
     MissingIndexFile,
-    #[error("Geozero error")]
+    #[error("Geozero error `{0}`")]
     GeozeroError(#[from] geozero::error::GeozeroError),
 }
+
+impl From<Error> for geozero::error::GeozeroError {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::GeozeroError(e) => e,
+            Error::IoError(e) => Self::IoError(e),
+            e => Self::Dataset(e.to_string()),
+        }
+    }
+}
+
+impl Error {
+    /// Add location information to a wrapped geozero error
+    pub(crate) fn with_context(self, context: geozero::error::ErrorContext) -> Self {
+        match self {
+            Error::GeozeroError(e) => Error::GeozeroError(e.with_context(context)),
+            e => e,
+        }
+    }
+}
@@ -1,55 +1,116 @@
 use crate::reader::ShapeRecord;
-use dbase::FieldValue;
+use dbase::{FieldInfo, FieldType, FieldValue};
 use geozero::error::Result;
-use geozero::{ColumnValue, FeatureProperties, PropertyProcessor};
+use geozero::{ColumnInfo, ColumnType, ColumnValue, FeatureProperties, PropertyProcessor};
 
 impl FeatureProperties for ShapeRecord {
     /// Process feature properties.
     fn process_properties<P: PropertyProcessor>(&self, processor: &mut P) -> Result<bool> {
-        let mut i = 0;
-        for (name, value) in self.record.as_ref().iter() {
-            let finish = match value {
-                FieldValue::Character(Some(val)) => {
-                    processor.property(i, name, &ColumnValue::String(val))?
-                }
-                FieldValue::Numeric(Some(val)) => {
-                    processor.property(i, name, &ColumnValue::Double(*val))?
-                }
-                FieldValue::Logical(Some(val)) => {
-                    processor.property(i, name, &ColumnValue::Bool(*val))?
-                }
-                FieldValue::Date(Some(_)) => {
-                    let s = value.to_string();
-                    processor.property(i, name, &ColumnValue::DateTime(&s))?
-                }
-                FieldValue::Float(Some(val)) => {
-                    processor.property(i, name, &ColumnValue::Float(*val))?
-                }
-                FieldValue::Integer(val) => processor.property(i, name, &ColumnValue::Int(*val))?,
-                FieldValue::Double(val) => {
-                    processor.property(i, name, &ColumnValue::Double(*val))?
-                }
-                FieldValue::Currency(val) => {
-                    processor.property(i, name, &ColumnValue::Double(*val))?
-                }
-                FieldValue::DateTime(_) => {
-                    let s = value.to_string();
-                    processor.property(i, name, &ColumnValue::DateTime(&s))?
-                }
-                FieldValue::Memo(val) => processor.property(i, name, &ColumnValue::String(val))?,
-                FieldValue::Character(None)
-                | FieldValue::Numeric(None)
-                | FieldValue::Logical(None)
-                | FieldValue::Date(None)
-                | FieldValue::Float(None) => {
-                    continue; // Ignore NULL values
-                }
-            };
-            if finish {
+        for (i, (name, value)) in self.record.as_ref().iter().enumerate() {
+            if process_field(i, name, value, processor)? {
                 return Ok(true);
             }
-            i += 1;
         }
         Ok(false)
     }
 }
+
+/// Process feature properties in the order of the announced schema.
+pub(crate) fn process_schema_properties<P: PropertyProcessor>(
+    record: &ShapeRecord,
+    columns: &[ColumnInfo],
+    processor: &mut P,
+) -> Result<bool> {
+    for (i, column) in columns.iter().enumerate() {
+        let Some(value) = record.record.as_ref().get(&column.name) else {
+            continue;
+        };
+        if process_field(i, &column.name, value, processor)? {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Process a DBF field value. NULL values are processed as `ColumnValue::Null`.
+///
+/// With the `with-chrono` feature, dates are processed as `ColumnValue::Date` and
+/// date/times as `ColumnValue::Timestamp`.
+fn process_field<P: PropertyProcessor>(
+    i: usize,
+    name: &str,
+    value: &FieldValue,
+    processor: &mut P,
+) -> Result<bool> {
+    match value {
+        FieldValue::Character(Some(val)) => processor.property(i, name, &ColumnValue::String(val)),
+        FieldValue::Numeric(Some(val)) => processor.property(i, name, &ColumnValue::Double(*val)),
+        FieldValue::Logical(Some(val)) => processor.property(i, name, &ColumnValue::Bool(*val)),
+        FieldValue::Date(Some(_)) | FieldValue::DateTime(_) => {
+            if let Some(temporal) = temporal_value(value) {
+                processor.property(i, name, &temporal)
+            } else {
+                let s = value.to_string();
+                processor.property(i, name, &ColumnValue::DateTime(&s))
+            }
+        }
+        FieldValue::Float(Some(val)) => processor.property(i, name, &ColumnValue::Float(*val)),
+        FieldValue::Integer(val) => processor.property(i, name, &ColumnValue::Int(*val)),
+        FieldValue::Double(val) => processor.property(i, name, &ColumnValue::Double(*val)),
+        FieldValue::Currency(val) => processor.property(i, name, &ColumnValue::Double(*val)),
+        FieldValue::Memo(val) => processor.property(i, name, &ColumnValue::String(val)),
+        FieldValue::Character(None)
+        | FieldValue::Numeric(None)
+        | FieldValue::Logical(None)
+        | FieldValue::Date(None)
+        | FieldValue::Float(None) => processor.property(i, name, &ColumnValue::Null),
+    }
+}
+
+/// Typed value of a valid date or date/time field, `None` without the `with-chrono` feature.
+fn temporal_value(value: &FieldValue) -> Option<ColumnValue<'static>> {
+    match value {
+        #[cfg(feature = "with-chrono")]
+        FieldValue::Date(Some(date)) => naive_date(date).map(ColumnValue::Date),
+        #[cfg(feature = "with-chrono")]
+        FieldValue::DateTime(datetime) => naive_datetime(datetime).map(ColumnValue::Timestamp),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+fn naive_date(date: &dbase::Date) -> Option<chrono::NaiveDate> {
+    chrono::NaiveDate::from_ymd_opt(date.year() as i32, date.month(), date.day())
+}
+
+#[cfg(feature = "with-chrono")]
+fn naive_datetime(datetime: &dbase::DateTime) -> Option<chrono::NaiveDateTime> {
+    let time = datetime.time();
+    naive_date(&datetime.date())?.and_hms_opt(time.hours(), time.minutes(), time.seconds())
+}
+
+/// Column description of a DBF field.
+pub(crate) fn column_info(field: &FieldInfo) -> ColumnInfo {
+    let (column_type, nullable) = match field.field_type() {
+        FieldType::Character => (ColumnType::String, true),
+        FieldType::Numeric => (ColumnType::Double, true),
+        FieldType::Logical => (ColumnType::Bool, true),
+        #[cfg(feature = "with-chrono")]
+        FieldType::Date => (ColumnType::Date, true),
+        #[cfg(not(feature = "with-chrono"))]
+        FieldType::Date => (ColumnType::DateTime, true),
+        FieldType::Float => (ColumnType::Float, true),
+        FieldType::Integer => (ColumnType::Int, false),
+        FieldType::Double | FieldType::Currency => (ColumnType::Double, false),
+        #[cfg(feature = "with-chrono")]
+        FieldType::DateTime => (ColumnType::Timestamp, false),
+        #[cfg(not(feature = "with-chrono"))]
+        FieldType::DateTime => (ColumnType::DateTime, false),
+        FieldType::Memo => (ColumnType::String, false),
+    };
+    ColumnInfo {
+        name: field.name().to_string(),
+        column_type: Some(column_type),
+        nullable,
+    }
+}
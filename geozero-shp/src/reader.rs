@@ -1,12 +1,14 @@
+use crate::property_processor::{column_info, process_schema_properties};
 use crate::shp_reader::{read_shape, RecordHeader};
 use crate::shx_reader::{read_index_file, ShapeIndex};
 use crate::{header, Error};
 pub use dbase::{FieldInfo, FieldType};
-use geozero::{FeatureProcessor, FeatureProperties, GeomProcessor};
+use geozero::error::ErrorContext;
+use geozero::{ColumnInfo, FeatureProcessor, GeomProcessor, GeometryType, GeozeroDatasource};
 use std::fs::File;
-use std::io::{BufReader, Read, Seek};
+use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::iter::FusedIterator;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Struct that handle iteration over the shapes of a .shp file
 pub struct ShapeIterator<'a, P: GeomProcessor, T: Read> {
@@ -24,7 +26,10 @@ impl<'a, P: GeomProcessor, T: Read + 'a> Iterator for ShapeIterator<'a, P, T> {
             None
         } else {
             let hdr = match read_shape(self.processor, &mut self.source) {
-                Err(e) => return Some(Err(e)),
+                Err(e) => {
+                    let context = ErrorContext::new().with_byte_offset(self.current_pos as u64);
+                    return Some(Err(e.with_context(context)));
+                }
                 Ok(hdr_and_shape) => hdr_and_shape,
             };
             self.current_pos += RecordHeader::SIZE;
@@ -39,6 +44,8 @@ impl<'a, P: FeatureProcessor, T: Read + Seek + 'a> FusedIterator for ShapeIterat
 pub struct ShapeRecordIterator<'a, P: FeatureProcessor, T: Read + Seek> {
     shape_iter: ShapeIterator<'a, P, T>,
     dbf_reader: dbase::Reader<T>,
+    columns: Vec<ColumnInfo>,
+    geometry_type: GeometryType,
     featno: u64,
 }
 
@@ -52,6 +59,13 @@ impl<'a, P: FeatureProcessor, T: Read + Seek + 'a> Iterator for ShapeRecordItera
     fn next(&mut self) -> Option<Self::Item> {
         if self.featno == 0 {
             self.shape_iter.processor.dataset_begin(None).ok();
+            let processor = &mut self.shape_iter.processor;
+            if let Err(e) = processor.schema(&self.columns, self.geometry_type) {
+                return Some(Err(Error::GeozeroError(e)));
+            }
+        } else if self.shape_iter.processor.done() {
+            self.shape_iter.processor.dataset_end().ok();
+            return None;
         }
         let record = match self.dbf_reader.iter_records().next() {
             None => {
@@ -66,8 +80,11 @@ impl<'a, P: FeatureProcessor, T: Read + Seek + 'a> Iterator for ShapeRecordItera
         {
             self.shape_iter.processor.feature_begin(self.featno).ok();
             self.shape_iter.processor.properties_begin().ok();
-            if let Err(e) = shprec.process_properties(self.shape_iter.processor) {
-                return Some(Err(Error::GeozeroError(e)));
+            if let Err(e) =
+                process_schema_properties(&shprec, &self.columns, self.shape_iter.processor)
+            {
+                let context = ErrorContext::new().with_feature(self.featno);
+                return Some(Err(Error::GeozeroError(e.with_context(context))));
             }
             self.shape_iter.processor.properties_end().ok();
 
@@ -75,12 +92,19 @@ impl<'a, P: FeatureProcessor, T: Read + Seek + 'a> Iterator for ShapeRecordItera
         }
 
         if let Err(e) = self.shape_iter.next()? {
-            return Some(Err(e));
+            let context = ErrorContext::new().with_feature(self.featno);
+            return Some(Err(e.with_context(context)));
         }
 
         {
+            let bytes = self.shape_iter.current_pos as u64;
+            let total = self.shape_iter.file_length as u64;
             let processor = &mut self.shape_iter.processor;
             processor.geometry_end().ok();
+            if let Err(e) = processor.bytes_read(bytes, Some(total)) {
+                let context = ErrorContext::new().with_feature(self.featno);
+                return Some(Err(Error::GeozeroError(e.with_context(context))));
+            }
             processor.feature_end(self.featno).ok();
         }
         self.featno += 1;
@@ -164,10 +188,19 @@ impl<T: Read + Seek> Reader<T> {
     ) -> Result<ShapeRecordIterator<P, T>, Error> {
         let maybe_dbf_reader = self.dbf_reader.take();
         if let Some(dbf_reader) = maybe_dbf_reader {
+            let columns = dbf_reader
+                .fields()
+                .iter()
+                .filter(|f| f.name() != "DeletionFlag")
+                .map(column_info)
+                .collect();
+            let geometry_type = self.header.shape_type.geometry_type();
             let shape_iter = self.iter_geometries(processor);
             Ok(ShapeRecordIterator {
                 shape_iter,
                 dbf_reader,
+                columns,
+                geometry_type,
                 featno: 0,
             })
         } else {
@@ -217,6 +250,116 @@ impl Reader<BufReader<File>> {
         }
         Ok(reader)
     }
+
+    /// Split the features of a shapefile into batches of `size` records, using the .shx index
+    ///
+    /// Each batch is read with its own file handles, e.g. for parallel processing.
+    ///
+    /// # Errors
+    ///
+    /// The `Result` will be an error if the .shx or .dbf wasn't found
+    pub fn batches_from_path<P: AsRef<Path>>(
+        path: P,
+        size: usize,
+    ) -> Result<ShapeRecordBatches, Error> {
+        let shape_path = path.as_ref().to_path_buf();
+        let shx_path = shape_path.with_extension("shx");
+        if !shx_path.exists() {
+            return Err(Error::MissingIndexFile);
+        }
+        if !shape_path.with_extension("dbf").exists() {
+            return Err(Error::MissingDbf);
+        }
+        let shapes_index = read_index_file(BufReader::new(File::open(shx_path)?))?;
+        Ok(ShapeRecordBatches {
+            path: shape_path,
+            shapes_index,
+            size: size.max(1),
+            next_record: 0,
+        })
+    }
+}
+
+/// Iterator over batches of shapefile records
+pub struct ShapeRecordBatches {
+    path: PathBuf,
+    shapes_index: Vec<ShapeIndex>,
+    size: usize,
+    next_record: usize,
+}
+
+impl Iterator for ShapeRecordBatches {
+    type Item = geozero::error::Result<ShapeRecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first_record = self.next_record;
+        let index = self.shapes_index.get(first_record)?;
+        let len = self.size.min(self.shapes_index.len() - first_record);
+        self.next_record += len;
+        Some(Ok(ShapeRecordBatch {
+            path: self.path.clone(),
+            first_record,
+            offset: index.offset as u64 * 2,
+            len,
+        }))
+    }
+}
+
+impl FusedIterator for ShapeRecordBatches {}
+
+/// Consecutive shapefile records
+///
+/// Features are processed with their index in the whole file.
+pub struct ShapeRecordBatch {
+    path: PathBuf,
+    first_record: usize,
+    /// Byte offset of the first record in the .shp file
+    offset: u64,
+    len: usize,
+}
+
+impl ShapeRecordBatch {
+    fn read<P: FeatureProcessor>(&self, processor: &mut P) -> Result<(), Error> {
+        let mut source = BufReader::new(File::open(&self.path)?);
+        let header = header::Header::read_from(&mut source)?;
+        source.seek(SeekFrom::Start(self.offset))?;
+        let mut dbf_reader = dbase::Reader::from_path(self.path.with_extension("dbf"))?;
+        let columns: Vec<ColumnInfo> = dbf_reader
+            .fields()
+            .iter()
+            .filter(|f| f.name() != "DeletionFlag")
+            .map(column_info)
+            .collect();
+        dbf_reader.seek(self.first_record)?;
+
+        processor.dataset_begin(None)?;
+        processor.schema(&columns, header.shape_type.geometry_type())?;
+        let records = dbf_reader.iter_records().take(self.len);
+        for (featno, record) in (self.first_record as u64..).zip(records) {
+            let context = || ErrorContext::new().with_feature(featno);
+            let shprec = ShapeRecord { record: record? };
+            processor.feature_begin(featno)?;
+            processor.properties_begin()?;
+            process_schema_properties(&shprec, &columns, processor)
+                .map_err(|e| e.with_context(context()))?;
+            processor.properties_end()?;
+            processor.geometry_begin()?;
+            read_shape(processor, &mut source).map_err(|e| e.with_context(context()))?;
+            processor.geometry_end()?;
+            processor.feature_end(featno)?;
+            if processor.done() {
+                break;
+            }
+        }
+        processor.dataset_end()?;
+        Ok(())
+    }
+}
+
+impl GeozeroDatasource for ShapeRecordBatch {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> geozero::error::Result<()> {
+        self.read(processor).map_err(Into::into)
+    }
 }
 
 // Does not work, because iter_features requires P instead of &mut P
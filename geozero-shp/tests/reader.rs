@@ -1,7 +1,10 @@
 use dbase::FieldValue;
 use geozero::geojson::GeoJsonWriter;
 use geozero::wkt::WktWriter;
-use geozero::{CoordDimensions, FeatureProperties, ProcessorSink};
+use geozero::{
+    ColumnInfo, ColumnType, CoordDimensions, FeatureProcessor, FeatureProperties, FeatureRecorder,
+    GeomProcessor, GeometryType, ProcessorSink, PropertyProcessor, RecordedFeature,
+};
 use std::fs::File;
 use std::io::BufReader;
 use std::str::from_utf8;
@@ -163,6 +166,66 @@ fn property_file() -> Result<(), geozero_shp::Error> {
     Ok(())
 }
 
+#[test]
+fn schema() -> Result<(), geozero_shp::Error> {
+    struct SchemaReader(Vec<ColumnInfo>, GeometryType);
+    impl FeatureProcessor for SchemaReader {
+        fn schema(
+            &mut self,
+            columns: &[ColumnInfo],
+            geometry_type: GeometryType,
+        ) -> geozero::error::Result<()> {
+            self.0 = columns.to_vec();
+            self.1 = geometry_type;
+            Ok(())
+        }
+    }
+    impl GeomProcessor for SchemaReader {}
+    impl PropertyProcessor for SchemaReader {}
+
+    let reader = geozero_shp::Reader::from_path("./tests/data/poly.shp")?;
+    let mut processor = SchemaReader(Vec::new(), GeometryType::Unknown);
+    let cnt = reader.iter_features(&mut processor)?.count();
+    assert_eq!(cnt, 10);
+    assert_eq!(processor.1, GeometryType::MultiPolygon);
+    let columns: Vec<_> = processor
+        .0
+        .iter()
+        .map(|c| (c.name.as_str(), c.column_type.unwrap()))
+        .collect();
+    assert_eq!(
+        columns,
+        [
+            ("AREA", ColumnType::Double),
+            ("EAS_ID", ColumnType::Double),
+            ("PRFEDEA", ColumnType::String)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn batches() -> Result<(), geozero_shp::Error> {
+    let reader = geozero_shp::Reader::from_path("./tests/data/poly.shp")?;
+    let mut recorder = FeatureRecorder::new();
+    let cnt = reader.iter_features(&mut recorder)?.count();
+    assert_eq!(cnt, 10);
+
+    let batches = geozero_shp::Reader::batches_from_path("./tests/data/poly.shp", 4)?;
+    let mut features = Vec::new();
+    let mut batch_cnt = 0;
+    for batch in batches {
+        features.extend(RecordedFeature::record_all(&mut batch?)?);
+        batch_cnt += 1;
+    }
+    assert_eq!(batch_cnt, 3);
+    assert_eq!(features, recorder.into_features());
+
+    let batches = geozero_shp::Reader::batches_from_path("./tests/data/point.shp", 4);
+    assert!(matches!(batches, Err(geozero_shp::Error::MissingDbf)));
+    Ok(())
+}
+
 #[test]
 fn point() -> Result<(), geozero_shp::Error> {
     let reader = geozero_shp::Reader::from_path("./tests/data/point.shp")?;
@@ -4,12 +4,18 @@
 //! The current feature can be processed with `FeatureAccess` processing API methods.
 //! Some datasources process features during consumption (e.g. reading from file).
 
-use crate::error::{GeozeroError, Result};
+use crate::bounds::{Bounds, BoundsProcessor};
+#[cfg(feature = "with-serde")]
+use crate::deserialize::{DeserializeProcessor, PropertyCollector};
+use crate::error::Result;
 use crate::feature_processor::FeatureProcessor;
 use crate::property_processor::{
     PropertyProcessor, PropertyReadType, PropertyReader, PropertyReaderIdx,
 };
+use crate::summary::{DatasetSummary, SummaryProcessor};
 use crate::{CoordDimensions, GeomProcessor};
+#[cfg(feature = "with-serde")]
+use serde::de::DeserializeOwned;
 use std::collections::HashMap;
 
 /// Geometry processing trait.
@@ -26,6 +32,15 @@ pub trait GeozeroGeometry {
     fn srid(&self) -> Option<i32> {
         None
     }
+    /// Bounding box of geometry, `None` if the geometry is empty
+    fn bbox(&self) -> Result<Option<Bounds>>
+    where
+        Self: Sized,
+    {
+        let mut processor = BoundsProcessor::new();
+        self.process_geom(&mut processor)?;
+        Ok(processor.bounds())
+    }
 }
 
 /// Datasource feature consumer trait.
@@ -37,6 +52,96 @@ pub trait GeozeroDatasource {
         let mut geom_processor = DatasourceGeomProcessor(processor);
         self.process(&mut geom_processor)
     }
+    /// Consume all selected features and return the extent of their geometries.
+    ///
+    /// Returns `None` if no feature has a non-empty geometry.
+    fn extent(&mut self) -> Result<Option<Bounds>> {
+        let mut processor = BoundsProcessor::new();
+        self.process(&mut processor)?;
+        Ok(processor.bounds())
+    }
+    /// Consume all selected features and return dataset statistics.
+    fn summary(&mut self) -> Result<DatasetSummary> {
+        let mut processor = SummaryProcessor::new();
+        self.process(&mut processor)?;
+        Ok(processor.summary())
+    }
+    /// Consume all selected features and deserialize their properties into `T`.
+    ///
+    /// See [`FeatureProperties::properties_as`] for the supported conversions.
+    #[cfg(feature = "with-serde")]
+    fn deserialize_properties<T: DeserializeOwned>(&mut self) -> Result<Vec<T>> {
+        let mut processor = DeserializeProcessor::new();
+        self.process(&mut processor)?;
+        Ok(processor.into_features())
+    }
+}
+
+/// Object-safe variant of [`GeozeroDatasource`].
+///
+/// Implemented for all datasources, allowing them to be boxed as `Box<dyn DynDatasource>`.
+pub trait DynDatasource {
+    /// Consume and process all selected features.
+    fn process_dyn(&mut self, processor: &mut dyn FeatureProcessor) -> Result<()>;
+}
+
+impl<T: GeozeroDatasource> DynDatasource for T {
+    fn process_dyn(&mut self, mut processor: &mut dyn FeatureProcessor) -> Result<()> {
+        self.process(&mut processor)
+    }
+}
+
+impl GeozeroDatasource for Box<dyn DynDatasource + '_> {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        (**self).process_dyn(processor)
+    }
+}
+
+/// Process a slice of features as dataset, e.g. a `Vec` of `#[derive(GeozeroFeature)]` structs.
+impl<T: FeatureAccess> GeozeroDatasource for [T] {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        processor.dataset_begin(None)?;
+        for (idx, feature) in self.iter().enumerate() {
+            feature.process(processor, idx as u64)?;
+            if processor.done() {
+                break;
+            }
+        }
+        processor.dataset_end()
+    }
+}
+
+impl<T: FeatureAccess> GeozeroDatasource for Vec<T> {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        self.as_mut_slice().process(processor)
+    }
+}
+
+/// Pull-based feature access.
+///
+/// In contrast to [`GeozeroDatasource`], which pushes all features into a processor,
+/// features are read one at a time. Depending on the datasource, features are owned
+/// or borrow from the iterator until the next call of `next_feature`.
+pub trait FeatureIterator {
+    /// Feature type returned by `next_feature`.
+    type Feature<'a>: FeatureAccess
+    where
+        Self: 'a;
+    /// Read the next feature, `None` if all features are consumed.
+    fn next_feature(&mut self) -> Result<Option<Self::Feature<'_>>>;
+    /// Consume and process all remaining features.
+    fn process_features<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        processor.dataset_begin(None)?;
+        let mut idx = 0;
+        while let Some(feature) = self.next_feature()? {
+            feature.process(processor, idx)?;
+            if processor.done() {
+                break;
+            }
+            idx += 1;
+        }
+        processor.dataset_end()
+    }
 }
 
 /// Feature processing API
@@ -64,11 +169,9 @@ pub trait FeatureProperties {
     /// Get property value by name
     ///
     /// An error `ColumnNotFound` can be interpreted as Null value.
+    /// Use `Option<T>` to get `None` for missing properties.
     fn property<T: PropertyReadType>(&self, name: &str) -> Result<T> {
-        let mut reader = PropertyReader {
-            name,
-            value: Err(GeozeroError::ColumnNotFound),
-        };
+        let mut reader = PropertyReader::new(name);
         self.process_properties(&mut reader)?;
         reader.value
     }
@@ -76,10 +179,7 @@ pub trait FeatureProperties {
     ///
     /// An error `ColumnNotFound` can be interpreted as Null value.
     fn property_n<T: PropertyReadType>(&self, n: usize) -> Result<T> {
-        let mut reader = PropertyReaderIdx {
-            idx: n,
-            value: Err(GeozeroError::ColumnNotFound),
-        };
+        let mut reader = PropertyReaderIdx::new(n);
         self.process_properties(&mut reader)?;
         reader.value
     }
@@ -91,6 +191,16 @@ pub trait FeatureProperties {
         let _ = self.process_properties(&mut properties)?;
         Ok(properties)
     }
+    /// Deserialize all properties into `T`, e.g. a struct deriving `serde::Deserialize`
+    ///
+    /// Property values are converted like in [`FeatureProperties::property`].
+    /// `Json` values are deserialized into nested types.
+    #[cfg(feature = "with-serde")]
+    fn properties_as<T: DeserializeOwned>(&self) -> Result<T> {
+        let mut collector = PropertyCollector::default();
+        let _ = self.process_properties(&mut collector)?;
+        collector.deserialize()
+    }
 }
 
 // Newtype for GeomProcessor impl for adding no-op PropertyProcessor/FeatureProcessor impl
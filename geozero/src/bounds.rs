@@ -0,0 +1,224 @@
+use crate::error::Result;
+use crate::{CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+
+/// Bounding box with optional Z and M ranges.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
+pub struct Bounds {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+    /// Z range, `None` if no coordinate had a Z value
+    pub z: Option<(f64, f64)>,
+    /// M range, `None` if no coordinate had a M value
+    pub m: Option<(f64, f64)>,
+}
+
+impl Bounds {
+    /// Bounds of a single coordinate
+    pub fn from_coord(x: f64, y: f64, z: Option<f64>, m: Option<f64>) -> Self {
+        Bounds {
+            min_x: x,
+            min_y: y,
+            max_x: x,
+            max_y: y,
+            z: z.map(|z| (z, z)),
+            m: m.map(|m| (m, m)),
+        }
+    }
+
+    /// Extend bounds to include a coordinate
+    pub fn extend(&mut self, x: f64, y: f64, z: Option<f64>, m: Option<f64>) {
+        self.merge(&Bounds::from_coord(x, y, z, m));
+    }
+
+    /// Extend bounds to include other bounds
+    pub fn merge(&mut self, other: &Bounds) {
+        self.min_x = self.min_x.min(other.min_x);
+        self.min_y = self.min_y.min(other.min_y);
+        self.max_x = self.max_x.max(other.max_x);
+        self.max_y = self.max_y.max(other.max_y);
+        self.z = merge_range(self.z, other.z);
+        self.m = merge_range(self.m, other.m);
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max_x - self.min_x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max_y - self.min_y
+    }
+
+    /// Check whether the 2D bounds share at least one point
+    pub fn intersects(&self, other: &Bounds) -> bool {
+        self.min_x <= other.max_x
+            && self.max_x >= other.min_x
+            && self.min_y <= other.max_y
+            && self.max_y >= other.min_y
+    }
+
+    /// Dimensions with available ranges
+    pub fn dims(&self) -> CoordDimensions {
+        CoordDimensions {
+            z: self.z.is_some(),
+            m: self.m.is_some(),
+            t: false,
+            tm: false,
+        }
+    }
+}
+
+fn merge_range(a: Option<(f64, f64)>, b: Option<(f64, f64)>) -> Option<(f64, f64)> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some((a.0.min(b.0), a.1.max(b.1))),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// Processor computing the bounds of processed geometries.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::{BoundsProcessor, GeozeroGeometry};
+/// use geozero::wkt::Wkt;
+///
+/// let mut processor = BoundsProcessor::new();
+/// Wkt("LINESTRING(1 5,3 2)").process_geom(&mut processor).unwrap();
+/// let bounds = processor.bounds().unwrap();
+/// assert_eq!((bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y), (1.0, 2.0, 3.0, 5.0));
+/// # }
+/// ```
+#[derive(Default, Debug)]
+pub struct BoundsProcessor {
+    bounds: Option<Bounds>,
+}
+
+impl BoundsProcessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bounds of all processed coordinates, `None` if no coordinate was processed
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Clear collected bounds
+    pub fn reset(&mut self) {
+        self.bounds = None;
+    }
+
+    fn add(&mut self, x: f64, y: f64, z: Option<f64>, m: Option<f64>) {
+        // Ignore empty points encoded as NaN (e.g. in WKB)
+        if x.is_nan() || y.is_nan() {
+            return;
+        }
+        let z = z.filter(|z| !z.is_nan());
+        let m = m.filter(|m| !m.is_nan());
+        match &mut self.bounds {
+            Some(bounds) => bounds.extend(x, y, z, m),
+            None => self.bounds = Some(Bounds::from_coord(x, y, z, m)),
+        }
+    }
+}
+
+impl GeomProcessor for BoundsProcessor {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions::xyzm()
+    }
+    fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+        self.add(x, y, None, None);
+        Ok(())
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        _t: Option<f64>,
+        _tm: Option<u64>,
+        _idx: usize,
+    ) -> Result<()> {
+        self.add(x, y, z, m);
+        Ok(())
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for BoundsProcessor {}
+
+impl FeatureProcessor for BoundsProcessor {}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{Wkt, WktReader};
+    use crate::{GeozeroDatasource, GeozeroGeometry};
+
+    #[test]
+    fn bbox_2d() {
+        let bounds = Wkt("POLYGON((0 0,10 0,10 -5,0 0),(1 -1,2 -1,2 -2,1 -1))")
+            .bbox()
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            bounds,
+            Bounds {
+                min_x: 0.0,
+                min_y: -5.0,
+                max_x: 10.0,
+                max_y: 0.0,
+                z: None,
+                m: None,
+            }
+        );
+        assert!(!bounds.dims().z && !bounds.dims().m);
+    }
+
+    #[test]
+    fn bounds_zm() -> Result<()> {
+        let mut processor = BoundsProcessor::new();
+        processor.linestring_begin(true, 2, 0)?;
+        processor.coordinate(1.0, 2.0, Some(3.0), Some(4.0), None, None, 0)?;
+        processor.coordinate(5.0, 6.0, Some(-7.0), None, None, None, 1)?;
+        processor.linestring_end(true, 0)?;
+        let bounds = processor.bounds().unwrap();
+        assert_eq!((bounds.min_x, bounds.max_y), (1.0, 6.0));
+        assert_eq!(bounds.z, Some((-7.0, 3.0)));
+        assert_eq!(bounds.m, Some((4.0, 4.0)));
+
+        processor.reset();
+        assert_eq!(processor.bounds(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn bbox_empty() {
+        assert_eq!(Wkt("LINESTRING EMPTY").bbox().unwrap(), None);
+        assert_eq!(Wkt("POINT EMPTY").bbox().unwrap(), None);
+    }
+
+    #[test]
+    fn datasource_extent() {
+        let mut ds = WktReader(
+            "GEOMETRYCOLLECTION(POINT(1 3),POLYGON((21 21,22 21,21 22,21 21)))".as_bytes(),
+        );
+        let bounds = ds.extent().unwrap().unwrap();
+        assert_eq!(
+            (bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y),
+            (1.0, 3.0, 22.0, 22.0)
+        );
+        assert!(bounds.intersects(&Bounds::from_coord(10.0, 10.0, None, None)));
+        assert!(!bounds.intersects(&Bounds::from_coord(0.0, 10.0, None, None)));
+    }
+}
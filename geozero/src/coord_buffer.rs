@@ -0,0 +1,61 @@
+//! Buffered coordinates of processors re-emitting modified lines.
+use crate::error::Result;
+use crate::geometry_processor::GeomProcessor;
+
+/// Buffered `xy` or `coordinate` event
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Coord {
+    Xy(f64, f64),
+    Coordinate {
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+    },
+}
+
+impl Coord {
+    pub(crate) fn xy(&self) -> (f64, f64) {
+        match *self {
+            Coord::Xy(x, y) | Coord::Coordinate { x, y, .. } => (x, y),
+        }
+    }
+
+    /// Emit as `xy` or `coordinate` event
+    pub(crate) fn process<P: GeomProcessor>(&self, processor: &mut P, idx: usize) -> Result<()> {
+        match *self {
+            Coord::Xy(x, y) => processor.xy(x, y, idx),
+            Coord::Coordinate { x, y, z, m, t, tm } => processor.coordinate(x, y, z, m, t, tm, idx),
+        }
+    }
+}
+
+/// Emit buffered coordinates as LineString
+pub(crate) fn process_linestring<P: GeomProcessor>(
+    processor: &mut P,
+    coords: &[Coord],
+    tagged: bool,
+    idx: usize,
+) -> Result<()> {
+    processor.linestring_begin(tagged, coords.len(), idx)?;
+    for (i, coord) in coords.iter().enumerate() {
+        coord.process(processor, i)?;
+    }
+    processor.linestring_end(tagged, idx)
+}
+
+/// Emit buffered rings as Polygon
+pub(crate) fn process_polygon<P: GeomProcessor>(
+    processor: &mut P,
+    rings: &[Vec<Coord>],
+    tagged: bool,
+    idx: usize,
+) -> Result<()> {
+    processor.polygon_begin(tagged, rings.len(), idx)?;
+    for (i, ring) in rings.iter().enumerate() {
+        process_linestring(processor, ring, false, i)?;
+    }
+    processor.polygon_end(tagged, idx)
+}
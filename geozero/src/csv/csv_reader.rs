@@ -1,8 +1,13 @@
-use crate::error::{GeozeroError, Result};
-use crate::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry};
+use crate::error::{ErrorContext, GeozeroError, Result, ResultExt};
+use crate::{
+    ColumnInfo, ColumnType, ColumnValue, FeatureAccess, FeatureIterator, FeatureProcessor,
+    FeatureProperties, GeomProcessor, GeometryType, GeozeroDatasource, GeozeroGeometry,
+    PropertyProcessor,
+};
 
 use std::io::Read;
 use std::str::FromStr;
+use std::sync::Arc;
 
 pub struct Csv<'a> {
     csv_text: &'a str,
@@ -86,6 +91,170 @@ impl<R: Read> CsvReader<R> {
     }
 }
 
+impl<R: Read> CsvReader<R> {
+    /// Iterate over the records, reading one record at a time.
+    pub fn feature_iter(self) -> Result<CsvFeatureIter<R>> {
+        let mut reader = csv::Reader::from_reader(self.inner);
+        let (headers, geometry_idx) = read_headers(&mut reader, &self.geometry_column_name)?;
+        Ok(CsvFeatureIter {
+            reader,
+            headers,
+            geometry_idx,
+            record: csv::StringRecord::new(),
+        })
+    }
+
+    /// Split the records into batches of `size` records, which can be processed
+    /// independently, e.g. in parallel.
+    pub fn batches(self, size: usize) -> Result<CsvBatches<R>> {
+        let mut reader = csv::Reader::from_reader(self.inner);
+        let (headers, geometry_idx) = read_headers(&mut reader, &self.geometry_column_name)?;
+        Ok(CsvBatches {
+            reader,
+            headers: Arc::new(headers),
+            geometry_idx,
+            size: size.max(1),
+            next_idx: 0,
+        })
+    }
+}
+
+/// Iterator over batches of CSV records.
+pub struct CsvBatches<R: Read> {
+    reader: csv::Reader<R>,
+    headers: Arc<csv::StringRecord>,
+    geometry_idx: usize,
+    size: usize,
+    next_idx: usize,
+}
+
+impl<R: Read> Iterator for CsvBatches<R> {
+    type Item = Result<CsvBatch>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut records = Vec::with_capacity(self.size);
+        for record in self.reader.records().take(self.size) {
+            match record {
+                Ok(record) => records.push(record),
+                Err(e) => {
+                    let feature_idx = (self.next_idx + records.len()) as u64;
+                    let context = ErrorContext::new().with_feature(feature_idx);
+                    return Some(Err(GeozeroError::from(e).with_context(context)));
+                }
+            }
+        }
+        if records.is_empty() {
+            return None;
+        }
+        let first_idx = self.next_idx;
+        self.next_idx += records.len();
+        Some(Ok(CsvBatch {
+            headers: self.headers.clone(),
+            records,
+            geometry_idx: self.geometry_idx,
+            first_idx,
+        }))
+    }
+}
+
+/// Batch of CSV records.
+///
+/// Features are processed with their index in the whole input.
+pub struct CsvBatch {
+    headers: Arc<csv::StringRecord>,
+    records: Vec<csv::StringRecord>,
+    geometry_idx: usize,
+    first_idx: usize,
+}
+
+impl GeozeroDatasource for CsvBatch {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        processor.dataset_begin(None)?;
+        let columns = csv_columns(&self.headers, self.geometry_idx);
+        processor.schema(&columns, GeometryType::Unknown)?;
+        for (feature_idx, record) in (self.first_idx..).zip(&self.records) {
+            process_csv_record(
+                record,
+                feature_idx,
+                &self.headers,
+                self.geometry_idx,
+                processor,
+            )
+            .with_context(|| record_context(feature_idx, record))?;
+            if processor.done() {
+                break;
+            }
+        }
+        processor.dataset_end()
+    }
+}
+
+/// Iterator over CSV records.
+///
+/// The returned features borrow the record buffer of the iterator.
+pub struct CsvFeatureIter<R: Read> {
+    reader: csv::Reader<R>,
+    headers: csv::StringRecord,
+    geometry_idx: usize,
+    record: csv::StringRecord,
+}
+
+impl<R: Read> FeatureIterator for CsvFeatureIter<R> {
+    type Feature<'a>
+        = CsvFeature<'a>
+    where
+        Self: 'a;
+    fn next_feature(&mut self) -> Result<Option<CsvFeature<'_>>> {
+        if !self.reader.read_record(&mut self.record)? {
+            return Ok(None);
+        }
+        Ok(Some(CsvFeature {
+            headers: &self.headers,
+            record: &self.record,
+            geometry_idx: self.geometry_idx,
+        }))
+    }
+}
+
+/// CSV record with a WKT geometry column.
+pub struct CsvFeature<'a> {
+    headers: &'a csv::StringRecord,
+    record: &'a csv::StringRecord,
+    geometry_idx: usize,
+}
+
+impl GeozeroGeometry for CsvFeature<'_> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        let geometry_field = self
+            .record
+            .get(self.geometry_idx)
+            .ok_or(GeozeroError::ColumnNotFound)?;
+        if geometry_field.is_empty() {
+            return Ok(());
+        }
+        crate::wkt::wkt_reader::read_wkt(&mut geometry_field.as_bytes(), processor)
+    }
+}
+
+impl FeatureProperties for CsvFeature<'_> {
+    fn process_properties<P: PropertyProcessor>(&self, processor: &mut P) -> Result<bool> {
+        let properties_iter = self
+            .headers
+            .iter()
+            .zip(self.record.iter())
+            .enumerate()
+            .filter(|(input_idx, _)| *input_idx != self.geometry_idx)
+            .map(|(_input_idx, (header, value))| (header, value));
+        for (output_idx, (header, field)) in properties_iter.enumerate() {
+            if processor.property(output_idx, header, &ColumnValue::String(field))? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl FeatureAccess for CsvFeature<'_> {}
+
 impl<R: Read> GeozeroDatasource for CsvReader<R> {
     fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
         process_csv_features(&mut self.inner, processor, &self.geometry_column_name)
@@ -98,28 +267,41 @@ impl<R: Read + Clone> GeozeroGeometry for CsvReader<R> {
     }
 }
 
-pub fn process_csv_geom(
-    input: impl Read,
-    processor: &mut impl GeomProcessor,
+/// Read the header record and find the index of the geometry column.
+fn read_headers<R: Read>(
+    reader: &mut csv::Reader<R>,
     geometry_column: &str,
-) -> Result<()> {
-    let mut reader = csv::Reader::from_reader(input);
+) -> Result<(csv::StringRecord, usize)> {
     let headers = reader.headers()?.clone();
-
     let geometry_idx = headers
         .iter()
         .position(|f| f == geometry_column)
         .ok_or(GeozeroError::ColumnNotFound)?;
+    Ok((headers, geometry_idx))
+}
+
+pub fn process_csv_geom(
+    input: impl Read,
+    processor: &mut impl GeomProcessor,
+    geometry_column: &str,
+) -> Result<()> {
+    let mut reader = csv::Reader::from_reader(input);
+    let (_, geometry_idx) = read_headers(&mut reader, geometry_column)?;
 
     let mut collection_started = false;
 
     for (record_idx, record) in reader.into_records().enumerate() {
-        let record = record?;
+        let record = record
+            .map_err(GeozeroError::from)
+            .with_context(|| ErrorContext::new().with_feature(record_idx as u64))?;
+        let context = || record_context(record_idx, &record);
         let geometry_field = record
             .get(geometry_idx)
-            .ok_or(GeozeroError::ColumnNotFound)?;
+            .ok_or(GeozeroError::ColumnNotFound)
+            .with_context(context)?;
         let wkt = wkt::Wkt::from_str(geometry_field)
-            .map_err(|e| GeozeroError::Geometry(e.to_string()))?;
+            .map_err(|e| GeozeroError::Geometry(e.to_string()))
+            .with_context(context)?;
 
         // We don't know how many lines are in the file, so we dont' know the size of the geometry collection,
         // but at this point we *do* know that it's non-zero. Currently there aren't any other significant
@@ -132,14 +314,8 @@ pub fn process_csv_geom(
             processor.geometrycollection_begin(1, 0)?;
         }
 
-        crate::wkt::wkt_reader::process_wkt_geom_n(&wkt.item, record_idx, processor).map_err(
-            |e| {
-                // +2 to start at line 1 and to account for the header row
-                let line = record_idx + 2;
-                log::warn!("line {line}: invalid WKT: '{geometry_field}', record: {record:?}");
-                e
-            },
-        )?;
+        crate::wkt::wkt_reader::process_wkt_geom_n(&wkt.item, record_idx, processor)
+            .with_context(context)?;
     }
 
     if !collection_started {
@@ -155,57 +331,100 @@ pub fn process_csv_features(
     geometry_column: &str,
 ) -> Result<()> {
     let mut reader = csv::Reader::from_reader(input);
-    let headers = reader.headers()?.clone();
+    let (headers, geometry_idx) = read_headers(&mut reader, geometry_column)?;
     processor.dataset_begin(None)?;
 
-    let geometry_idx = headers
-        .iter()
-        .position(|f| f == geometry_column)
-        .ok_or(GeozeroError::ColumnNotFound)?;
-
-    for (feature_idx, record) in reader.into_records().enumerate() {
-        let record = record?;
-        processor.feature_begin(feature_idx as u64)?;
+    let columns = csv_columns(&headers, geometry_idx);
+    processor.schema(&columns, GeometryType::Unknown)?;
+
+    let mut record = csv::StringRecord::new();
+    for feature_idx in 0.. {
+        let more = reader
+            .read_record(&mut record)
+            .map_err(GeozeroError::from)
+            .with_context(|| ErrorContext::new().with_feature(feature_idx as u64))?;
+        if !more {
+            break;
+        }
+        processor
+            .bytes_read(reader.position().byte(), None)
+            .with_context(|| record_context(feature_idx, &record))?;
+        process_csv_record(&record, feature_idx, &headers, geometry_idx, processor)
+            .with_context(|| record_context(feature_idx, &record))?;
+        if processor.done() {
+            break;
+        }
+    }
 
-        processor.properties_begin()?;
+    processor.dataset_end()
+}
 
-        let properties_iter = headers
-            .iter()
-            .zip(record.iter())
-            .enumerate()
-            // skip the geometry field -  we process it after all the "properties"
-            .filter(|(input_idx, _)| *input_idx != geometry_idx)
-            .map(|(_input_idx, (header, value))| (header, value));
+fn process_csv_record(
+    record: &csv::StringRecord,
+    feature_idx: usize,
+    headers: &csv::StringRecord,
+    geometry_idx: usize,
+    processor: &mut impl FeatureProcessor,
+) -> Result<()> {
+    processor.feature_begin(feature_idx as u64)?;
 
-        for (output_idx, (header, field)) in properties_iter.enumerate() {
-            let value = &ColumnValue::String(field);
-            processor.property(output_idx, header, value)?;
-        }
+    processor.properties_begin()?;
 
-        processor.properties_end()?;
+    let properties_iter = headers
+        .iter()
+        .zip(record.iter())
+        .enumerate()
+        // skip the geometry field -  we process it after all the "properties"
+        .filter(|(input_idx, _)| *input_idx != geometry_idx)
+        .map(|(_input_idx, (header, value))| (header, value));
+
+    for (output_idx, (header, field)) in properties_iter.enumerate() {
+        let value = &ColumnValue::String(field);
+        processor
+            .property(output_idx, header, value)
+            .with_context(|| ErrorContext::new().with_property(header))?;
+    }
 
-        let geometry_field = record
-            .get(geometry_idx)
-            .ok_or(GeozeroError::ColumnNotFound)?;
+    processor.properties_end()?;
 
-        // Do all formats allow empty geometries?
-        if !geometry_field.is_empty() {
-            processor.geometry_begin()?;
-            crate::wkt::wkt_reader::read_wkt(&mut geometry_field.as_bytes(), processor).map_err(
-                |e| {
-                    // +2 to start at line 1 and to account for the header row
-                    let line = feature_idx + 2;
-                    log::warn!("line {line}: invalid WKT: '{geometry_field}', record: {record:?}");
-                    e
-                },
-            )?;
-            processor.geometry_end()?;
-        }
+    let geometry_field = record
+        .get(geometry_idx)
+        .ok_or(GeozeroError::ColumnNotFound)?;
 
-        processor.feature_end(feature_idx as u64)?;
+    // Do all formats allow empty geometries?
+    if !geometry_field.is_empty() {
+        processor.geometry_begin()?;
+        crate::wkt::wkt_reader::read_wkt(&mut geometry_field.as_bytes(), processor)?;
+        processor.geometry_end()?;
     }
 
-    processor.dataset_end()
+    processor.feature_end(feature_idx as u64)
+}
+
+/// Property columns of a CSV file
+fn csv_columns(headers: &csv::StringRecord, geometry_idx: usize) -> Vec<ColumnInfo> {
+    headers
+        .iter()
+        .enumerate()
+        .filter(|(input_idx, _)| *input_idx != geometry_idx)
+        .map(|(_input_idx, header)| ColumnInfo {
+            name: header.to_string(),
+            column_type: Some(ColumnType::String),
+            // Empty cells are null values
+            nullable: true,
+        })
+        .collect()
+}
+
+/// Location of a CSV record
+fn record_context(record_idx: usize, record: &csv::StringRecord) -> ErrorContext {
+    let context = ErrorContext::new().with_feature(record_idx as u64);
+    match record.position() {
+        Some(position) => context
+            .with_line(position.line())
+            .with_byte_offset(position.byte()),
+        None => context,
+    }
 }
 
 impl From<csv::Error> for GeozeroError {
@@ -371,6 +590,54 @@ mod tests {
         assert_eq!(expected_geojson, actual_geojson)
     }
 
+    #[test]
+    fn feature_iter() -> Result<()> {
+        use crate::ToWkt;
+
+        let input = r#"address,type,datetime,report location,incident number
+904 7th Av,Car Fire,05/22/2019 12:55:00 PM,POINT (-122.329051 47.6069),F190051945
+9610 53rd Av S,Aid Response,05/22/2019 12:55:00 PM,,F190051946
+"#;
+        let mut features = CsvReader::new("report location", input.as_bytes()).feature_iter()?;
+        let feature = features.next_feature()?.unwrap();
+        assert_eq!(feature.property::<String>("type")?, "Car Fire");
+        assert_eq!(feature.property_n::<String>(3)?, "F190051945");
+        assert_eq!(feature.to_wkt()?, "POINT(-122.329051 47.6069)");
+        let feature = features.next_feature()?.unwrap();
+        assert_eq!(feature.property::<String>("address")?, "9610 53rd Av S");
+        assert_eq!(feature.bbox()?, None);
+        assert!(features.next_feature()?.is_none());
+
+        let features = CsvReader::new("location", input.as_bytes()).feature_iter();
+        assert!(matches!(features, Err(GeozeroError::ColumnNotFound)));
+        Ok(())
+    }
+
+    #[test]
+    fn batches() -> Result<()> {
+        use crate::RecordedFeature;
+
+        let input = r#"name,geom
+Bern,POINT(7.44 46.95)
+Guarda,POINT(10.15 46.77)
+Zernez,
+"#;
+        let mut batches = CsvReader::new("geom", input.as_bytes())
+            .batches(2)?
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 2);
+        let features = RecordedFeature::record_all(&mut batches[1])?;
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].property::<String>("name")?, "Zernez");
+
+        let mut recorder = crate::FeatureRecorder::new();
+        batches[0].process(&mut recorder)?;
+        assert_eq!(recorder.features().len(), 2);
+        let (columns, _) = recorder.schema().unwrap();
+        assert_eq!(columns[0].name, "name");
+        Ok(())
+    }
+
     #[test]
     fn geom_processor() {
         use crate::geojson::conversion::ToJson;
@@ -450,4 +717,22 @@ mod tests {
         let expected = "GEOMETRYCOLLECTION EMPTY";
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn error_context() {
+        use crate::ToJson;
+
+        let input = r#"name,geometry
+a,POINT(1 1)
+b,POINT(1 x)
+"#;
+        let err = CsvReader::new("geometry", input.as_bytes())
+            .to_json()
+            .unwrap_err();
+        let context = err.context().unwrap();
+        assert_eq!(context.feature(), Some(1));
+        assert_eq!(context.line(), Some(3));
+        assert_eq!(context.byte_offset(), Some(27));
+        assert!(matches!(err.without_context(), GeozeroError::Geometry(_)));
+    }
 }
@@ -1,12 +1,17 @@
-use crate::error::Result;
+use crate::error::{GeozeroError, Result};
 use crate::wkt::WktWriter;
-use crate::{ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
+use crate::{
+    ColumnInfo, ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, GeometryType,
+    PropertyProcessor,
+};
 
 use std::io::Write;
 
 pub struct CsvWriter<W: Write> {
     csv: csv::Writer<W>,
     headers: Vec<String>,
+    /// Headers were announced by the reader schema
+    has_schema: bool,
     has_written_first_record: bool,
     current_row_props: Vec<String>,
     wkt_writer: WktWriter<Vec<u8>>,
@@ -21,6 +26,7 @@ impl<W: Write> CsvWriter<W> {
         Self {
             csv: csv::Writer::from_writer(out),
             headers: vec!["geometry".to_string()],
+            has_schema: false,
             has_written_first_record: false,
             current_row_props: vec![],
             wkt_writer: WktWriter::with_dims(vec![], dims),
@@ -39,6 +45,10 @@ impl<W: Write> CsvWriter<W> {
             0
         }
     }
+
+    fn has_fixed_headers(&self) -> bool {
+        self.has_schema || self.has_written_first_record
+    }
 }
 
 impl<W: Write> FeatureProcessor for CsvWriter<W> {
@@ -48,6 +58,18 @@ impl<W: Write> FeatureProcessor for CsvWriter<W> {
     }
     fn dataset_end(&mut self) -> Result<()> {
         self.headers = vec!["geometry".to_string()];
+        self.has_schema = false;
+        Ok(())
+    }
+    fn schema(&mut self, columns: &[ColumnInfo], _geometry_type: GeometryType) -> Result<()> {
+        if self.has_written_first_record {
+            return Err(GeozeroError::Dataset(
+                "CSV schema announced after the first record".to_string(),
+            ));
+        }
+        self.headers
+            .extend(columns.iter().map(|column| column.name.clone()));
+        self.has_schema = true;
         Ok(())
     }
     fn feature_begin(&mut self, _idx: u64) -> Result<()> {
@@ -65,7 +87,9 @@ impl<W: Write> FeatureProcessor for CsvWriter<W> {
         self.csv.write_field(geom)?;
         self.wkt_writer.out.clear();
 
-        for field in &self.current_row_props {
+        // Properties missing in this row are written as empty fields
+        for i in 0..self.headers.len() - 1 {
+            let field = self.current_row_props.get(i).map_or("", String::as_str);
             self.csv.write_field(field)?;
         }
         self.csv.write_record(None::<&[u8]>)?;
@@ -91,19 +115,29 @@ impl<W: Write> FeatureProcessor for CsvWriter<W> {
 
 impl<W: Write> PropertyProcessor for CsvWriter<W> {
     fn property(&mut self, i: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
-        // TODO: support mis-ordered properties?
-        if self.has_written_first_record {
-            assert_eq!(
-                colname,
-                &self.headers[i + 1],
-                "CSV features must all have the same column names"
-            );
+        if self.has_fixed_headers() {
+            let pos = if self.headers.get(i + 1).map(String::as_str) == Some(colname) {
+                i
+            } else {
+                // Properties are in a different order than the header columns
+                self.headers[1..]
+                    .iter()
+                    .position(|header| header == colname)
+                    .ok_or_else(|| {
+                        GeozeroError::Property(format!(
+                            "column `{colname}` is not part of the CSV header"
+                        ))
+                    })?
+            };
+            if self.current_row_props.len() <= pos {
+                self.current_row_props.resize(pos + 1, String::new());
+            }
+            // TODO: support non-string colval
+            self.current_row_props[pos] = colval.to_string();
         } else {
             self.headers.push(colname.to_string());
+            self.current_row_props.push(colval.to_string());
         }
-
-        // TODO: support non-string colval
-        self.current_row_props.push(colval.to_string());
         Ok(false)
     }
 }
@@ -371,4 +405,56 @@ POINT(1 45),904 7th Av,05/22/2019 12:55:00 PM,F190051945,Car Fire
 
         assert_eq!(expected_output, actual_output);
     }
+
+    #[test]
+    fn varying_properties() {
+        let input_geojson = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                { "type": "Feature", "properties": { "name": "Foo" }, "geometry": { "type": "Point", "coordinates": [1, 1] } },
+                { "type": "Feature", "properties": { "kind": "river", "name": "Bar" }, "geometry": { "type": "Point", "coordinates": [2, 2] } },
+                { "type": "Feature", "properties": { "kind": "lake" }, "geometry": { "type": "Point", "coordinates": [3, 3] } }
+            ]
+        }"#;
+
+        let expected_output = r#"geometry,name,kind
+POINT(1 1),Foo,
+POINT(2 2),Bar,river
+POINT(3 3),,lake
+"#;
+
+        let actual_output = crate::geojson::GeoJson(input_geojson).to_csv().unwrap();
+
+        assert_eq!(expected_output, actual_output);
+    }
+
+    #[test]
+    fn unknown_column() {
+        let input = r#"{ "type": "Feature", "properties": { "name": "Foo" }, "geometry": { "type": "Point", "coordinates": [1, 1] } }
+{ "type": "Feature", "properties": { "kind": "river" }, "geometry": { "type": "Point", "coordinates": [2, 2] } }
+"#;
+
+        let result = crate::geojson::GeoJsonLineReader::new(input.as_bytes()).to_csv();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "processing feature property: `column `kind` is not part of the CSV header` (feature 1, line 2, property `kind`)"
+        );
+    }
+
+    #[test]
+    fn late_schema() {
+        use crate::{ColumnInfo, ColumnType, FeatureProcessor, GeometryType};
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut writer = super::CsvWriter::new(&mut out);
+        let columns = [ColumnInfo {
+            name: "name".to_string(),
+            column_type: Some(ColumnType::String),
+            nullable: true,
+        }];
+        writer.dataset_begin(None).unwrap();
+        writer.feature_begin(0).unwrap();
+        writer.feature_end(0).unwrap();
+        assert!(writer.schema(&columns, GeometryType::Unknown).is_err());
+    }
 }
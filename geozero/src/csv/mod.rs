@@ -29,3 +29,55 @@ pub(crate) mod conversion {
         }
     }
 }
+
+pub(crate) mod driver {
+    use crate::csv::{CsvReader, CsvWriter};
+    use crate::error::{GeozeroError, Result};
+    use crate::registry::{trim_end, trim_start};
+    use crate::{Driver, DriverOptions, DynDatasource, FeatureProcessor};
+    use std::io::{BufRead, Write};
+
+    pub(crate) const CSV: Driver = Driver {
+        name: "CSV",
+        extensions: &["csv"],
+        sniff: Some(sniff),
+        open: Some(open),
+        create: Some(create),
+    };
+
+    /// A header line containing the geometry column
+    fn sniff(buf: &[u8], options: &DriverOptions) -> bool {
+        let Some(geometry_column) = options.geometry_column.as_deref() else {
+            return false;
+        };
+        let buf = trim_start(buf);
+        let Some(eol) = buf.iter().position(|b| *b == b'\n') else {
+            return false;
+        };
+        buf[..eol].split(|b| *b == b',').any(|name| {
+            let name = trim_end(trim_start(name));
+            let name = name
+                .strip_prefix(b"\"")
+                .and_then(|name| name.strip_suffix(b"\""))
+                .unwrap_or(name);
+            name == geometry_column.as_bytes()
+        })
+    }
+
+    fn open<'a>(
+        reader: Box<dyn BufRead + 'a>,
+        options: &DriverOptions,
+    ) -> Result<Box<dyn DynDatasource + 'a>> {
+        let geometry_column = options.geometry_column.as_deref().ok_or_else(|| {
+            GeozeroError::Dataset("CSV input requires a geometry column name".to_string())
+        })?;
+        Ok(Box::new(CsvReader::new(geometry_column, reader)))
+    }
+
+    fn create<'a>(
+        out: Box<dyn Write + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn FeatureProcessor + 'a>> {
+        Ok(Box::new(CsvWriter::new(out)))
+    }
+}
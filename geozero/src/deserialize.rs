@@ -0,0 +1,459 @@
+use crate::error::{ErrorContext, GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{
+    ColumnValue, OwnedColumnValue, PropertyProcessor, PropertyReadType,
+};
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::forward_to_deserialize_any;
+use std::fmt;
+
+impl de::Error for GeozeroError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        GeozeroError::Property(msg.to_string())
+    }
+}
+
+/// Serde deserializer for feature properties.
+///
+/// Properties are presented as a map from property name to value. Values are
+/// converted with [`PropertyReadType`] for the requested type, so e.g. numeric
+/// CSV strings can be read into number fields. `Json` values are deserialized
+/// with `serde_json`, except when read into strings.
+pub struct PropertiesDeserializer<'de> {
+    properties: &'de [(String, OwnedColumnValue)],
+}
+
+impl<'de> PropertiesDeserializer<'de> {
+    pub fn new(properties: &'de [(String, OwnedColumnValue)]) -> Self {
+        PropertiesDeserializer { properties }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for PropertiesDeserializer<'de> {
+    type Error = GeozeroError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(PropertiesAccess::new(self.properties, true))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct PropertiesAccess<'de> {
+    properties: std::slice::Iter<'de, (String, OwnedColumnValue)>,
+    value: Option<(&'de str, &'de OwnedColumnValue)>,
+    /// Add the property name to errors
+    context: bool,
+}
+
+impl<'de> PropertiesAccess<'de> {
+    fn new(properties: &'de [(String, OwnedColumnValue)], context: bool) -> Self {
+        PropertiesAccess {
+            properties: properties.iter(),
+            value: None,
+            context,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for PropertiesAccess<'de> {
+    type Error = GeozeroError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.properties.next() {
+            Some((name, value)) => {
+                self.value = Some((name, value));
+                seed.deserialize(BorrowedStrDeserializer::new(name))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        let (name, value) = self
+            .value
+            .take()
+            .ok_or_else(|| GeozeroError::Property("value without property name".to_string()))?;
+        let result = seed.deserialize(ColumnValueDeserializer(value));
+        if self.context {
+            result.map_err(|e| e.with_context(ErrorContext::new().with_property(name)))
+        } else {
+            result
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.properties.len())
+    }
+}
+
+struct ListAccess<'de>(std::slice::Iter<'de, OwnedColumnValue>);
+
+impl<'de> SeqAccess<'de> for ListAccess<'de> {
+    type Error = GeozeroError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        self.0
+            .next()
+            .map(|value| seed.deserialize(ColumnValueDeserializer(value)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+/// Deserializer for a single property value.
+struct ColumnValueDeserializer<'de>(&'de OwnedColumnValue);
+
+impl ColumnValueDeserializer<'_> {
+    fn read<T: PropertyReadType>(&self) -> Result<T> {
+        T::get_value(&self.0.as_column_value())
+    }
+}
+
+type JsonDeserializer<'de> = serde_json::Deserializer<serde_json::de::StrRead<'de>>;
+
+/// Deserialize a `Json` value with `serde_json`.
+fn from_json<'de, T, F>(json: &'de str, f: F) -> Result<T>
+where
+    F: FnOnce(&mut JsonDeserializer<'de>) -> serde_json::Result<T>,
+{
+    let json_error = |e: serde_json::Error| GeozeroError::Property(e.to_string());
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let value = f(&mut deserializer).map_err(json_error)?;
+    deserializer.end().map_err(json_error)?;
+    Ok(value)
+}
+
+macro_rules! deserialize_scalar {
+    ( $method:ident, $visit:ident, $t:ty ) => {
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+            match self.0 {
+                OwnedColumnValue::Json(json) => {
+                    from_json(json, |de| de::Deserializer::$method(de, visitor))
+                }
+                _ => visitor.$visit(self.read::<$t>()?),
+            }
+        }
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ColumnValueDeserializer<'de> {
+    type Error = GeozeroError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            OwnedColumnValue::Byte(v) => visitor.visit_i8(*v),
+            OwnedColumnValue::UByte(v) => visitor.visit_u8(*v),
+            OwnedColumnValue::Bool(v) => visitor.visit_bool(*v),
+            OwnedColumnValue::Short(v) => visitor.visit_i16(*v),
+            OwnedColumnValue::UShort(v) => visitor.visit_u16(*v),
+            OwnedColumnValue::Int(v) => visitor.visit_i32(*v),
+            OwnedColumnValue::UInt(v) => visitor.visit_u32(*v),
+            OwnedColumnValue::Long(v) => visitor.visit_i64(*v),
+            OwnedColumnValue::ULong(v) => visitor.visit_u64(*v),
+            OwnedColumnValue::Float(v) => visitor.visit_f32(*v),
+            OwnedColumnValue::Double(v) => visitor.visit_f64(*v),
+            OwnedColumnValue::String(v) | OwnedColumnValue::DateTime(v) => {
+                visitor.visit_borrowed_str(v)
+            }
+            OwnedColumnValue::Json(json) => {
+                from_json(json, |de| de::Deserializer::deserialize_any(de, visitor))
+            }
+            OwnedColumnValue::Binary(v) => visitor.visit_borrowed_bytes(v),
+            OwnedColumnValue::Null => visitor.visit_unit(),
+            OwnedColumnValue::List(values) => visitor.visit_seq(ListAccess(values.iter())),
+            OwnedColumnValue::Map(entries) => {
+                visitor.visit_map(PropertiesAccess::new(entries, false))
+            }
+            #[cfg(feature = "with-chrono")]
+            OwnedColumnValue::Date(_)
+            | OwnedColumnValue::Time(_)
+            | OwnedColumnValue::Timestamp(_)
+            | OwnedColumnValue::TimestampTz(_) => {
+                visitor.visit_string(self.0.as_column_value().to_string())
+            }
+        }
+    }
+
+    deserialize_scalar!(deserialize_bool, visit_bool, bool);
+    deserialize_scalar!(deserialize_i8, visit_i8, i8);
+    deserialize_scalar!(deserialize_i16, visit_i16, i16);
+    deserialize_scalar!(deserialize_i32, visit_i32, i32);
+    deserialize_scalar!(deserialize_i64, visit_i64, i64);
+    deserialize_scalar!(deserialize_u8, visit_u8, u8);
+    deserialize_scalar!(deserialize_u16, visit_u16, u16);
+    deserialize_scalar!(deserialize_u32, visit_u32, u32);
+    deserialize_scalar!(deserialize_u64, visit_u64, u64);
+    deserialize_scalar!(deserialize_f32, visit_f32, f32);
+    deserialize_scalar!(deserialize_f64, visit_f64, f64);
+
+    /// Values of all types can be read as string, `Json` values as JSON text.
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            OwnedColumnValue::String(v)
+            | OwnedColumnValue::Json(v)
+            | OwnedColumnValue::DateTime(v) => visitor.visit_borrowed_str(v),
+            _ => visitor.visit_string(self.read::<String>()?),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_str(visitor)
+    }
+
+    /// `Null` values, empty strings and JSON `null` are read as `None`.
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            OwnedColumnValue::Null => visitor.visit_none(),
+            OwnedColumnValue::String(v) if v.is_empty() => visitor.visit_none(),
+            OwnedColumnValue::Json(json) => {
+                from_json(json, |de| de::Deserializer::deserialize_option(de, visitor))
+            }
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Unit variants are read from strings, other variants from `Json` values.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.0 {
+            OwnedColumnValue::String(v) => {
+                visitor.visit_enum(BorrowedStrDeserializer::new(v.trim()))
+            }
+            OwnedColumnValue::Json(json) => from_json(json, |de| {
+                de::Deserializer::deserialize_enum(de, name, variants, visitor)
+            }),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char bytes byte_buf unit unit_struct seq tuple tuple_struct
+        map struct identifier ignored_any
+    }
+}
+
+/// Processor collecting feature properties for deserialization.
+#[derive(Default)]
+pub(crate) struct PropertyCollector {
+    properties: Vec<(String, OwnedColumnValue)>,
+}
+
+impl PropertyCollector {
+    /// Deserialize the collected properties.
+    pub(crate) fn deserialize<T: DeserializeOwned>(&self) -> Result<T> {
+        T::deserialize(PropertiesDeserializer::new(&self.properties))
+    }
+
+    fn clear(&mut self) {
+        self.properties.clear();
+    }
+}
+
+impl PropertyProcessor for PropertyCollector {
+    fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.properties.push((name.to_string(), value.into()));
+        Ok(false)
+    }
+}
+
+/// Processor deserializing the properties of each feature into `T`.
+///
+/// Geometries are ignored.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-csv")]
+/// # {
+/// use geozero::csv::Csv;
+/// use geozero::{DeserializeProcessor, GeozeroDatasource};
+///
+/// #[derive(serde::Deserialize)]
+/// struct Place {
+///     name: String,
+///     population: u32,
+/// }
+///
+/// let mut csv = Csv::new("geometry", "name,population,geometry\nZurich,421878,POINT(8.5 47.4)\n");
+/// let mut processor = DeserializeProcessor::<Place>::new();
+/// csv.process(&mut processor).unwrap();
+/// let places = processor.into_features();
+/// assert_eq!(places[0].name, "Zurich");
+/// assert_eq!(places[0].population, 421878);
+/// # }
+/// ```
+pub struct DeserializeProcessor<T> {
+    properties: PropertyCollector,
+    features: Vec<T>,
+}
+
+impl<T: DeserializeOwned> Default for DeserializeProcessor<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: DeserializeOwned> DeserializeProcessor<T> {
+    pub fn new() -> Self {
+        DeserializeProcessor {
+            properties: PropertyCollector::default(),
+            features: Vec::new(),
+        }
+    }
+
+    /// Deserialized features
+    pub fn features(&self) -> &[T] {
+        &self.features
+    }
+
+    /// Return the deserialized features
+    pub fn into_features(self) -> Vec<T> {
+        self.features
+    }
+}
+
+impl<T: DeserializeOwned> FeatureProcessor for DeserializeProcessor<T> {
+    fn feature_begin(&mut self, _idx: u64) -> Result<()> {
+        self.properties.clear();
+        Ok(())
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        let feature = self
+            .properties
+            .deserialize()
+            .map_err(|e| e.with_context(ErrorContext::new().with_feature(idx)))?;
+        self.features.push(feature);
+        Ok(())
+    }
+}
+
+impl<T: DeserializeOwned> PropertyProcessor for DeserializeProcessor<T> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.properties.property(idx, name, value)
+    }
+}
+
+impl<T: DeserializeOwned> GeomProcessor for DeserializeProcessor<T> {}
+
+#[cfg(test)]
+#[cfg(all(feature = "with-csv", feature = "with-geojson"))]
+mod test {
+    use super::*;
+    use crate::csv::Csv;
+    use crate::geojson::GeoJson;
+    use crate::{FeatureProperties, GeozeroDatasource};
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    #[serde(rename_all = "lowercase")]
+    enum Kind {
+        City,
+        Village,
+    }
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Place {
+        name: String,
+        population: u64,
+        area: f64,
+        capital: bool,
+        kind: Kind,
+        zip: Option<u32>,
+        tags: Option<Vec<String>>,
+    }
+
+    #[test]
+    fn geojson_properties() -> Result<()> {
+        let geojson: geojson::Feature = r#"{"type": "Feature", "properties": {
+            "name": "Bern", "population": 134794, "area": 51.62, "capital": true,
+            "kind": "city", "zip": 3000, "tags": ["UNESCO", "old town"], "ignored": 1},
+            "geometry": {"type": "Point", "coordinates": [7.44, 46.95]}}"#
+            .parse()
+            .unwrap();
+        let place: Place = geojson.properties_as()?;
+        assert_eq!(
+            place,
+            Place {
+                name: "Bern".to_string(),
+                population: 134794,
+                area: 51.62,
+                capital: true,
+                kind: Kind::City,
+                zip: Some(3000),
+                tags: Some(vec!["UNESCO".to_string(), "old town".to_string()]),
+            }
+        );
+
+        let map: BTreeMap<String, serde_json::Value> = geojson.properties_as()?;
+        assert_eq!(map["tags"], serde_json::json!(["UNESCO", "old town"]));
+        Ok(())
+    }
+
+    #[test]
+    fn csv_datasource() -> Result<()> {
+        let mut csv = Csv::new(
+            "geometry",
+            "name,population,area,capital,kind,zip,tags,geometry
+Zurich,421878,87.88,false,city,,,POINT(8.54 47.37)
+Guarda,161,97.12,false,village,7545,,POINT(10.15 46.77)
+",
+        );
+        let places: Vec<Place> = csv.deserialize_properties()?;
+        assert_eq!(places.len(), 2);
+        assert_eq!(places[0].population, 421878);
+        assert_eq!(places[0].zip, None);
+        assert_eq!(places[1].kind, Kind::Village);
+        assert_eq!(places[1].zip, Some(7545));
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_error() {
+        let mut geojson = GeoJson(
+            r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"name": "Bern", "population": "many"},
+            "geometry": null}]}"#,
+        );
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Population {
+            name: String,
+            population: u64,
+        }
+        let err = geojson.deserialize_properties::<Population>().unwrap_err();
+        assert_eq!(err.context().unwrap().feature(), Some(0));
+        assert_eq!(err.context().unwrap().property(), Some("population"));
+
+        let mut geojson =
+            GeoJson(r#"{"type": "Feature", "properties": {"name": "Bern"}, "geometry": null}"#);
+        let err = geojson.deserialize_properties::<Population>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "processing feature property: `missing field `population`` (feature 0)"
+        );
+    }
+}
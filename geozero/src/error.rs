@@ -1,4 +1,5 @@
 //! Error and Result types.
+use std::fmt;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -37,6 +38,8 @@ pub enum GeozeroError {
     #[error("processing geometry `{0}`")]
     Geometry(String),
     // General
+    #[error("processing cancelled")]
+    Cancelled,
     #[error("I/O error `{0}`")]
     IoError(#[from] std::io::Error),
     #[cfg(feature = "with-mvt")]
@@ -45,6 +48,203 @@ pub enum GeozeroError {
     #[cfg(feature = "with-gdal")]
     #[error("GDAL error `{0}`")]
     GdalError(#[from] crate::gdal::GdalError),
+    #[cfg(feature = "with-proj")]
+    #[error("projection error `{0}`")]
+    ProjError(#[from] proj4rs::errors::Error),
+    /// Error with location information added by a reader
+    #[error("{source} ({context})")]
+    WithContext {
+        source: Box<GeozeroError>,
+        context: ErrorContext,
+    },
+}
+
+impl GeozeroError {
+    /// Add location information.
+    ///
+    /// Fields already known by the error take precedence.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        match self {
+            GeozeroError::WithContext {
+                source,
+                context: mut known,
+            } => {
+                known.merge(context);
+                GeozeroError::WithContext {
+                    source,
+                    context: known,
+                }
+            }
+            error => GeozeroError::WithContext {
+                source: Box::new(error),
+                context,
+            },
+        }
+    }
+
+    /// Location information, if added by a reader
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            GeozeroError::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// The error without location information
+    pub fn without_context(&self) -> &GeozeroError {
+        match self {
+            GeozeroError::WithContext { source, .. } => source,
+            error => error,
+        }
+    }
+
+    /// Whether processing was cancelled, with or without location information
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.without_context(), GeozeroError::Cancelled)
+    }
+}
+
+/// Location of an error within a dataset.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct ErrorContext {
+    dataset: Option<String>,
+    feature: Option<u64>,
+    line: Option<u64>,
+    byte_offset: Option<u64>,
+    property: Option<String>,
+}
+
+impl ErrorContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_dataset(mut self, name: impl Into<String>) -> Self {
+        self.dataset = Some(name.into());
+        self
+    }
+
+    pub fn with_feature(mut self, idx: u64) -> Self {
+        self.feature = Some(idx);
+        self
+    }
+
+    pub fn with_line(mut self, line: u64) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn with_byte_offset(mut self, offset: u64) -> Self {
+        self.byte_offset = Some(offset);
+        self
+    }
+
+    pub fn with_property(mut self, name: impl Into<String>) -> Self {
+        self.property = Some(name.into());
+        self
+    }
+
+    /// Dataset or layer name
+    pub fn dataset(&self) -> Option<&str> {
+        self.dataset.as_deref()
+    }
+
+    /// Feature index
+    pub fn feature(&self) -> Option<u64> {
+        self.feature
+    }
+
+    /// Line number, starting with 1
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    /// Byte offset within the input
+    pub fn byte_offset(&self) -> Option<u64> {
+        self.byte_offset
+    }
+
+    /// Property name
+    pub fn property(&self) -> Option<&str> {
+        self.property.as_deref()
+    }
+
+    fn merge(&mut self, other: ErrorContext) {
+        self.dataset = self.dataset.take().or(other.dataset);
+        self.feature = self.feature.or(other.feature);
+        self.line = self.line.or(other.line);
+        self.byte_offset = self.byte_offset.or(other.byte_offset);
+        self.property = self.property.take().or(other.property);
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(dataset) = &self.dataset {
+            parts.push(format!("dataset `{dataset}`"));
+        }
+        if let Some(feature) = self.feature {
+            parts.push(format!("feature {feature}"));
+        }
+        if let Some(line) = self.line {
+            parts.push(format!("line {line}"));
+        }
+        if let Some(offset) = self.byte_offset {
+            parts.push(format!("byte offset {offset}"));
+        }
+        if let Some(property) = &self.property {
+            parts.push(format!("property `{property}`"));
+        }
+        f.write_str(&parts.join(", "))
+    }
 }
 
 pub type Result<T> = std::result::Result<T, GeozeroError>;
+
+/// Adding location information to errors.
+pub trait ResultExt<T> {
+    /// Add location information to an error, see [`GeozeroError::with_context`].
+    fn with_context<F: FnOnce() -> ErrorContext>(self, context: F) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn with_context<F: FnOnce() -> ErrorContext>(self, context: F) -> Result<T> {
+        self.map_err(|error| error.with_context(context()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn context() {
+        let err = GeozeroError::GeometryFormat
+            .with_context(ErrorContext::new().with_property("name"))
+            .with_context(ErrorContext::new().with_feature(3).with_property("other"))
+            .with_context(ErrorContext::new().with_dataset("roads"));
+        assert_eq!(
+            err.to_string(),
+            "geometry format (dataset `roads`, feature 3, property `name`)"
+        );
+        let context = err.context().unwrap();
+        assert_eq!(context.feature(), Some(3));
+        assert_eq!(context.dataset(), Some("roads"));
+        assert_eq!(context.line(), None);
+        assert!(matches!(
+            err.without_context(),
+            GeozeroError::GeometryFormat
+        ));
+
+        let result: Result<()> = Err(GeozeroError::Coord);
+        let err = result
+            .with_context(|| ErrorContext::new().with_line(7).with_byte_offset(120))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "accessing requested coordinate (line 7, byte offset 120)"
+        );
+        assert!(GeozeroError::Coord.context().is_none());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::error::Result;
-use crate::geometry_processor::GeomProcessor;
-use crate::property_processor::PropertyProcessor;
+use crate::forward::forward_feature_processor;
+use crate::geometry_processor::{GeomProcessor, GeometryType};
+use crate::property_processor::{ColumnInfo, PropertyProcessor};
 
 /// Feature processing trait
 #[allow(unused_variables)]
@@ -25,6 +26,23 @@ pub trait FeatureProcessor: GeomProcessor + PropertyProcessor {
     fn dataset_end(&mut self) -> Result<()> {
         Ok(())
     }
+    /// Schema of the dataset
+    ///
+    /// Announced by readers which know their columns before reading the first feature.
+    ///
+    /// - `columns`: the property columns of the dataset.
+    /// - `geometry_type`: the geometry type of all features, [`GeometryType::Unknown`] if
+    ///   unknown or mixed.
+    ///
+    /// ## Invariants
+    ///
+    /// - `schema` is called _at most once_ for an entire dataset, after `dataset_begin` and
+    ///   before the first `feature_begin`.
+    /// - If a schema was announced, the `idx` passed to [`PropertyProcessor::property`] is the
+    ///   position of the column in `columns`. Null values may still be omitted.
+    fn schema(&mut self, columns: &[ColumnInfo], geometry_type: GeometryType) -> Result<()> {
+        Ok(())
+    }
     /// Begin of feature processing
     ///
     /// - `idx`: the positional row index in the dataset. For the `n`th row, `idx` will be
@@ -41,6 +59,24 @@ pub trait FeatureProcessor: GeomProcessor + PropertyProcessor {
     fn feature_end(&mut self, idx: u64) -> Result<()> {
         Ok(())
     }
+    /// Input position of a reader
+    ///
+    /// Readers knowing their input position call `bytes_read` once per feature, before its
+    /// `feature_end`.
+    ///
+    /// - `bytes`: the number of input bytes consumed so far.
+    /// - `total`: the total input size, if known.
+    fn bytes_read(&mut self, bytes: u64, total: Option<u64>) -> Result<()> {
+        Ok(())
+    }
+    /// No more features required
+    ///
+    /// Readers check `done` after each `feature_end` and stop reading further features if it
+    /// returns `true`, finishing with `dataset_end`. Processors wrapping another processor
+    /// should return `true` if the wrapped processor is done.
+    fn done(&self) -> bool {
+        false
+    }
     /// Begin of feature property processing
     ///
     /// ## Invariants
@@ -66,3 +102,11 @@ pub trait FeatureProcessor: GeomProcessor + PropertyProcessor {
         Ok(())
     }
 }
+
+impl<P: FeatureProcessor + ?Sized> FeatureProcessor for &mut P {
+    forward_feature_processor!(*);
+}
+
+impl<P: FeatureProcessor + ?Sized> FeatureProcessor for Box<P> {
+    forward_feature_processor!(*);
+}
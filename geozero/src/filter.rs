@@ -0,0 +1,269 @@
+use crate::api::{FeatureAccess, GeozeroGeometry};
+use crate::bounds::Bounds;
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::record::{FeatureRecorder, RecordedFeature};
+
+/// Processor passing only features matching a bounding box and/or a predicate to a wrapped
+/// processor.
+///
+/// Each feature is recorded as [`RecordedFeature`] until `feature_end`. Features passing the
+/// filter are renumbered consecutively. Events outside of features are passed through unchanged.
+///
+/// # Usage example:
+///
+/// Extent of all features with a population greater than 15:
+///
+/// ```
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::{BoundsProcessor, FeatureProperties, FilterProcessor, GeozeroDatasource};
+/// use geozero::geojson::GeoJson;
+///
+/// let input = r#"{"type": "FeatureCollection", "features": [
+///     {"type": "Feature", "properties": {"pop": 10}, "geometry": {"type": "Point", "coordinates": [1, 1]}},
+///     {"type": "Feature", "properties": {"pop": 20}, "geometry": {"type": "Point", "coordinates": [2, 2]}}
+/// ]}"#;
+/// let mut processor = FilterProcessor::new(BoundsProcessor::new(), |feature| {
+///     feature.property::<i64>("pop").is_ok_and(|pop| pop > 15)
+/// });
+/// GeoJson(input).process(&mut processor).unwrap();
+/// let bounds = processor.into_inner().bounds().unwrap();
+/// assert_eq!((bounds.min_x, bounds.min_y), (2.0, 2.0));
+/// # }
+/// ```
+pub struct FilterProcessor<P, F> {
+    processor: P,
+    bbox: Option<Bounds>,
+    predicate: F,
+    in_feature: bool,
+    recorder: FeatureRecorder,
+    /// Number of features passed to the wrapped processor
+    passed: u64,
+}
+
+/// Predicate accepting all features
+pub type AcceptAll = fn(&RecordedFeature) -> bool;
+
+impl<P> FilterProcessor<P, AcceptAll> {
+    /// Pass features intersecting `bbox`
+    pub fn with_bbox(processor: P, bbox: Bounds) -> Self {
+        let mut filter = Self::new(processor, |_| true);
+        filter.set_bbox(Some(bbox));
+        filter
+    }
+}
+
+impl<P, F: FnMut(&RecordedFeature) -> bool> FilterProcessor<P, F> {
+    /// Pass features matching `predicate`
+    pub fn new(processor: P, predicate: F) -> Self {
+        FilterProcessor {
+            processor,
+            bbox: None,
+            predicate,
+            in_feature: false,
+            recorder: FeatureRecorder::new(),
+            passed: 0,
+        }
+    }
+
+    /// Additionally require features to intersect `bbox`
+    pub fn set_bbox(&mut self, bbox: Option<Bounds>) {
+        self.bbox = bbox;
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    fn matches(&mut self, feature: &RecordedFeature) -> Result<bool> {
+        if let Some(bbox) = &self.bbox {
+            match feature.bbox()? {
+                Some(bounds) if bounds.intersects(bbox) => {}
+                _ => return Ok(false),
+            }
+        }
+        Ok((self.predicate)(feature))
+    }
+}
+
+impl<P: FeatureProcessor, F: FnMut(&RecordedFeature) -> bool> FeatureProcessor
+    for FilterProcessor<P, F>
+{
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.passed = 0;
+        self.processor.dataset_begin(name)
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.in_feature = true;
+        self.recorder.feature_begin(idx)
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        self.in_feature = false;
+        self.recorder.feature_end(idx)?;
+        let feature = self.recorder.pop_feature().unwrap_or_default();
+        if self.matches(&feature)? {
+            let idx = self.passed;
+            self.passed += 1;
+            feature.process(&mut self.processor, idx)
+        } else {
+            Ok(())
+        }
+    }
+    forward_feature_processor!(processor; dataset_end, schema, bytes_read, done);
+    forward_feature_processor!(
+        (processor unless in_feature);
+        properties_begin, properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: GeomProcessor, F> GeomProcessor for FilterProcessor<P, F> {
+    forward_geom_processor!((recorder if in_feature else processor));
+}
+
+impl<P: PropertyProcessor, F> PropertyProcessor for FilterProcessor<P, F> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        if self.in_feature {
+            self.recorder.property(idx, name, value)
+        } else {
+            self.processor.property(idx, name, value)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-geojson")]
+mod test {
+    use super::*;
+    use crate::geojson::GeoJson;
+    use crate::{FeatureProperties, GeozeroDatasource, ProcessorSink};
+    use serde_json::json;
+
+    fn input() -> String {
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": {"name": "a", "pop": 10},
+                    "geometry": {"type": "Point", "coordinates": [1, 1]}
+                },
+                {
+                    "type": "Feature",
+                    "properties": {"name": "b", "pop": 20},
+                    "geometry": {"type": "LineString", "coordinates": [[5, 5], [8, 8]]}
+                },
+                {
+                    "type": "Feature",
+                    "properties": {"name": "c", "pop": 30},
+                    "geometry": {"type": "Point", "coordinates": [9, 9]}
+                }
+            ]
+        })
+        .to_string()
+    }
+
+    /// Collects names and coordinate counts of processed features
+    #[derive(Default)]
+    struct FeatureCollector {
+        idx: Vec<u64>,
+        names: Vec<String>,
+        coords: usize,
+    }
+
+    impl FeatureProcessor for FeatureCollector {
+        fn feature_begin(&mut self, idx: u64) -> Result<()> {
+            self.idx.push(idx);
+            Ok(())
+        }
+    }
+    impl GeomProcessor for FeatureCollector {
+        fn xy(&mut self, _x: f64, _y: f64, _idx: usize) -> Result<()> {
+            self.coords += 1;
+            Ok(())
+        }
+    }
+    impl PropertyProcessor for FeatureCollector {
+        fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+            if name == "name" {
+                self.names.push(value.to_string());
+            }
+            Ok(false)
+        }
+    }
+
+    fn filter<F: FnMut(&RecordedFeature) -> bool>(
+        bbox: Option<Bounds>,
+        predicate: F,
+    ) -> Result<FeatureCollector> {
+        let input = input();
+        let mut processor = FilterProcessor::new(FeatureCollector::default(), predicate);
+        processor.set_bbox(bbox);
+        GeoJson(&input).process(&mut processor)?;
+        Ok(processor.into_inner())
+    }
+
+    #[test]
+    fn bbox() -> Result<()> {
+        let bbox = Bounds {
+            min_x: 0.0,
+            min_y: 0.0,
+            max_x: 6.0,
+            max_y: 6.0,
+            z: None,
+            m: None,
+        };
+        let features = filter(Some(bbox), |_| true)?;
+        assert_eq!(features.names, ["a", "b"]);
+        assert_eq!(features.idx, [0, 1]);
+        assert_eq!(features.coords, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn predicate() -> Result<()> {
+        let features = filter(None, |feature| {
+            feature.property::<i64>("pop").is_ok_and(|pop| pop > 15)
+        })?;
+        assert_eq!(features.names, ["b", "c"]);
+        assert_eq!(features.idx, [0, 1]);
+
+        let features = filter(None, |feature| {
+            feature
+                .bbox()
+                .unwrap()
+                .is_some_and(|bounds| bounds.width() > 0.0)
+        })?;
+        assert_eq!(features.names, ["b"]);
+        Ok(())
+    }
+
+    #[test]
+    fn bbox_and_predicate() -> Result<()> {
+        let bbox = Bounds::from_coord(9.0, 9.0, None, None);
+        let features = filter(Some(bbox), |feature| {
+            feature.property::<String>("name").unwrap() != "b"
+        })?;
+        assert_eq!(features.names, ["c"]);
+        assert_eq!(features.idx, [0]);
+        Ok(())
+    }
+
+    #[test]
+    fn borrowed_processor() -> Result<()> {
+        let input = input();
+        let mut collector = FeatureCollector::default();
+        let mut processor =
+            FilterProcessor::with_bbox(&mut collector, Bounds::from_coord(9.0, 9.0, None, None));
+        GeoJson(&input).process(&mut processor)?;
+        assert_eq!(collector.names, ["c"]);
+
+        let mut processor = FilterProcessor::new(ProcessorSink, |_| false);
+        GeoJson(&input).process(&mut processor)?;
+        Ok(())
+    }
+}
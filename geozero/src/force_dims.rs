@@ -0,0 +1,230 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::{CoordDimensions, GeomProcessor};
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+
+/// Processor passing coordinates with fixed dimensions to a wrapped processor.
+///
+/// Requests the target dimensions from the reader, removes other dimensions and optionally adds
+/// constant Z or M values to coordinates without them. Coordinates are passed as `coordinate`
+/// events, if the wrapped processor requests multiple dimensions, as `xy` events otherwise.
+///
+/// # Usage example:
+///
+/// Add a Z value of 10 to 2D input:
+///
+/// ```
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::{CoordDimensions, ForceDimsProcessor, GeozeroGeometry};
+/// use geozero::wkt::{Wkt, WktWriter};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let writer = WktWriter::with_dims(&mut out, CoordDimensions::xyz());
+/// let mut processor = ForceDimsProcessor::force_3d(writer, 10.0);
+/// Wkt("LINESTRING(1 2,3 4)").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING(1 2 10,3 4 10)");
+/// # }
+/// ```
+pub struct ForceDimsProcessor<P> {
+    processor: P,
+    dims: CoordDimensions,
+    default_z: Option<f64>,
+    default_m: Option<f64>,
+}
+
+impl<P: GeomProcessor> ForceDimsProcessor<P> {
+    /// Pass coordinates with `dims`
+    pub fn new(processor: P, dims: CoordDimensions) -> Self {
+        ForceDimsProcessor {
+            processor,
+            dims,
+            default_z: None,
+            default_m: None,
+        }
+    }
+
+    /// Pass x/y coordinates only
+    pub fn force_2d(processor: P) -> Self {
+        Self::new(processor, CoordDimensions::xy())
+    }
+
+    /// Pass x/y/z coordinates with `z` for coordinates without Z value
+    pub fn force_3d(processor: P, z: f64) -> Self {
+        let mut force = Self::new(processor, CoordDimensions::xyz());
+        force.set_default_z(Some(z));
+        force
+    }
+
+    /// Pass coordinates without M value, keeping Z values
+    pub fn drop_m(processor: P) -> Self {
+        Self::new(processor, CoordDimensions::xyz())
+    }
+
+    /// Z value for coordinates without Z, if Z is a target dimension
+    pub fn set_default_z(&mut self, z: Option<f64>) {
+        self.default_z = z;
+    }
+
+    /// M value for coordinates without M, if M is a target dimension
+    pub fn set_default_m(&mut self, m: Option<f64>) {
+        self.default_m = m;
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for ForceDimsProcessor<P> {
+    forward_feature_processor!(processor);
+}
+
+impl<P: GeomProcessor> GeomProcessor for ForceDimsProcessor<P> {
+    fn dimensions(&self) -> CoordDimensions {
+        self.dims
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coordinate(x, y, None, None, None, None, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let dims = self.dims;
+        let z = if dims.z { z.or(self.default_z) } else { None };
+        let m = if dims.m { m.or(self.default_m) } else { None };
+        let t = t.filter(|_| dims.t);
+        let tm = tm.filter(|_| dims.tm);
+        if self.processor.multi_dim() {
+            self.processor.coordinate(x, y, z, m, t, tm, idx)
+        } else {
+            self.processor.xy(x, y, idx)
+        }
+    }
+    forward_geom_processor!(
+        processor;
+        srid, empty_point, point_begin, point_end, multipoint_begin, multipoint_end,
+        linestring_begin, linestring_end, multilinestring_begin, multilinestring_end, polygon_begin,
+        polygon_end, multipolygon_begin, multipolygon_end, geometrycollection_begin,
+        geometrycollection_end, circularstring_begin, circularstring_end, compoundcurve_begin,
+        compoundcurve_end, curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end, polyhedralsurface_begin,
+        polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for ForceDimsProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "with-wkb", feature = "with-wkt"))]
+mod test {
+    use super::*;
+    use crate::wkb::Ewkb;
+    use crate::wkt::WktWriter;
+    use crate::GeozeroGeometry;
+
+    const POINT: &str = "0101000000000000000000f03f0000000000000040";
+    const POINT_Z: &str = "0101000080000000000000f03f00000000000000400000000000001440";
+    const LINESTRING_Z: &str = "010200008002000000000000000000f03f00000000000000400000000000000840000000000000144000000000000018400000000000001c40";
+    const LINESTRING_ZM: &str = "01020000c002000000000000000000f03f000000000000004000000000000008400000000000001040000000000000144000000000000018400000000000001c400000000000002040";
+
+    fn forced<F>(ewkb: &str, out_dims: CoordDimensions, force: F) -> Result<String>
+    where
+        F: FnOnce(WktWriter<Vec<u8>>) -> ForceDimsProcessor<WktWriter<Vec<u8>>>,
+    {
+        let mut processor = force(WktWriter::with_dims(Vec::new(), out_dims));
+        Ewkb(hex::decode(ewkb).unwrap()).process_geom(&mut processor)?;
+        Ok(String::from_utf8(processor.into_inner().out).unwrap())
+    }
+
+    #[test]
+    fn force_2d() -> Result<()> {
+        assert_eq!(
+            forced(
+                LINESTRING_ZM,
+                CoordDimensions::xyzm(),
+                ForceDimsProcessor::force_2d
+            )?,
+            "LINESTRING(1 2,5 6)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn force_3d() -> Result<()> {
+        let force_3d = |writer| ForceDimsProcessor::force_3d(writer, 0.0);
+        assert_eq!(
+            forced(POINT, CoordDimensions::xyz(), force_3d)?,
+            "POINT(1 2 0)"
+        );
+        assert_eq!(
+            forced(POINT_Z, CoordDimensions::xyz(), force_3d)?,
+            "POINT(1 2 5)"
+        );
+        assert_eq!(
+            forced(LINESTRING_ZM, CoordDimensions::xyzm(), force_3d)?,
+            "LINESTRING(1 2 3,5 6 7)"
+        );
+        // 2D writer
+        assert_eq!(
+            forced(POINT_Z, CoordDimensions::xy(), force_3d)?,
+            "POINT(1 2)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn drop_m() -> Result<()> {
+        assert_eq!(
+            forced(
+                LINESTRING_ZM,
+                CoordDimensions::xyzm(),
+                ForceDimsProcessor::drop_m
+            )?,
+            "LINESTRING(1 2 3,5 6 7)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn default_m() -> Result<()> {
+        let force_m = |writer| {
+            let mut processor = ForceDimsProcessor::new(writer, CoordDimensions::xym());
+            processor.set_default_m(Some(-1.0));
+            processor
+        };
+        assert_eq!(
+            forced(LINESTRING_Z, CoordDimensions::xyzm(), force_m)?,
+            "LINESTRING(1 2 -1,5 6 -1)"
+        );
+        assert_eq!(
+            forced(LINESTRING_ZM, CoordDimensions::xyzm(), force_m)?,
+            "LINESTRING(1 2 4,5 6 8)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn requested_dimensions() {
+        let processor =
+            ForceDimsProcessor::force_2d(WktWriter::with_dims(Vec::new(), CoordDimensions::xyz()));
+        assert!(!processor.multi_dim());
+        let processor = ForceDimsProcessor::drop_m(WktWriter::new(Vec::new()));
+        let dims = processor.dimensions();
+        assert!(processor.multi_dim());
+        assert!(dims.z && !dims.m && !dims.t && !dims.tm);
+    }
+}
@@ -0,0 +1,426 @@
+//! Macros forwarding processor methods to a wrapped processor.
+//!
+//! Used within `impl` blocks of processors passing events to another processor:
+//!
+//! ```ignore
+//! impl<P: GeomProcessor> GeomProcessor for MyProcessor<P> {
+//!     fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+//!         self.processor.xy(x + 1.0, y, idx)
+//!     }
+//!     // Forward the other methods to `self.processor`
+//!     forward_geom_processor!(processor; dimensions, multi_dim, srid, coordinate, ...);
+//! }
+//! ```
+//!
+//! Without a method list, all methods are forwarded. The methods are forwarded to
+//! * `field`: the wrapped processor `self.field`
+//! * `*`: the dereferenced processor of `&mut P` or `Box<P>`
+//! * `(field unless flag)`: `self.field`, or ignored with `Ok(())` if `self.flag` is set
+//! * `(other if flag else field)`: `self.other` if `self.flag` is set, `self.field` otherwise
+
+/// Forward [`GeomProcessor`](crate::GeomProcessor) methods
+macro_rules! forward_geom_processor {
+    (@call $self:ident * $($call:tt)+) => {
+        (**$self).$($call)+
+    };
+    (@call $self:ident ($field:ident unless $flag:ident) $($call:tt)+) => {
+        if $self.$flag {
+            Ok(())
+        } else {
+            $self.$field.$($call)+
+        }
+    };
+    (@call $self:ident ($other:ident if $flag:ident else $field:ident) $($call:tt)+) => {
+        if $self.$flag {
+            $self.$other.$($call)+
+        } else {
+            $self.$field.$($call)+
+        }
+    };
+    (@call $self:ident $field:tt $($call:tt)+) => {
+        $self.$field.$($call)+
+    };
+    (@method $recv:tt dimensions) => {
+        fn dimensions(&self) -> $crate::CoordDimensions {
+            $crate::forward::forward_geom_processor!(@call self $recv dimensions())
+        }
+    };
+    (@method $recv:tt multi_dim) => {
+        fn multi_dim(&self) -> bool {
+            $crate::forward::forward_geom_processor!(@call self $recv multi_dim())
+        }
+    };
+    (@method $recv:tt srid) => {
+        fn srid(&mut self, srid: Option<i32>) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv srid(srid))
+        }
+    };
+    (@method $recv:tt xy) => {
+        fn xy(&mut self, x: f64, y: f64, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv xy(x, y, idx))
+        }
+    };
+    (@method $recv:tt coordinate) => {
+        #[allow(clippy::too_many_arguments)]
+        fn coordinate(
+            &mut self,
+            x: f64,
+            y: f64,
+            z: Option<f64>,
+            m: Option<f64>,
+            t: Option<f64>,
+            tm: Option<u64>,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv coordinate(x, y, z, m, t, tm, idx)
+            )
+        }
+    };
+    (@method $recv:tt empty_point) => {
+        fn empty_point(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv empty_point(idx))
+        }
+    };
+    (@method $recv:tt point_begin) => {
+        fn point_begin(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv point_begin(idx))
+        }
+    };
+    (@method $recv:tt point_end) => {
+        fn point_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv point_end(idx))
+        }
+    };
+    (@method $recv:tt multipoint_begin) => {
+        fn multipoint_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multipoint_begin(size, idx))
+        }
+    };
+    (@method $recv:tt multipoint_end) => {
+        fn multipoint_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multipoint_end(idx))
+        }
+    };
+    (@method $recv:tt linestring_begin) => {
+        fn linestring_begin(
+            &mut self,
+            tagged: bool,
+            size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv linestring_begin(tagged, size, idx)
+            )
+        }
+    };
+    (@method $recv:tt linestring_end) => {
+        fn linestring_end(&mut self, tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv linestring_end(tagged, idx))
+        }
+    };
+    (@method $recv:tt multilinestring_begin) => {
+        fn multilinestring_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv multilinestring_begin(size, idx)
+            )
+        }
+    };
+    (@method $recv:tt multilinestring_end) => {
+        fn multilinestring_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multilinestring_end(idx))
+        }
+    };
+    (@method $recv:tt polygon_begin) => {
+        fn polygon_begin(
+            &mut self,
+            tagged: bool,
+            size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv polygon_begin(tagged, size, idx)
+            )
+        }
+    };
+    (@method $recv:tt polygon_end) => {
+        fn polygon_end(&mut self, tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv polygon_end(tagged, idx))
+        }
+    };
+    (@method $recv:tt multipolygon_begin) => {
+        fn multipolygon_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multipolygon_begin(size, idx))
+        }
+    };
+    (@method $recv:tt multipolygon_end) => {
+        fn multipolygon_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multipolygon_end(idx))
+        }
+    };
+    (@method $recv:tt geometrycollection_begin) => {
+        fn geometrycollection_begin(
+            &mut self,
+            size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv geometrycollection_begin(size, idx)
+            )
+        }
+    };
+    (@method $recv:tt geometrycollection_end) => {
+        fn geometrycollection_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv geometrycollection_end(idx))
+        }
+    };
+    (@method $recv:tt circularstring_begin) => {
+        fn circularstring_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv circularstring_begin(size, idx)
+            )
+        }
+    };
+    (@method $recv:tt circularstring_end) => {
+        fn circularstring_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv circularstring_end(idx))
+        }
+    };
+    (@method $recv:tt compoundcurve_begin) => {
+        fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv compoundcurve_begin(size, idx)
+            )
+        }
+    };
+    (@method $recv:tt compoundcurve_end) => {
+        fn compoundcurve_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv compoundcurve_end(idx))
+        }
+    };
+    (@method $recv:tt curvepolygon_begin) => {
+        fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv curvepolygon_begin(size, idx))
+        }
+    };
+    (@method $recv:tt curvepolygon_end) => {
+        fn curvepolygon_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv curvepolygon_end(idx))
+        }
+    };
+    (@method $recv:tt multicurve_begin) => {
+        fn multicurve_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multicurve_begin(size, idx))
+        }
+    };
+    (@method $recv:tt multicurve_end) => {
+        fn multicurve_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multicurve_end(idx))
+        }
+    };
+    (@method $recv:tt multisurface_begin) => {
+        fn multisurface_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multisurface_begin(size, idx))
+        }
+    };
+    (@method $recv:tt multisurface_end) => {
+        fn multisurface_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv multisurface_end(idx))
+        }
+    };
+    (@method $recv:tt triangle_begin) => {
+        fn triangle_begin(
+            &mut self,
+            tagged: bool,
+            size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv triangle_begin(tagged, size, idx)
+            )
+        }
+    };
+    (@method $recv:tt triangle_end) => {
+        fn triangle_end(&mut self, tagged: bool, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv triangle_end(tagged, idx))
+        }
+    };
+    (@method $recv:tt polyhedralsurface_begin) => {
+        fn polyhedralsurface_begin(
+            &mut self,
+            size: usize,
+            idx: usize,
+        ) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(
+                @call self $recv polyhedralsurface_begin(size, idx)
+            )
+        }
+    };
+    (@method $recv:tt polyhedralsurface_end) => {
+        fn polyhedralsurface_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv polyhedralsurface_end(idx))
+        }
+    };
+    (@method $recv:tt tin_begin) => {
+        fn tin_begin(&mut self, size: usize, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv tin_begin(size, idx))
+        }
+    };
+    (@method $recv:tt tin_end) => {
+        fn tin_end(&mut self, idx: usize) -> $crate::error::Result<()> {
+            $crate::forward::forward_geom_processor!(@call self $recv tin_end(idx))
+        }
+    };
+    ($recv:tt) => {
+        $crate::forward::forward_geom_processor!(
+            $recv;
+            dimensions,
+            multi_dim,
+            srid,
+            xy,
+            coordinate,
+            empty_point,
+            point_begin,
+            point_end,
+            multipoint_begin,
+            multipoint_end,
+            linestring_begin,
+            linestring_end,
+            multilinestring_begin,
+            multilinestring_end,
+            polygon_begin,
+            polygon_end,
+            multipolygon_begin,
+            multipolygon_end,
+            geometrycollection_begin,
+            geometrycollection_end,
+            circularstring_begin,
+            circularstring_end,
+            compoundcurve_begin,
+            compoundcurve_end,
+            curvepolygon_begin,
+            curvepolygon_end,
+            multicurve_begin,
+            multicurve_end,
+            multisurface_begin,
+            multisurface_end,
+            triangle_begin,
+            triangle_end,
+            polyhedralsurface_begin,
+            polyhedralsurface_end,
+            tin_begin,
+            tin_end
+        );
+    };
+    ($recv:tt; $($method:ident),+ $(,)?) => {
+        $($crate::forward::forward_geom_processor!(@method $recv $method);)+
+    };
+}
+
+pub(crate) use forward_geom_processor;
+
+/// Forward [`FeatureProcessor`](crate::FeatureProcessor) methods
+macro_rules! forward_feature_processor {
+    (@call $self:ident * $($call:tt)+) => {
+        (**$self).$($call)+
+    };
+    (@call $self:ident ($field:ident unless $flag:ident) $($call:tt)+) => {
+        if $self.$flag {
+            Ok(())
+        } else {
+            $self.$field.$($call)+
+        }
+    };
+    (@call $self:ident ($other:ident if $flag:ident else $field:ident) $($call:tt)+) => {
+        if $self.$flag {
+            $self.$other.$($call)+
+        } else {
+            $self.$field.$($call)+
+        }
+    };
+    (@call $self:ident $field:tt $($call:tt)+) => {
+        $self.$field.$($call)+
+    };
+    (@method $recv:tt dataset_begin) => {
+        fn dataset_begin(&mut self, name: Option<&str>) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv dataset_begin(name))
+        }
+    };
+    (@method $recv:tt dataset_end) => {
+        fn dataset_end(&mut self) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv dataset_end())
+        }
+    };
+    (@method $recv:tt schema) => {
+        fn schema(
+            &mut self,
+            columns: &[$crate::ColumnInfo],
+            geometry_type: $crate::GeometryType,
+        ) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(
+                @call self $recv schema(columns, geometry_type)
+            )
+        }
+    };
+    (@method $recv:tt feature_begin) => {
+        fn feature_begin(&mut self, idx: u64) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv feature_begin(idx))
+        }
+    };
+    (@method $recv:tt feature_end) => {
+        fn feature_end(&mut self, idx: u64) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv feature_end(idx))
+        }
+    };
+    (@method $recv:tt bytes_read) => {
+        fn bytes_read(&mut self, bytes: u64, total: Option<u64>) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv bytes_read(bytes, total))
+        }
+    };
+    (@method $recv:tt done) => {
+        fn done(&self) -> bool {
+            $crate::forward::forward_feature_processor!(@call self $recv done())
+        }
+    };
+    (@method $recv:tt properties_begin) => {
+        fn properties_begin(&mut self) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv properties_begin())
+        }
+    };
+    (@method $recv:tt properties_end) => {
+        fn properties_end(&mut self) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv properties_end())
+        }
+    };
+    (@method $recv:tt geometry_begin) => {
+        fn geometry_begin(&mut self) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv geometry_begin())
+        }
+    };
+    (@method $recv:tt geometry_end) => {
+        fn geometry_end(&mut self) -> $crate::error::Result<()> {
+            $crate::forward::forward_feature_processor!(@call self $recv geometry_end())
+        }
+    };
+    ($recv:tt) => {
+        $crate::forward::forward_feature_processor!(
+            $recv;
+            dataset_begin,
+            dataset_end,
+            schema,
+            feature_begin,
+            feature_end,
+            bytes_read,
+            done,
+            properties_begin,
+            properties_end,
+            geometry_begin,
+            geometry_end
+        );
+    };
+    ($recv:tt; $($method:ident),+ $(,)?) => {
+        $($crate::forward::forward_feature_processor!(@method $recv $method);)+
+    };
+}
+
+pub(crate) use forward_feature_processor;
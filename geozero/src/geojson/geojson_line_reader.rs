@@ -1,11 +1,11 @@
 use crate::{
-    error::{GeozeroError, Result},
-    FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry,
+    error::{ErrorContext, GeozeroError, Result, ResultExt},
+    FeatureIterator, FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry,
 };
 
 use super::{process_geojson_geom_n, process_properties};
 
-use std::io::{BufRead, BufReader, Read};
+use std::io::{BufRead, BufReader, Lines, Read};
 
 use geojson::{Feature, GeoJson as GeoGeoJson, Geometry};
 
@@ -19,6 +19,105 @@ impl<R: Read> GeoJsonLineReader<R> {
     }
 }
 
+impl<R: Read> GeoJsonLineReader<R> {
+    /// Iterate over the features, reading one line at a time.
+    ///
+    /// Geometry lines are returned as features without properties.
+    pub fn feature_iter(self) -> GeoJsonLineFeatureIter<R> {
+        GeoJsonLineFeatureIter(BufReader::new(self.0).lines())
+    }
+
+    /// Split the input into batches of `size` lines, which can be parsed independently,
+    /// e.g. in parallel.
+    pub fn batches(self, size: usize) -> GeoJsonLineBatches<R> {
+        GeoJsonLineBatches {
+            lines: BufReader::new(self.0).lines(),
+            size: size.max(1),
+            next_idx: 0,
+        }
+    }
+}
+
+/// Iterator over owned features of line delimited GeoJSON.
+pub struct GeoJsonLineFeatureIter<R: Read>(Lines<BufReader<R>>);
+
+impl<R: Read> FeatureIterator for GeoJsonLineFeatureIter<R> {
+    type Feature<'a>
+        = Feature
+    where
+        Self: 'a;
+    fn next_feature(&mut self) -> Result<Option<Feature>> {
+        let Some(line) = self.0.next() else {
+            return Ok(None);
+        };
+        match line?.parse::<GeoGeoJson>()? {
+            GeoGeoJson::Feature(feature) => Ok(Some(feature)),
+            GeoGeoJson::Geometry(geometry) => Ok(Some(Feature::from(geometry))),
+            _ => Err(GeozeroError::Dataset("line-delimited GeoJson ('geojsonl') files must have one Feature or Geometry per line".to_string())),
+        }
+    }
+}
+
+impl<R: Read> Iterator for GeoJsonLineFeatureIter<R> {
+    type Item = Result<Feature>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_feature().transpose()
+    }
+}
+
+/// Iterator over batches of line delimited GeoJSON.
+pub struct GeoJsonLineBatches<R: Read> {
+    lines: Lines<BufReader<R>>,
+    size: usize,
+    next_idx: usize,
+}
+
+impl<R: Read> Iterator for GeoJsonLineBatches<R> {
+    type Item = Result<GeoJsonLineBatch>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let lines = match self
+            .lines
+            .by_ref()
+            .take(self.size)
+            .collect::<std::io::Result<Vec<_>>>()
+        {
+            Ok(lines) => lines,
+            Err(e) => return Some(Err(e.into())),
+        };
+        if lines.is_empty() {
+            return None;
+        }
+        let first_idx = self.next_idx;
+        self.next_idx += lines.len();
+        Some(Ok(GeoJsonLineBatch { lines, first_idx }))
+    }
+}
+
+/// Unparsed lines of line delimited GeoJSON.
+///
+/// Features are processed with their index in the whole input.
+pub struct GeoJsonLineBatch {
+    lines: Vec<String>,
+    first_idx: usize,
+}
+
+impl GeozeroDatasource for GeoJsonLineBatch {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        processor.dataset_begin(None)?;
+        for (idx, line) in (self.first_idx..).zip(&self.lines) {
+            process_line(processor, idx, line).with_context(|| {
+                ErrorContext::new()
+                    .with_feature(idx as u64)
+                    .with_line(idx as u64 + 1)
+            })?;
+            if processor.done() {
+                break;
+            }
+        }
+        processor.dataset_end()
+    }
+}
+
 impl<R: Read + Clone> GeozeroGeometry for GeoJsonLineReader<R> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()>
     where
@@ -81,21 +180,43 @@ pub fn read_geojson_line_geometries(
 
 /// Read and process line delimited GeoJSON (one object per line).
 pub fn read_geojson_lines(reader: impl Read, processor: &mut impl FeatureProcessor) -> Result<()> {
-    let buf_reader = BufReader::new(reader);
+    let mut buf_reader = BufReader::new(reader);
+    let mut line = String::new();
+    let mut bytes = 0;
 
     processor.dataset_begin(None)?;
-    for (idx, line) in buf_reader.lines().enumerate() {
-        match line?.parse::<GeoGeoJson>()? {
-            GeoGeoJson::Feature(feature) => process_feature(processor, idx, &feature)?,
-            GeoGeoJson::Geometry(geometry) => process_geometry(processor, idx, &geometry)?,
-            _ => {
-                return Err(GeozeroError::Dataset("line-delimited GeoJson ('geojsonl') files must have one Feature or Geometry per line".to_string()));
-            }
+    for idx in 0.. {
+        line.clear();
+        let len = buf_reader.read_line(&mut line)?;
+        if len == 0 {
+            break;
+        }
+        bytes += len as u64;
+        let context = || {
+            ErrorContext::new()
+                .with_feature(idx as u64)
+                .with_line(idx as u64 + 1)
+        };
+        processor.bytes_read(bytes, None).with_context(context)?;
+        process_line(processor, idx, line.trim_end_matches(['\n', '\r'])).with_context(context)?;
+        if processor.done() {
+            break;
         }
     }
     processor.dataset_end()
 }
 
+fn process_line(processor: &mut impl FeatureProcessor, idx: usize, line: &str) -> Result<()> {
+    match line.parse::<GeoGeoJson>()? {
+        GeoGeoJson::Feature(feature) => process_feature(processor, idx, &feature),
+        GeoGeoJson::Geometry(geometry) => process_geometry(processor, idx, &geometry),
+        _ => Err(GeozeroError::Dataset(
+            "line-delimited GeoJson ('geojsonl') files must have one Feature or Geometry per line"
+                .to_string(),
+        )),
+    }
+}
+
 fn process_feature(
     processor: &mut impl FeatureProcessor,
     idx: usize,
@@ -128,6 +249,7 @@ fn process_geometry(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::FeatureProperties;
     use crate::ProcessToJson;
     use crate::ToWkt;
 
@@ -172,6 +294,24 @@ ooops this is malformed json { "type": "Feature", "geometry": { "type": "Point",
         let mut reader = GeoJsonLineReader(input.as_bytes());
         _ = reader.to_json().unwrap_err();
     }
+    #[test]
+    fn batches() {
+        let input = r#"{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [1.1, 1.2] }, "properties": { "name": "first" } }
+{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [2.1, 2.2] }, "properties": { "name": "second" } }
+ooops this is malformed json
+"#;
+        let mut batches = GeoJsonLineReader(input.as_bytes())
+            .batches(2)
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batches.len(), 2);
+        let features = crate::RecordedFeature::record_all(&mut batches[0]).unwrap();
+        assert_eq!(features[1].property::<String>("name").unwrap(), "second");
+        let err = batches[1].to_json().unwrap_err();
+        let context = err.context().unwrap();
+        assert_eq!((context.feature(), context.line()), (Some(2), Some(3)));
+    }
+
     #[test]
     fn valid_json_but_not_one_feature_per_line() {
         let input = r#"{
@@ -185,4 +325,51 @@ ooops this is malformed json { "type": "Feature", "geometry": { "type": "Point",
         let mut reader = GeoJsonLineReader(input.as_bytes());
         _ = reader.to_json().unwrap_err();
     }
+
+    #[test]
+    fn feature_iter() -> Result<()> {
+        use crate::FeatureProperties;
+
+        let input = r#"{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [1.1, 1.2] }, "properties": { "name": "first" } }
+{ "type": "Point", "coordinates": [2.1, 2.2] }
+{ "type": "Feature", "geometry": { "type": "Point", "coordinates": [3.1, 3.3] }, "properties": { "name": "third" } }
+"#;
+        let mut features = GeoJsonLineReader::new(input.as_bytes()).feature_iter();
+        let first = features.next_feature()?.unwrap();
+        assert_eq!(
+            FeatureProperties::property::<String>(&first, "name")?,
+            "first"
+        );
+        assert_eq!(first.to_wkt()?, "POINT(1.1 1.2)");
+        let second = features.next_feature()?.unwrap();
+        assert!(FeatureProperties::property::<String>(&second, "name").is_err());
+        assert_eq!(second.to_wkt()?, "POINT(2.1 2.2)");
+
+        // Owned features can be used with std iterator adapters
+        let mut pairs = GeoJsonLineReader::new(input.as_bytes()).feature_iter().zip(
+            GeoJsonLineReader::new(input.as_bytes())
+                .feature_iter()
+                .skip(2),
+        );
+        let (a, b) = pairs.next().unwrap();
+        assert_eq!(a?.to_wkt()?, "POINT(1.1 1.2)");
+        assert_eq!(b?.to_wkt()?, "POINT(3.1 3.3)");
+        assert!(pairs.next().is_none());
+
+        let mut reader = GeoJsonLineReader::new("{}".as_bytes()).feature_iter();
+        assert!(reader.next_feature().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn error_context() {
+        let input = r#"{ "type": "Point", "coordinates": [1.1, 1.2] }
+{ "type": "Point", "coordinates": [2.1] }
+"#;
+        let err = GeoJsonLineReader::new(input.as_bytes())
+            .to_json()
+            .unwrap_err();
+        let context = err.context().unwrap();
+        assert_eq!((context.feature(), context.line()), (Some(1), Some(2)));
+    }
 }
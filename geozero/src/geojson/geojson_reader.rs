@@ -1,13 +1,17 @@
-use crate::error::{GeozeroError, Result};
+use crate::error::{ErrorContext, GeozeroError, Result, ResultExt};
 use crate::{
-    ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry,
-    PropertyProcessor,
+    ColumnInfo, ColumnType, ColumnValue, FeatureAccess, FeatureIterator, FeatureProcessor,
+    FeatureProperties, GeomProcessor, GeometryType, GeozeroDatasource, GeozeroGeometry,
+    OwnedColumnValue, PropertyProcessor,
 };
-use geojson::{Feature, FeatureReader};
+use geojson::{Feature, FeatureCollection, FeatureReader};
 use geojson::{GeoJson as GeoGeoJson, Geometry, Value};
 use serde_json::map::Map;
 use serde_json::value::Value as JsonValue;
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::io::Read;
+use std::rc::Rc;
 
 /// GeoJSON String.
 #[derive(Debug)]
@@ -49,6 +53,70 @@ impl<R: Read> GeozeroDatasource for GeoJsonReader<R> {
     }
 }
 
+impl GeoJson<'_> {
+    /// Iterate over the features of a FeatureCollection.
+    ///
+    /// A single Feature or Geometry is returned as one feature.
+    pub fn feature_iter(&self) -> Result<GeoJsonFeatureIter> {
+        let features = match self.0.parse::<GeoGeoJson>()? {
+            GeoGeoJson::FeatureCollection(collection) => collection.features,
+            GeoGeoJson::Feature(feature) => vec![feature],
+            GeoGeoJson::Geometry(geometry) => vec![Feature::from(geometry)],
+        };
+        Ok(GeoJsonFeatureIter(features.into_iter()))
+    }
+}
+
+impl<R: Read> GeoJsonReader<R> {
+    /// Iterate over the features of a FeatureCollection.
+    ///
+    /// The input is parsed completely before returning the first feature.
+    pub fn feature_iter(mut self) -> Result<GeoJsonFeatureIter> {
+        let mut geojson_str = String::new();
+        self.0.read_to_string(&mut geojson_str)?;
+        GeoJson(&geojson_str).feature_iter()
+    }
+}
+
+/// Iterator over owned GeoJSON features.
+pub struct GeoJsonFeatureIter(std::vec::IntoIter<Feature>);
+
+impl FeatureIterator for GeoJsonFeatureIter {
+    type Feature<'a> = Feature;
+    fn next_feature(&mut self) -> Result<Option<Feature>> {
+        Ok(self.0.next())
+    }
+}
+
+impl Iterator for GeoJsonFeatureIter {
+    type Item = Result<Feature>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(Ok)
+    }
+}
+
+impl GeozeroGeometry for Feature {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        match self.geometry {
+            Some(ref geometry) => process_geojson_geom_n(geometry, 0, processor),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FeatureProperties for Feature {
+    fn process_properties<P: PropertyProcessor>(&self, processor: &mut P) -> Result<bool> {
+        for (i, (key, value)) in self.properties.iter().flatten().enumerate() {
+            if process_property(i, key, value, processor)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl FeatureAccess for Feature {}
+
 /// Read and process GeoJSON.
 pub fn read_geojson<R: Read, P: FeatureProcessor>(mut reader: R, processor: &mut P) -> Result<()> {
     let mut geojson_str = String::new();
@@ -57,12 +125,44 @@ pub fn read_geojson<R: Read, P: FeatureProcessor>(mut reader: R, processor: &mut
     process_geojson(&geojson, processor)
 }
 
+/// Read and process the features of a GeoJSON FeatureCollection one at a time.
+///
+/// In contrast to [`read_geojson`], features are processed while reading and reading
+/// stops when the processor is [done](FeatureProcessor::done). No schema is announced,
+/// since it depends on all features of the collection.
 pub fn read_geojson_fc<R: Read, P: FeatureProcessor>(reader: R, processor: &mut P) -> Result<()> {
+    let bytes = Rc::new(Cell::new(0));
+    let reader = CountingReader {
+        inner: reader,
+        bytes: bytes.clone(),
+    };
+    processor.dataset_begin(None)?;
     for (idx, feature) in FeatureReader::from_reader(reader).features().enumerate() {
-        process_geojson_feature(&feature?, idx, processor)?;
+        let context = || ErrorContext::new().with_feature(idx as u64);
+        let feature = feature.map_err(GeozeroError::from).with_context(context)?;
+        processor
+            .bytes_read(bytes.get(), None)
+            .with_context(context)?;
+        process_feature(&feature, idx, processor).with_context(context)?;
+        if processor.done() {
+            break;
+        }
     }
+    processor.dataset_end()
+}
 
-    Ok(())
+/// Reader counting the consumed bytes
+struct CountingReader<R> {
+    inner: R,
+    bytes: Rc<Cell<u64>>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.bytes.set(self.bytes.get() + n as u64);
+        Ok(n)
+    }
 }
 
 /// Read and process GeoJSON geometry.
@@ -81,19 +181,19 @@ fn process_geojson<P: FeatureProcessor>(gj: &GeoGeoJson, processor: &mut P) -> R
     match *gj {
         GeoGeoJson::FeatureCollection(ref collection) => {
             processor.dataset_begin(None)?;
+            let (columns, geometry_type) = feature_collection_schema(collection);
+            processor.schema(&columns, geometry_type)?;
+            let column_idx: HashMap<&str, usize> = columns
+                .iter()
+                .enumerate()
+                .map(|(i, column)| (column.name.as_str(), i))
+                .collect();
             for (idx, feature) in collection.features.iter().enumerate() {
-                processor.feature_begin(idx as u64)?;
-                if let Some(ref properties) = feature.properties {
-                    processor.properties_begin()?;
-                    process_properties(properties, processor)?;
-                    processor.properties_end()?;
-                }
-                if let Some(ref geometry) = feature.geometry {
-                    processor.geometry_begin()?;
-                    process_geojson_geom_n(geometry, idx, processor)?;
-                    processor.geometry_end()?;
+                process_collection_feature(feature, idx, &column_idx, processor)
+                    .with_context(|| ErrorContext::new().with_feature(idx as u64))?;
+                if processor.done() {
+                    break;
                 }
-                processor.feature_end(idx as u64)?;
             }
             processor.dataset_end()
         }
@@ -102,30 +202,139 @@ fn process_geojson<P: FeatureProcessor>(gj: &GeoGeoJson, processor: &mut P) -> R
     }
 }
 
-/// Process top-level GeoJSON items
-fn process_geojson_feature<P: FeatureProcessor>(
+/// Process a FeatureCollection member with properties in schema order
+fn process_collection_feature<P: FeatureProcessor>(
     feature: &Feature,
     idx: usize,
+    column_idx: &HashMap<&str, usize>,
     processor: &mut P,
 ) -> Result<()> {
-    processor.dataset_begin(None)?;
-    if feature.geometry.is_some() || feature.properties.is_some() {
-        processor.feature_begin(idx as u64)?;
-        if let Some(ref properties) = feature.properties {
-            processor.properties_begin()?;
-            process_properties(properties, processor)?;
-            processor.properties_end()?;
+    processor.feature_begin(idx as u64)?;
+    if let Some(ref properties) = feature.properties {
+        processor.properties_begin()?;
+        for (key, value) in properties {
+            process_property(column_idx[key.as_str()], key, value, processor)?;
+        }
+        processor.properties_end()?;
+    }
+    if let Some(ref geometry) = feature.geometry {
+        processor.geometry_begin()?;
+        process_geojson_geom_n(geometry, idx, processor)?;
+        processor.geometry_end()?;
+    }
+    processor.feature_end(idx as u64)
+}
+
+/// Collect the property columns and the common geometry type of all features
+fn feature_collection_schema(collection: &FeatureCollection) -> (Vec<ColumnInfo>, GeometryType) {
+    let mut columns: Vec<ColumnInfo> = Vec::new();
+    let mut column_idx: HashMap<&str, usize> = HashMap::new();
+    // Number of features with this property and whether a non-null value was seen
+    let mut counts: Vec<(usize, bool)> = Vec::new();
+    let mut geometry_type = None;
+    for feature in &collection.features {
+        for (key, value) in feature.properties.iter().flatten() {
+            let i = *column_idx.entry(key).or_insert_with(|| {
+                columns.push(ColumnInfo {
+                    name: key.clone(),
+                    column_type: None,
+                    nullable: false,
+                });
+                counts.push((0, false));
+                columns.len() - 1
+            });
+            let (count, typed) = &mut counts[i];
+            *count += 1;
+            let column = &mut columns[i];
+            match json_column_type(value) {
+                None => column.nullable = true,
+                Some(column_type) if !*typed => {
+                    *typed = true;
+                    column.column_type = Some(column_type);
+                }
+                Some(column_type) if column.column_type != Some(column_type) => {
+                    // Mixed types
+                    column.column_type = None;
+                }
+                Some(_) => {}
+            }
         }
         if let Some(ref geometry) = feature.geometry {
-            processor.geometry_begin()?;
-            process_geojson_geom_n(geometry, idx, processor)?;
-            processor.geometry_end()?;
+            let feature_type = geojson_geometry_type(geometry);
+            geometry_type = match geometry_type {
+                None => Some(feature_type),
+                Some(t) if t == feature_type => Some(t),
+                Some(_) => Some(GeometryType::Unknown),
+            };
+        }
+    }
+    for (column, (count, _)) in columns.iter_mut().zip(counts) {
+        if count < collection.features.len() {
+            column.nullable = true;
         }
-        processor.feature_end(idx as u64)?;
     }
+    (columns, geometry_type.unwrap_or_default())
+}
+
+fn json_column_type(value: &JsonValue) -> Option<ColumnType> {
+    match value {
+        JsonValue::String(_) => Some(ColumnType::String),
+        JsonValue::Number(v) if v.is_f64() => Some(ColumnType::Double),
+        JsonValue::Number(v) if v.is_i64() => Some(ColumnType::Long),
+        JsonValue::Number(_) => Some(ColumnType::ULong),
+        JsonValue::Bool(_) => Some(ColumnType::Bool),
+        JsonValue::Array(_) => Some(ColumnType::List),
+        JsonValue::Object(_) => Some(ColumnType::Map),
+        JsonValue::Null => None,
+    }
+}
+
+fn geojson_geometry_type(geometry: &Geometry) -> GeometryType {
+    match geometry.value {
+        Value::Point(_) => GeometryType::Point,
+        Value::MultiPoint(_) => GeometryType::MultiPoint,
+        Value::LineString(_) => GeometryType::LineString,
+        Value::MultiLineString(_) => GeometryType::MultiLineString,
+        Value::Polygon(_) => GeometryType::Polygon,
+        Value::MultiPolygon(_) => GeometryType::MultiPolygon,
+        Value::GeometryCollection(_) => GeometryType::GeometryCollection,
+    }
+}
+
+/// Process top-level GeoJSON items
+fn process_geojson_feature<P: FeatureProcessor>(
+    feature: &Feature,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    processor.dataset_begin(None)?;
+    process_feature(feature, idx, processor)?;
     processor.dataset_end()
 }
 
+/// Process a feature, omitting features without geometry and properties
+fn process_feature<P: FeatureProcessor>(
+    feature: &Feature,
+    idx: usize,
+    processor: &mut P,
+) -> Result<()> {
+    if feature.geometry.is_none() && feature.properties.is_none() {
+        return Ok(());
+    }
+    processor.feature_begin(idx as u64)?;
+    if let Some(ref properties) = feature.properties {
+        processor.properties_begin()?;
+        process_properties(properties, processor)?;
+        processor.properties_end()?;
+    }
+    if let Some(ref geometry) = feature.geometry {
+        processor.geometry_begin()?;
+        process_geojson_geom_n(geometry, idx, processor)?;
+        processor.geometry_end()?;
+    }
+    processor.feature_end(idx as u64)
+}
+
 /// Process top-level GeoJSON items (geometry only)
 fn process_geojson_geom<P: GeomProcessor>(gj: &GeoGeoJson, processor: &mut P) -> Result<()> {
     match *gj {
@@ -204,38 +413,41 @@ pub(crate) fn process_properties<P: PropertyProcessor>(
     processor: &mut P,
 ) -> Result<()> {
     for (i, (key, value)) in properties.iter().enumerate() {
-        // Could we provide a stable property index?
-        match value {
-            JsonValue::String(v) => processor.property(i, key, &ColumnValue::String(v))?,
-            JsonValue::Number(v) => {
-                if v.is_f64() {
-                    processor.property(i, key, &ColumnValue::Double(v.as_f64().unwrap()))?
-                } else if v.is_i64() {
-                    processor.property(i, key, &ColumnValue::Long(v.as_i64().unwrap()))?
-                } else if v.is_u64() {
-                    processor.property(i, key, &ColumnValue::ULong(v.as_u64().unwrap()))?
-                } else {
-                    unreachable!()
-                }
-            }
-            JsonValue::Bool(v) => processor.property(i, key, &ColumnValue::Bool(*v))?,
-            JsonValue::Array(v) => {
-                let json_string =
-                    serde_json::to_string(v).map_err(|_err| GeozeroError::Property(key.clone()))?;
-                processor.property(i, key, &ColumnValue::Json(&json_string))?
-            }
-            JsonValue::Object(v) => {
-                let json_string =
-                    serde_json::to_string(v).map_err(|_err| GeozeroError::Property(key.clone()))?;
-                processor.property(i, key, &ColumnValue::Json(&json_string))?
-            }
-            // For null values omit the property
-            JsonValue::Null => false,
-        };
+        process_property(i, key, value, processor)?;
     }
     Ok(())
 }
 
+/// Process a single GeoJSON property
+fn process_property<P: PropertyProcessor>(
+    i: usize,
+    key: &str,
+    value: &JsonValue,
+    processor: &mut P,
+) -> Result<bool> {
+    let result = match value {
+        JsonValue::String(v) => processor.property(i, key, &ColumnValue::String(v)),
+        JsonValue::Number(v) => {
+            if v.is_f64() {
+                processor.property(i, key, &ColumnValue::Double(v.as_f64().unwrap()))
+            } else if v.is_i64() {
+                processor.property(i, key, &ColumnValue::Long(v.as_i64().unwrap()))
+            } else if v.is_u64() {
+                processor.property(i, key, &ColumnValue::ULong(v.as_u64().unwrap()))
+            } else {
+                unreachable!()
+            }
+        }
+        JsonValue::Bool(v) => processor.property(i, key, &ColumnValue::Bool(*v)),
+        JsonValue::Array(_) | JsonValue::Object(_) => {
+            let value = OwnedColumnValue::from(value);
+            processor.property(i, key, &value.as_column_value())
+        }
+        JsonValue::Null => processor.property(i, key, &ColumnValue::Null),
+    };
+    result.with_context(|| ErrorContext::new().with_property(key))
+}
+
 type Position = Vec<f64>;
 type PointType = Position;
 type LineStringType = Vec<Position>;
@@ -294,7 +506,10 @@ mod test {
     use super::*;
     use crate::geojson::GeoJsonWriter;
     use crate::wkt::WktWriter;
-    use crate::{CoordDimensions, ProcessToSvg, ToJson, ToWkt};
+    use crate::{
+        CoordDimensions, FeatureRecorder, LimitProcessor, ProcessToSvg, ProcessorSink, Progress,
+        ProgressProcessor, ToJson, ToWkt,
+    };
     use std::fs::File;
 
     #[test]
@@ -425,6 +640,63 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn fc_stops_reading() -> Result<()> {
+        let input = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"id": 0}, "geometry": {"type": "Point", "coordinates": [0, 0]}},
+            {"type": "Feature", "properties": {"id": 1}, "geometry": {"type": "Point", "coordinates": [1, 1]}},
+            invalid
+        ]}"#;
+        let mut processor = LimitProcessor::new(FeatureRecorder::new(), 2);
+        read_geojson_fc(input.as_bytes(), &mut processor)?;
+        assert_eq!(processor.into_inner().features().len(), 2);
+
+        let mut processor = LimitProcessor::new(ProcessorSink, 3);
+        assert!(read_geojson_fc(input.as_bytes(), &mut processor).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn fc_bytes_read() -> Result<()> {
+        let input = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"id": 0}, "geometry": {"type": "Point", "coordinates": [0, 0]}},
+            {"type": "Feature", "properties": {"id": 1}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+        ]}"#;
+        let mut bytes = Vec::new();
+        let mut processor = ProgressProcessor::new(ProcessorSink, |progress: &Progress| {
+            bytes.push(progress.bytes.unwrap());
+        });
+        processor.set_interval(1);
+        read_geojson_fc(input.as_bytes(), &mut processor)?;
+        // After the first and second feature and at the end of the dataset
+        assert_eq!(bytes.len(), 3);
+        assert!(bytes[0] < bytes[1] && bytes[1] <= input.len() as u64);
+        Ok(())
+    }
+
+    #[test]
+    fn feature_iter() -> Result<()> {
+        let f = File::open("tests/data/places.json")?;
+        let mut features = GeoJsonReader(f).feature_iter()?;
+        let feature = features.next_feature()?.unwrap();
+        assert_eq!(
+            FeatureProperties::property::<String>(&feature, "NAME")?,
+            "Bombo"
+        );
+        assert_eq!(
+            feature.to_wkt()?,
+            "POINT(32.533299524864844 0.583299105614628)"
+        );
+        assert_eq!(features.count(), 1248);
+
+        let mut features =
+            GeoJson(r#"{"type": "Point", "coordinates": [10,20]}"#).feature_iter()?;
+        let mut wkt_data: Vec<u8> = Vec::new();
+        features.process_features(&mut WktWriter::new(&mut wkt_data))?;
+        assert_eq!(std::str::from_utf8(&wkt_data).unwrap(), "POINT(10 20)");
+        Ok(())
+    }
+
     #[test]
     fn conversions() -> Result<()> {
         let geojson = GeoJson(r#"{"type": "Point", "coordinates": [10,20]}"#);
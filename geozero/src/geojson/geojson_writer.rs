@@ -1,24 +1,26 @@
 use crate::error::Result;
 use crate::{ColumnValue, CoordDimensions, FeatureProcessor, GeomProcessor, PropertyProcessor};
-use std::fmt::Display;
 use std::io::Write;
 
 /// GeoJSON writer.
 pub struct GeoJsonWriter<W: Write> {
     dims: CoordDimensions,
+    /// No property of the current feature has been written yet
+    first_property: bool,
     pub(crate) out: W,
 }
 
 impl<W: Write> GeoJsonWriter<W> {
     pub fn new(out: W) -> Self {
+        Self::with_dims(out, CoordDimensions::default())
+    }
+    pub fn with_dims(out: W, dims: CoordDimensions) -> Self {
         GeoJsonWriter {
-            dims: CoordDimensions::default(),
+            dims,
+            first_property: true,
             out,
         }
     }
-    pub fn with_dims(out: W, dims: CoordDimensions) -> Self {
-        GeoJsonWriter { dims, out }
-    }
     fn comma(&mut self, idx: usize) -> Result<()> {
         if idx > 0 {
             self.out.write_all(b",")?;
@@ -58,6 +60,7 @@ impl<W: Write> FeatureProcessor for GeoJsonWriter<W> {
         Ok(())
     }
     fn properties_begin(&mut self) -> Result<()> {
+        self.first_property = true;
         self.out.write_all(br#", "properties": {"#)?;
         Ok(())
     }
@@ -195,17 +198,9 @@ impl<W: Write> GeomProcessor for GeoJsonWriter<W> {
     }
 }
 
-fn write_num_prop<W: Write>(mut out: W, colname: &str, v: &dyn Display) -> Result<()> {
-    let colname = colname.replace('\"', "\\\"");
-    out.write_all(format!(r#""{colname}": {v}"#).as_bytes())?;
-    Ok(())
-}
-
-fn write_str_prop<W: Write>(mut out: W, colname: &str, v: &str) -> Result<()> {
-    let colname = colname.replace('\"', "\\\"");
-    let value = v.replace('\"', "\\\"");
-    out.write_all(format!(r#""{colname}": "{value}""#).as_bytes())?;
-    Ok(())
+/// JSON string with escaped quotes
+fn json_str(v: &str) -> String {
+    format!(r#""{}""#, v.replace('\"', "\\\""))
 }
 
 fn write_json_prop<W: Write>(mut out: W, colname: &str, v: &str) -> Result<()> {
@@ -215,28 +210,36 @@ fn write_json_prop<W: Write>(mut out: W, colname: &str, v: &str) -> Result<()> {
 }
 
 impl<W: Write> PropertyProcessor for GeoJsonWriter<W> {
-    fn property(&mut self, i: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
-        if i > 0 {
+    fn property(&mut self, _i: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
+        let value = match colval {
+            ColumnValue::Byte(v) => v.to_string(),
+            ColumnValue::UByte(v) => v.to_string(),
+            ColumnValue::Bool(v) => v.to_string(),
+            ColumnValue::Short(v) => v.to_string(),
+            ColumnValue::UShort(v) => v.to_string(),
+            ColumnValue::Int(v) => v.to_string(),
+            ColumnValue::UInt(v) => v.to_string(),
+            ColumnValue::Long(v) => v.to_string(),
+            ColumnValue::ULong(v) => v.to_string(),
+            ColumnValue::Float(v) => v.to_string(),
+            ColumnValue::Double(v) => v.to_string(),
+            ColumnValue::String(v) | ColumnValue::DateTime(v) => json_str(v),
+            ColumnValue::Json(v) => v.to_string(),
+            // Binary values have no GeoJSON representation
+            ColumnValue::Binary(_) => return Ok(false),
+            ColumnValue::Null => "null".to_string(),
+            ColumnValue::List(_) | ColumnValue::Map(_) => colval.json_value()?.to_string(),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Date(_)
+            | ColumnValue::Time(_)
+            | ColumnValue::Timestamp(_)
+            | ColumnValue::TimestampTz(_) => json_str(&colval.to_string()),
+        };
+        if !self.first_property {
             self.out.write_all(b", ")?;
         }
-        match colval {
-            ColumnValue::Byte(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::UByte(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::Bool(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::Short(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::UShort(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::Int(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::UInt(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::Long(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::ULong(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::Float(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::Double(v) => write_num_prop(&mut self.out, colname, &v)?,
-            ColumnValue::String(v) | ColumnValue::DateTime(v) => {
-                write_str_prop(&mut self.out, colname, v)?;
-            }
-            ColumnValue::Json(v) => write_json_prop(&mut self.out, colname, v)?,
-            ColumnValue::Binary(_v) => (),
-        };
+        self.first_property = false;
+        write_json_prop(&mut self.out, colname, &value)?;
         Ok(false)
     }
 }
@@ -497,6 +500,31 @@ mod test {
         assert_json_eq(&out, geojson);
     }
 
+    #[test]
+    fn null_property() {
+        let geojson = r#"{
+  "type": "FeatureCollection",
+  "features": [
+    {
+      "type": "Feature",
+      "properties": {
+        "id": "NZL",
+        "population": null,
+        "nested": [{"a": null, "b": [1.5, "x"]}, null]
+      },
+      "geometry": {
+        "type": "Point",
+        "coordinates": [-80, 40]
+      }
+    }
+  ]
+}
+        "#;
+        let mut out: Vec<u8> = Vec::new();
+        assert!(read_geojson(geojson.as_bytes(), &mut GeoJsonWriter::new(&mut out)).is_ok());
+        assert_json_eq(&out, geojson);
+    }
+
     fn assert_json_eq(a: &[u8], b: &str) {
         let a = std::str::from_utf8(a).unwrap();
         let a: serde_json::Value = serde_json::from_str(a).unwrap();
@@ -86,3 +86,71 @@ mod wkb {
     // crate::impl_sqlx_gpkg_decode!(GeoJsonString);
     // crate::impl_sqlx_gpkg_encode!(GeoJsonString);
 }
+
+pub(crate) mod driver {
+    use crate::error::Result;
+    use crate::geojson::{GeoJsonLineReader, GeoJsonLineWriter, GeoJsonReader, GeoJsonWriter};
+    use crate::registry::{trim_end, trim_start};
+    use crate::{Driver, DriverOptions, DynDatasource, FeatureProcessor};
+    use std::io::{BufRead, Write};
+
+    pub(crate) const GEOJSON: Driver = Driver {
+        name: "GeoJSON",
+        extensions: &["geojson", "json"],
+        sniff: Some(sniff_geojson),
+        open: Some(open_geojson),
+        create: Some(create_geojson),
+    };
+
+    pub(crate) const GEOJSON_LINES: Driver = Driver {
+        name: "GeoJSONL",
+        extensions: &["geojsonl", "jsonl"],
+        sniff: Some(sniff_geojson_lines),
+        open: Some(open_geojson_lines),
+        create: Some(create_geojson_lines),
+    };
+
+    fn sniff_geojson(buf: &[u8], _options: &DriverOptions) -> bool {
+        trim_start(buf).starts_with(b"{")
+    }
+
+    /// A complete object on the first line, followed by another object
+    fn sniff_geojson_lines(buf: &[u8], _options: &DriverOptions) -> bool {
+        let buf = trim_start(buf);
+        let Some(eol) = buf.iter().position(|b| *b == b'\n') else {
+            return false;
+        };
+        let first_line = trim_end(&buf[..eol]);
+        first_line.starts_with(b"{")
+            && first_line.ends_with(b"}")
+            && trim_start(&buf[eol..]).starts_with(b"{")
+    }
+
+    fn open_geojson<'a>(
+        reader: Box<dyn BufRead + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn DynDatasource + 'a>> {
+        Ok(Box::new(GeoJsonReader(reader)))
+    }
+
+    fn open_geojson_lines<'a>(
+        reader: Box<dyn BufRead + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn DynDatasource + 'a>> {
+        Ok(Box::new(GeoJsonLineReader::new(reader)))
+    }
+
+    fn create_geojson<'a>(
+        out: Box<dyn Write + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn FeatureProcessor + 'a>> {
+        Ok(Box::new(GeoJsonWriter::new(out)))
+    }
+
+    fn create_geojson_lines<'a>(
+        out: Box<dyn Write + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn FeatureProcessor + 'a>> {
+        Ok(Box::new(GeoJsonLineWriter::new(out)))
+    }
+}
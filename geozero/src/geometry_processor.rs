@@ -1,7 +1,9 @@
 use crate::error::{GeozeroError, Result};
+use crate::forward::forward_geom_processor;
 
 /// Dimensions requested for processing
-#[derive(Default, Clone, Copy)]
+#[derive(Default, Clone, Copy, Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
 pub struct CoordDimensions {
     /// height
     pub z: bool,
@@ -48,6 +50,32 @@ impl CoordDimensions {
     }
 }
 
+/// Geometry type of a dataset or feature
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
+pub enum GeometryType {
+    /// Unknown or mixed geometry types
+    #[default]
+    Unknown,
+    Point,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    CircularString,
+    CompoundCurve,
+    CurvePolygon,
+    MultiCurve,
+    MultiSurface,
+    Curve,
+    Surface,
+    PolyhedralSurface,
+    Tin,
+    Triangle,
+}
+
 /// Geometry processing trait
 ///
 /// # Usage example:
@@ -382,6 +410,14 @@ pub trait GeomProcessor {
     }
 }
 
+impl<P: GeomProcessor + ?Sized> GeomProcessor for &mut P {
+    forward_geom_processor!(*);
+}
+
+impl<P: GeomProcessor + ?Sized> GeomProcessor for Box<P> {
+    forward_geom_processor!(*);
+}
+
 #[test]
 fn error_message() {
     use crate::error::GeozeroError;
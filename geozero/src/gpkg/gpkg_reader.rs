@@ -0,0 +1,192 @@
+use crate::error::{GeozeroError, Result};
+use crate::wkb::process_gpkg_geom;
+use crate::{ColumnInfo, ColumnType, ColumnValue, FeatureProcessor, GeometryType};
+use futures_util::TryStreamExt;
+use sqlx::sqlite::{SqlitePool, SqliteRow};
+use sqlx::{Row, TypeInfo, ValueRef};
+
+/// Process all features of a GeoPackage feature table.
+///
+/// The table columns are announced as schema, with the geometry type registered in
+/// `gpkg_geometry_columns`. NULL values are processed as `ColumnValue::Null`.
+/// With the `with-chrono` feature, DATE and DATETIME values are processed as `Date`,
+/// `Timestamp` or `TimestampTz` values.
+///
+/// # Usage example:
+///
+/// ```
+/// use geozero::gpkg::process_table;
+/// use geozero::ProcessorSink;
+/// use sqlx::sqlite::SqlitePoolOptions;
+///
+/// # async fn read_table() -> geozero::error::Result<()> {
+/// let pool = SqlitePoolOptions::new()
+///     .connect("sqlite://points.gpkg")
+///     .await
+///     .unwrap();
+/// process_table(&pool, "pt2d", &mut ProcessorSink).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn process_table<P: FeatureProcessor>(
+    pool: &SqlitePool,
+    table: &str,
+    processor: &mut P,
+) -> Result<()> {
+    let (geometry_column, geometry_type): (String, String) = sqlx::query_as(
+        "SELECT column_name, geometry_type_name FROM gpkg_geometry_columns WHERE table_name = ?",
+    )
+    .bind(table)
+    .fetch_one(pool)
+    .await
+    .map_err(sqlx_error)?;
+    let table_info: Vec<(String, String, bool, i64)> = sqlx::query_as(
+        r#"SELECT name, type, "notnull", pk FROM pragma_table_info(?) ORDER BY cid"#,
+    )
+    .bind(table)
+    .fetch_all(pool)
+    .await
+    .map_err(sqlx_error)?;
+    let columns: Vec<ColumnInfo> = table_info
+        .iter()
+        .filter(|(name, ..)| *name != geometry_column)
+        .map(|(name, column_type, notnull, pk)| ColumnInfo {
+            name: name.clone(),
+            column_type: column_type_from_decl(column_type),
+            nullable: !notnull && *pk == 0,
+        })
+        .collect();
+
+    processor.dataset_begin(Some(table))?;
+    processor.schema(&columns, geometry_type_from_name(&geometry_type))?;
+    let query = format!(r#"SELECT * FROM "{}""#, table.replace('"', "\"\""));
+    let mut rows = sqlx::query(&query).fetch(pool);
+    let mut idx = 0;
+    while let Some(row) = rows.try_next().await.map_err(sqlx_error)? {
+        processor.feature_begin(idx)?;
+        processor.properties_begin()?;
+        for (i, column) in columns.iter().enumerate() {
+            if process_value(&row, i, column, processor)? {
+                break;
+            }
+        }
+        processor.properties_end()?;
+        let geometry: Option<&[u8]> = row.try_get(geometry_column.as_str()).map_err(sqlx_error)?;
+        if let Some(mut geometry) = geometry {
+            processor.geometry_begin()?;
+            process_gpkg_geom(&mut geometry, processor)?;
+            processor.geometry_end()?;
+        }
+        processor.feature_end(idx)?;
+        if processor.done() {
+            break;
+        }
+        idx += 1;
+    }
+    processor.dataset_end()
+}
+
+fn process_value<P: FeatureProcessor>(
+    row: &SqliteRow,
+    idx: usize,
+    column: &ColumnInfo,
+    processor: &mut P,
+) -> Result<bool> {
+    let name = column.name.as_str();
+    let raw = row.try_get_raw(name).map_err(sqlx_error)?;
+    if raw.is_null() {
+        return processor.property(idx, name, &ColumnValue::Null);
+    }
+    // SQLite values may have a different storage class than the declared column type
+    let storage = raw.type_info().name().to_string();
+    match storage.as_str() {
+        "INTEGER" => {
+            let v: i64 = row.try_get(name).map_err(sqlx_error)?;
+            let value = match column.column_type {
+                Some(ColumnType::Bool) => ColumnValue::Bool(v != 0),
+                Some(ColumnType::Byte) => ColumnValue::Byte(v as i8),
+                Some(ColumnType::Short) => ColumnValue::Short(v as i16),
+                Some(ColumnType::Int) => ColumnValue::Int(v as i32),
+                Some(ColumnType::Float) => ColumnValue::Float(v as f32),
+                Some(ColumnType::Double) => ColumnValue::Double(v as f64),
+                _ => ColumnValue::Long(v),
+            };
+            processor.property(idx, name, &value)
+        }
+        "REAL" => {
+            let v: f64 = row.try_get(name).map_err(sqlx_error)?;
+            let value = match column.column_type {
+                Some(ColumnType::Float) => ColumnValue::Float(v as f32),
+                _ => ColumnValue::Double(v),
+            };
+            processor.property(idx, name, &value)
+        }
+        "TEXT" => {
+            let v: &str = row.try_get(name).map_err(sqlx_error)?;
+            let value = match column.column_type {
+                Some(ColumnType::DateTime) => ColumnValue::DateTime(v),
+                #[cfg(feature = "with-chrono")]
+                Some(ColumnType::Date | ColumnType::TimestampTz) => {
+                    // Values not in ISO 8601 format are processed as string
+                    let value = ColumnValue::DateTime(v);
+                    value.parse_datetime().unwrap_or(value)
+                }
+                _ => ColumnValue::String(v),
+            };
+            processor.property(idx, name, &value)
+        }
+        _ => {
+            let v: &[u8] = row.try_get(name).map_err(sqlx_error)?;
+            processor.property(idx, name, &ColumnValue::Binary(v))
+        }
+    }
+}
+
+/// Column type of a GeoPackage column type declaration like `TEXT(20)`
+fn column_type_from_decl(decl: &str) -> Option<ColumnType> {
+    let decl = decl.to_ascii_uppercase();
+    let column_type = match decl.split('(').next().unwrap_or_default().trim() {
+        "BOOLEAN" => ColumnType::Bool,
+        "TINYINT" => ColumnType::Byte,
+        "SMALLINT" => ColumnType::Short,
+        "MEDIUMINT" => ColumnType::Int,
+        "INT" | "INTEGER" => ColumnType::Long,
+        "FLOAT" => ColumnType::Float,
+        "DOUBLE" | "REAL" => ColumnType::Double,
+        "TEXT" => ColumnType::String,
+        "BLOB" => ColumnType::Binary,
+        #[cfg(feature = "with-chrono")]
+        "DATE" => ColumnType::Date,
+        // GeoPackage datetimes are in UTC, e.g. `2024-05-17T10:30:00.000Z`
+        #[cfg(feature = "with-chrono")]
+        "DATETIME" => ColumnType::TimestampTz,
+        #[cfg(not(feature = "with-chrono"))]
+        "DATE" | "DATETIME" => ColumnType::DateTime,
+        _ => return None,
+    };
+    Some(column_type)
+}
+
+fn geometry_type_from_name(name: &str) -> GeometryType {
+    match name.to_ascii_uppercase().as_str() {
+        "POINT" => GeometryType::Point,
+        "LINESTRING" => GeometryType::LineString,
+        "POLYGON" => GeometryType::Polygon,
+        "MULTIPOINT" => GeometryType::MultiPoint,
+        "MULTILINESTRING" => GeometryType::MultiLineString,
+        "MULTIPOLYGON" => GeometryType::MultiPolygon,
+        "GEOMCOLLECTION" => GeometryType::GeometryCollection,
+        "CIRCULARSTRING" => GeometryType::CircularString,
+        "COMPOUNDCURVE" => GeometryType::CompoundCurve,
+        "CURVEPOLYGON" => GeometryType::CurvePolygon,
+        "MULTICURVE" => GeometryType::MultiCurve,
+        "MULTISURFACE" => GeometryType::MultiSurface,
+        "CURVE" => GeometryType::Curve,
+        "SURFACE" => GeometryType::Surface,
+        _ => GeometryType::Unknown,
+    }
+}
+
+fn sqlx_error(error: sqlx::Error) -> GeozeroError {
+    GeozeroError::Dataset(error.to_string())
+}
@@ -28,3 +28,6 @@
 //! ```
 
 mod geopackage;
+mod gpkg_reader;
+
+pub use gpkg_reader::*;
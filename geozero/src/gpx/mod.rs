@@ -2,3 +2,30 @@ mod gpx_reader;
 
 pub use gpx_reader::read_gpx;
 pub use gpx_reader::{Gpx, GpxReader};
+
+pub(crate) mod driver {
+    use crate::error::Result;
+    use crate::gpx::GpxReader;
+    use crate::registry::trim_start;
+    use crate::{Driver, DriverOptions, DynDatasource};
+    use std::io::BufRead;
+
+    pub(crate) const GPX: Driver = Driver {
+        name: "GPX",
+        extensions: &["gpx"],
+        sniff: Some(sniff),
+        open: Some(open),
+        create: None,
+    };
+
+    fn sniff(buf: &[u8], _options: &DriverOptions) -> bool {
+        trim_start(buf).starts_with(b"<") && buf.windows(4).any(|w| w == b"<gpx")
+    }
+
+    fn open<'a>(
+        reader: Box<dyn BufRead + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn DynDatasource + 'a>> {
+        Ok(Box::new(GpxReader(reader)))
+    }
+}
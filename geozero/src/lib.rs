@@ -30,7 +30,7 @@
 //! | MVT           | [mvt::tile::Feature]                                                                                                     | XY         | [mvt::tile::Layer]                                                                   | [ToMvt]             | [MvtWriter](mvt::MvtWriter)                     |
 //! | SVG           | -                                                                                                                        | XY         | -                                                                                    | [ToSvg]             | [SvgWriter](svg::SvgWriter)                     |
 //! | WKB           | [Wkb](wkb::Wkb), [Ewkb](wkb::Ewkb), [GpkgWkb](wkb::GpkgWkb), [SpatiaLiteWkb](wkb::SpatiaLiteWkb), [MySQL](wkb::MySQLWkb) | XYZM       | -                                                                                    | [ToWkb]             | [WkbWriter](wkb::WkbWriter)                     |
-//! | WKT           | [wkt::WktStr], [wkt::WktString], [wkt::EwktStr], [wkt::EwktString]                                                       | XYZM       | [wkt::WktReader], [wkt::WktStr], [wkt::WktString], [wkt::EwktStr], [wkt::EwktString] | [ToWkt]             | [WktWriter](wkt::WktWriter)                     |
+//! | WKT           | [wkt::WktStr], [wkt::WktString], [wkt::EwktStr], [wkt::EwktString]                                                       | XYZM       | [wkt::WktReader], [wkt::WktLineReader], [wkt::WktStr], [wkt::WktString], [wkt::EwktStr], [wkt::EwktString] | [ToWkt]             | [WktWriter](wkt::WktWriter)                     |
 
 #![warn(clippy::uninlined_format_args)]
 #![allow(
@@ -49,23 +49,62 @@
 )]
 
 mod api;
+mod bounds;
+mod coord_buffer;
+#[cfg(feature = "with-serde")]
+mod deserialize;
 pub mod error;
 mod feature_processor;
+mod filter;
+mod force_dims;
+mod forward;
 mod geometry_processor;
+mod limit;
+mod linearize;
 mod multiplex;
+mod progress;
 mod property_processor;
+mod record;
+mod registry;
+mod select;
+mod simplify;
+mod snap;
+mod summary;
+mod transform;
+mod validate;
+mod winding;
 
 pub use api::*;
+pub use bounds::*;
+#[cfg(feature = "with-serde")]
+pub use deserialize::*;
 pub use feature_processor::*;
+pub use filter::*;
+pub use force_dims::*;
 pub use geometry_processor::*;
+pub use limit::*;
+pub use linearize::*;
 pub use multiplex::*;
+pub use progress::*;
 pub use property_processor::*;
+pub use record::*;
+pub use registry::*;
+pub use select::*;
+pub use simplify::*;
+pub use snap::*;
+pub use summary::*;
+pub use transform::*;
+pub use validate::*;
+pub use winding::*;
 
 #[cfg(feature = "with-csv")]
 pub mod csv;
 #[cfg(feature = "with-csv")]
 pub use crate::csv::conversion::*;
 
+#[cfg(feature = "with-derive")]
+pub use geozero_derive::GeozeroFeature;
+
 #[cfg(feature = "with-gdal")]
 pub mod gdal;
 #[cfg(feature = "with-gdal")]
@@ -99,6 +138,14 @@ pub mod gpx;
 ))]
 pub mod postgis;
 
+#[cfg(feature = "with-proj")]
+pub mod proj;
+
+#[cfg(feature = "with-rayon")]
+mod parallel;
+#[cfg(feature = "with-rayon")]
+pub use parallel::*;
+
 #[cfg(feature = "with-svg")]
 pub mod svg;
 #[cfg(feature = "with-svg")]
@@ -0,0 +1,287 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+
+/// Processor passing a slice of the features of each dataset to a wrapped processor.
+///
+/// The first `offset` features are skipped, of the remaining features every `step`-th feature
+/// is passed on until `limit` features are passed. Passed features are renumbered
+/// consecutively. Events outside of features are passed through unchanged.
+///
+/// When the limit is reached, the processor is [done](FeatureProcessor::done) and readers
+/// stop reading further features.
+///
+/// # Usage example:
+///
+/// Second and third feature of a collection:
+///
+/// ```
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::{GeozeroDatasource, LimitProcessor};
+/// use geozero::geojson::{GeoJson, GeoJsonWriter};
+///
+/// let input = r#"{"type": "FeatureCollection", "features": [
+///     {"type": "Feature", "properties": {"id": 1}, "geometry": {"type": "Point", "coordinates": [1, 1]}},
+///     {"type": "Feature", "properties": {"id": 2}, "geometry": {"type": "Point", "coordinates": [2, 2]}},
+///     {"type": "Feature", "properties": {"id": 3}, "geometry": {"type": "Point", "coordinates": [3, 3]}},
+///     {"type": "Feature", "properties": {"id": 4}, "geometry": {"type": "Point", "coordinates": [4, 4]}}
+/// ]}"#;
+/// let mut json: Vec<u8> = Vec::new();
+/// let mut processor = LimitProcessor::new(GeoJsonWriter::new(&mut json), 2);
+/// processor.set_offset(1);
+/// GeoJson(input).process(&mut processor).unwrap();
+/// let json = std::str::from_utf8(&json).unwrap();
+/// assert!(json.contains(r#""id": 2"#) && json.contains(r#""id": 3"#));
+/// assert!(!json.contains(r#""id": 4"#));
+/// # }
+/// ```
+pub struct LimitProcessor<P> {
+    processor: P,
+    offset: u64,
+    limit: Option<u64>,
+    step: u64,
+    /// Inside of a skipped feature
+    skip: bool,
+    /// Number of features read in the current dataset
+    read: u64,
+    /// Number of the next feature to pass
+    next: u64,
+    /// Number of features passed to the wrapped processor
+    passed: u64,
+}
+
+impl<P> LimitProcessor<P> {
+    /// Pass the first `limit` features
+    pub fn new(processor: P, limit: u64) -> Self {
+        let mut processor = Self::all(processor);
+        processor.set_limit(Some(limit));
+        processor
+    }
+
+    /// Skip the first `offset` features
+    pub fn with_offset(processor: P, offset: u64) -> Self {
+        let mut processor = Self::all(processor);
+        processor.set_offset(offset);
+        processor
+    }
+
+    /// Pass every `step`-th feature, starting with the first
+    pub fn with_sample(processor: P, step: u64) -> Self {
+        let mut processor = Self::all(processor);
+        processor.set_sample(step);
+        processor
+    }
+
+    fn all(processor: P) -> Self {
+        LimitProcessor {
+            processor,
+            offset: 0,
+            limit: None,
+            step: 1,
+            skip: false,
+            read: 0,
+            next: 0,
+            passed: 0,
+        }
+    }
+
+    /// Set the maximal number of passed features, `None` for no limit
+    pub fn set_limit(&mut self, limit: Option<u64>) {
+        self.limit = limit;
+    }
+
+    /// Set the number of skipped features
+    pub fn set_offset(&mut self, offset: u64) {
+        self.offset = offset;
+    }
+
+    /// Pass only every `step`-th feature after the offset
+    pub fn set_sample(&mut self, step: u64) {
+        self.step = step.max(1);
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    fn limit_reached(&self) -> bool {
+        self.limit.is_some_and(|limit| self.passed >= limit)
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for LimitProcessor<P> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.read = 0;
+        self.next = self.offset;
+        self.passed = 0;
+        self.processor.dataset_begin(name)
+    }
+    fn feature_begin(&mut self, _idx: u64) -> Result<()> {
+        self.skip = self.read != self.next || self.limit_reached();
+        if !self.skip {
+            self.next += self.step;
+        }
+        self.read += 1;
+        if self.skip {
+            Ok(())
+        } else {
+            self.processor.feature_begin(self.passed)
+        }
+    }
+    fn feature_end(&mut self, _idx: u64) -> Result<()> {
+        if self.skip {
+            self.skip = false;
+            Ok(())
+        } else {
+            let idx = self.passed;
+            self.passed += 1;
+            self.processor.feature_end(idx)
+        }
+    }
+    fn done(&self) -> bool {
+        self.limit_reached() || self.processor.done()
+    }
+    forward_feature_processor!(processor; dataset_end, schema, bytes_read);
+    forward_feature_processor!(
+        (processor unless skip);
+        properties_begin, properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: GeomProcessor> GeomProcessor for LimitProcessor<P> {
+    forward_geom_processor!(processor; dimensions, multi_dim);
+    forward_geom_processor!(
+        (processor unless skip);
+        srid, xy, coordinate, empty_point, point_begin, point_end, multipoint_begin, multipoint_end,
+        linestring_begin, linestring_end, multilinestring_begin, multilinestring_end, polygon_begin,
+        polygon_end, multipolygon_begin, multipolygon_end, geometrycollection_begin,
+        geometrycollection_end, circularstring_begin, circularstring_end, compoundcurve_begin,
+        compoundcurve_end, curvepolygon_begin, curvepolygon_end, multicurve_begin, multicurve_end,
+        multisurface_begin, multisurface_end, triangle_begin, triangle_end, polyhedralsurface_begin,
+        polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for LimitProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        if self.skip {
+            Ok(false)
+        } else {
+            self.processor.property(idx, name, value)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-geojson")]
+mod test {
+    use super::*;
+    use crate::geojson::{GeoJson, GeoJsonLineReader, GeoJsonWriter};
+    use crate::{FeatureProperties, FeatureRecorder, GeozeroDatasource, RecordedFeature};
+    use serde_json::json;
+
+    fn input() -> String {
+        let features: Vec<_> = (0..10)
+            .map(|i| {
+                json!({
+                    "type": "Feature",
+                    "properties": {"id": i},
+                    "geometry": {"type": "Point", "coordinates": [i, i]}
+                })
+            })
+            .collect();
+        json!({"type": "FeatureCollection", "features": features}).to_string()
+    }
+
+    fn process(mut processor: LimitProcessor<FeatureRecorder>) -> Result<Vec<RecordedFeature>> {
+        GeoJson(&input()).process(&mut processor)?;
+        Ok(processor.into_inner().into_features())
+    }
+
+    /// Recorded input features with the given ids
+    fn expected(ids: &[usize]) -> Result<Vec<RecordedFeature>> {
+        let features = RecordedFeature::record_all(&mut GeoJson(&input()))?;
+        Ok(ids.iter().map(|id| features[*id].clone()).collect())
+    }
+
+    fn ids(features: &[RecordedFeature]) -> Result<Vec<u64>> {
+        features
+            .iter()
+            .map(|feature| feature.property("id"))
+            .collect()
+    }
+
+    #[test]
+    fn limit() -> Result<()> {
+        let features = process(LimitProcessor::new(FeatureRecorder::new(), 3))?;
+        assert_eq!(features, expected(&[0, 1, 2])?);
+
+        let features = process(LimitProcessor::new(FeatureRecorder::new(), 0))?;
+        assert!(features.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn offset() -> Result<()> {
+        let features = process(LimitProcessor::with_offset(FeatureRecorder::new(), 8))?;
+        assert_eq!(features, expected(&[8, 9])?);
+
+        let mut processor = LimitProcessor::new(FeatureRecorder::new(), 2);
+        processor.set_offset(4);
+        let features = process(processor)?;
+        assert_eq!(ids(&features)?, [4, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn renumbered() -> Result<()> {
+        // Features without geometry, the writer separates features with an index > 0
+        let input = json!({"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"id": 0}, "geometry": null},
+            {"type": "Feature", "properties": {"id": 1}, "geometry": null},
+            {"type": "Feature", "properties": {"id": 2}, "geometry": null}
+        ]})
+        .to_string();
+        let mut json: Vec<u8> = Vec::new();
+        let mut processor = LimitProcessor::with_offset(GeoJsonWriter::new(&mut json), 1);
+        GeoJson(&input).process(&mut processor)?;
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(json["features"][0]["properties"]["id"], 1);
+        assert_eq!(json["features"][1]["properties"]["id"], 2);
+        Ok(())
+    }
+
+    #[test]
+    fn sample() -> Result<()> {
+        let features = process(LimitProcessor::with_sample(FeatureRecorder::new(), 3))?;
+        assert_eq!(features, expected(&[0, 3, 6, 9])?);
+
+        let mut processor = LimitProcessor::with_sample(FeatureRecorder::new(), 4);
+        processor.set_offset(1);
+        processor.set_limit(Some(2));
+        let features = process(processor)?;
+        assert_eq!(ids(&features)?, [1, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn stops_reading() -> Result<()> {
+        let input = r#"{"type": "Feature", "properties": {"id": 0}, "geometry": {"type": "Point", "coordinates": [0, 0]}}
+{"type": "Feature", "properties": {"id": 1}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+invalid
+"#;
+        let mut processor = LimitProcessor::new(FeatureRecorder::new(), 2);
+        GeoJsonLineReader::new(input.as_bytes()).process(&mut processor)?;
+        assert_eq!(ids(processor.into_inner().features())?, [0, 1]);
+
+        let mut processor = LimitProcessor::new(FeatureRecorder::new(), 3);
+        assert!(GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut processor)
+            .is_err());
+        Ok(())
+    }
+}
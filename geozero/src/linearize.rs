@@ -0,0 +1,415 @@
+use crate::coord_buffer::{process_linestring, Coord};
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::{GeomProcessor, GeometryType};
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use std::f64::consts::{PI, TAU};
+
+/// Segmentation tolerance of [`LinearizeProcessor`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ArcTolerance {
+    /// Maximal angle in radians between arc segment end points and the arc center
+    MaxAngle(f64),
+    /// Maximal distance between arc and segments
+    MaxDeviation(f64),
+}
+
+impl Default for ArcTolerance {
+    /// 32 segments per quarter circle, like PostGIS `ST_CurveToLine`
+    fn default() -> Self {
+        ArcTolerance::MaxAngle(PI / 64.0)
+    }
+}
+
+impl ArcTolerance {
+    /// Maximal segment angle for an arc with `radius`
+    fn max_angle(&self, radius: f64) -> f64 {
+        let angle = match *self {
+            ArcTolerance::MaxAngle(angle) => angle,
+            ArcTolerance::MaxDeviation(deviation) if deviation < radius => {
+                2.0 * (1.0 - deviation / radius).acos()
+            }
+            ArcTolerance::MaxDeviation(_) => PI,
+        };
+        // Limit number of segments for tiny or invalid tolerances
+        angle.max(PI / 4096.0)
+    }
+}
+
+/// Processor replacing curve geometries with linear geometries before passing them to a wrapped
+/// processor.
+///
+/// CircularStrings and CompoundCurves are emitted as LineStrings, CurvePolygons as Polygons,
+/// MultiCurves as MultiLineStrings and MultiSurfaces as MultiPolygons. Arcs are approximated by
+/// segments according to the [`ArcTolerance`], the arc end points are kept unchanged.
+/// Z and M values of inserted points are interpolated.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::{ArcTolerance, GeomProcessor, LinearizeProcessor};
+/// use geozero::wkt::WktWriter;
+/// use std::f64::consts::PI;
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor =
+///     LinearizeProcessor::new(WktWriter::new(&mut out), ArcTolerance::MaxAngle(PI / 2.0));
+/// processor.circularstring_begin(3, 0).unwrap();
+/// processor.xy(0.0, 0.0, 0).unwrap();
+/// processor.xy(1.0, 1.0, 1).unwrap();
+/// processor.xy(2.0, 0.0, 2).unwrap();
+/// processor.circularstring_end(0).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING(0 0,1 1,2 0)");
+/// # }
+/// ```
+pub struct LinearizeProcessor<P> {
+    processor: P,
+    tolerance: ArcTolerance,
+    /// Open curve containers
+    open: Vec<GeometryType>,
+    /// Points of the current CircularString or CompoundCurve member
+    points: Option<Vec<Coord>>,
+    /// Linearized points of the current CompoundCurve
+    compound: Option<Vec<Coord>>,
+}
+
+impl<P: GeomProcessor> LinearizeProcessor<P> {
+    pub fn new(processor: P, tolerance: ArcTolerance) -> Self {
+        LinearizeProcessor {
+            processor,
+            tolerance,
+            open: Vec::new(),
+            points: None,
+            compound: None,
+        }
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    fn parent(&self) -> Option<GeometryType> {
+        self.open.last().copied()
+    }
+
+    /// Emit linearized curve or append it to the current CompoundCurve
+    fn curve_end(&mut self, line: Vec<Coord>, idx: usize) -> Result<()> {
+        match self.parent() {
+            Some(GeometryType::CompoundCurve) => {
+                let compound = self.compound.get_or_insert_with(Vec::new);
+                let mut line = line.into_iter().peekable();
+                // Skip shared end point of consecutive members
+                if let (Some(last), Some(first)) = (compound.last(), line.peek()) {
+                    if last.xy() == first.xy() {
+                        line.next();
+                    }
+                }
+                compound.extend(line);
+                Ok(())
+            }
+            Some(GeometryType::CurvePolygon) | Some(GeometryType::MultiCurve) => {
+                process_linestring(&mut self.processor, &line, false, idx)
+            }
+            _ => process_linestring(&mut self.processor, &line, true, idx),
+        }
+    }
+
+    /// Approximate arcs of a CircularString by segments
+    fn linearize(&self, points: &[Coord]) -> Vec<Coord> {
+        let mut line = Vec::with_capacity(points.len());
+        if let Some(first) = points.first() {
+            line.push(*first);
+        }
+        for arc in points.windows(3).step_by(2) {
+            self.linearize_arc(&arc[0], &arc[1], &arc[2], &mut line);
+            line.push(arc[2]);
+        }
+        // Incomplete arc
+        if points.len() > 1 && line.last() != points.last() {
+            line.extend(points.last());
+        }
+        line
+    }
+
+    /// Push points between `start` and `end`
+    fn linearize_arc(&self, start: &Coord, mid: &Coord, end: &Coord, line: &mut Vec<Coord>) {
+        let (p0, p1, p2) = (start.xy(), mid.xy(), end.xy());
+        let (center, sweep) = if p0 == p2 {
+            // Full circle
+            ((0.5 * (p0.0 + p1.0), 0.5 * (p0.1 + p1.1)), TAU)
+        } else {
+            let d = 2.0 * (p0.0 * (p1.1 - p2.1) + p1.0 * (p2.1 - p0.1) + p2.0 * (p0.1 - p1.1));
+            if d == 0.0 || !d.is_finite() {
+                // Collinear points
+                line.push(*mid);
+                return;
+            }
+            let sq = |p: (f64, f64)| p.0 * p.0 + p.1 * p.1;
+            let cx = (sq(p0) * (p1.1 - p2.1) + sq(p1) * (p2.1 - p0.1) + sq(p2) * (p0.1 - p1.1)) / d;
+            let cy = (sq(p0) * (p2.0 - p1.0) + sq(p1) * (p0.0 - p2.0) + sq(p2) * (p1.0 - p0.0)) / d;
+            let a0 = (p0.1 - cy).atan2(p0.0 - cx);
+            let a2 = (p2.1 - cy).atan2(p2.0 - cx);
+            // Counter-clockwise, if the positive sweep from start to end contains mid
+            let sweep = if d > 0.0 {
+                (a2 - a0).rem_euclid(TAU)
+            } else {
+                -(a0 - a2).rem_euclid(TAU)
+            };
+            ((cx, cy), sweep)
+        };
+        let radius = (p0.0 - center.0).hypot(p0.1 - center.1);
+        let a0 = (p0.1 - center.1).atan2(p0.0 - center.0);
+        let a1 = (p1.1 - center.1).atan2(p1.0 - center.0);
+        // Fraction of the sweep at the mid point
+        let mid_fraction = ((a1 - a0) / sweep).rem_euclid(TAU / sweep.abs()).min(1.0);
+        let segments = (sweep.abs() / self.tolerance.max_angle(radius)).ceil() as usize;
+        for i in 1..segments {
+            let fraction = i as f64 / segments as f64;
+            let angle = a0 + sweep * fraction;
+            let (x, y) = (
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            );
+            line.push(match (start, mid, end) {
+                (
+                    Coord::Coordinate { z: z0, m: m0, .. },
+                    Coord::Coordinate { z: z1, m: m1, .. },
+                    Coord::Coordinate { z: z2, m: m2, .. },
+                ) => {
+                    let interpolate = |v0: Option<f64>, v1: Option<f64>, v2: Option<f64>| {
+                        if fraction <= mid_fraction {
+                            Some(v0? + (v1? - v0?) * fraction / mid_fraction)
+                        } else {
+                            Some(
+                                v1? + (v2? - v1?) * (fraction - mid_fraction)
+                                    / (1.0 - mid_fraction),
+                            )
+                        }
+                    };
+                    Coord::Coordinate {
+                        x,
+                        y,
+                        z: interpolate(*z0, *z1, *z2),
+                        m: interpolate(*m0, *m1, *m2),
+                        t: None,
+                        tm: None,
+                    }
+                }
+                _ => Coord::Xy(x, y),
+            });
+        }
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for LinearizeProcessor<P> {
+    forward_feature_processor!(processor);
+}
+
+impl<P: GeomProcessor> GeomProcessor for LinearizeProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        if let Some(points) = &mut self.points {
+            points.push(Coord::Xy(x, y));
+            Ok(())
+        } else {
+            self.processor.xy(x, y, idx)
+        }
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if let Some(points) = &mut self.points {
+            points.push(Coord::Coordinate { x, y, z, m, t, tm });
+            Ok(())
+        } else {
+            self.processor.coordinate(x, y, z, m, t, tm, idx)
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if self.parent() == Some(GeometryType::CompoundCurve) {
+            self.points = Some(Vec::with_capacity(size));
+            Ok(())
+        } else {
+            self.processor.linestring_begin(tagged, size, idx)
+        }
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if let Some(points) = self.points.take() {
+            self.curve_end(points, idx)
+        } else {
+            self.processor.linestring_end(tagged, idx)
+        }
+    }
+    fn circularstring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.points = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        let points = self.points.take().unwrap_or_default();
+        let line = self.linearize(&points);
+        self.curve_end(line, idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.open.push(GeometryType::CompoundCurve);
+        self.compound = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.open.pop();
+        let line = self.compound.take().unwrap_or_default();
+        self.curve_end(line, idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        let tagged = self.parent() != Some(GeometryType::MultiSurface);
+        self.open.push(GeometryType::CurvePolygon);
+        self.processor.polygon_begin(tagged, size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.open.pop();
+        let tagged = self.parent() != Some(GeometryType::MultiSurface);
+        self.processor.polygon_end(tagged, idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.open.push(GeometryType::MultiCurve);
+        self.processor.multilinestring_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.open.pop();
+        self.processor.multilinestring_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.open.push(GeometryType::MultiSurface);
+        self.processor.multipolygon_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.open.pop();
+        self.processor.multipolygon_end(idx)
+    }
+    forward_geom_processor!(
+        processor;
+        dimensions, multi_dim, srid, empty_point, point_begin, point_end, multipoint_begin,
+        multipoint_end, multilinestring_begin, multilinestring_end, polygon_begin, polygon_end,
+        multipolygon_begin, multipolygon_end, geometrycollection_begin, geometrycollection_end,
+        triangle_begin, triangle_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin,
+        tin_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for LinearizeProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkb")]
+mod test {
+    use super::*;
+    use crate::wkb::Ewkb;
+    use crate::wkt::WktWriter;
+    use crate::{CoordDimensions, GeozeroGeometry, SnapGrid, SnapProcessor};
+
+    fn linearized(ewkb: &str, tolerance: ArcTolerance) -> Result<String> {
+        let mut out: Vec<u8> = Vec::new();
+        let snap = SnapProcessor::new(WktWriter::new(&mut out), SnapGrid::Decimals(3));
+        let mut processor = LinearizeProcessor::new(snap, tolerance);
+        Ewkb(hex::decode(ewkb).unwrap()).process_geom(&mut processor)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn circularstring() -> Result<()> {
+        // CIRCULARSTRING(0 0,1 1,2 0)
+        let ewkb = "01080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F00000000000000400000000000000000";
+        assert_eq!(
+            linearized(ewkb, ArcTolerance::MaxAngle(PI / 4.0))?,
+            "LINESTRING(0 0,0.293 0.707,1 1,1.707 0.707,2 0)"
+        );
+        assert_eq!(
+            linearized(ewkb, ArcTolerance::MaxDeviation(0.04))?,
+            "LINESTRING(0 0,0.134 0.5,0.5 0.866,1 1,1.5 0.866,1.866 0.5,2 0)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn curves() -> Result<()> {
+        let tolerance = ArcTolerance::MaxAngle(PI / 2.0);
+        // COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0))
+        assert_eq!(
+            linearized("01090000000200000001080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F000000000000004000000000000000000102000000020000000000000000000040000000000000000000000000000008400000000000000000", tolerance)?,
+            "LINESTRING(0 0,1 1,2 0,3 0)"
+        );
+        // MULTICURVE((0 0,5 5),CIRCULARSTRING(4 0,4 4,8 4))
+        assert_eq!(
+            linearized("010B000000020000000102000000020000000000000000000000000000000000000000000000000014400000000000001440010800000003000000000000000000104000000000000000000000000000001040000000000000104000000000000020400000000000001040", tolerance)?,
+            "MULTILINESTRING((0 0,5 5),(4 0,4 4,8 4))"
+        );
+        // MULTISURFACE(CURVEPOLYGON(COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0,3 -1,0 -1,0 0))))
+        assert_eq!(
+            linearized("010C00000001000000010A0000000100000001090000000200000001080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F0000000000000040000000000000000001020000000500000000000000000000400000000000000000000000000000084000000000000000000000000000000840000000000000F0BF0000000000000000000000000000F0BF00000000000000000000000000000000", tolerance)?,
+            "MULTIPOLYGON(((0 0,1 1,2 0,3 0,3 -1,0 -1,0 0)))"
+        );
+        Ok(())
+    }
+
+    /// Collect coordinates with Z values
+    #[derive(Default)]
+    struct XyzCollector(Vec<(f64, f64, Option<f64>)>);
+
+    impl GeomProcessor for XyzCollector {
+        fn dimensions(&self) -> CoordDimensions {
+            CoordDimensions::xyz()
+        }
+        fn coordinate(
+            &mut self,
+            x: f64,
+            y: f64,
+            z: Option<f64>,
+            _m: Option<f64>,
+            _t: Option<f64>,
+            _tm: Option<u64>,
+            _idx: usize,
+        ) -> Result<()> {
+            let round = |v: f64| (v * 1000.0).round() / 1000.0;
+            self.0.push((round(x), round(y), z.map(round)));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn interpolate_z() -> Result<()> {
+        let mut processor =
+            LinearizeProcessor::new(XyzCollector::default(), ArcTolerance::MaxAngle(PI / 2.0));
+        // Full circle
+        processor.circularstring_begin(3, 0)?;
+        for (i, (x, y, z)) in [(0.0, 0.0, 0.0), (2.0, 0.0, 2.0), (0.0, 0.0, 8.0)]
+            .into_iter()
+            .enumerate()
+        {
+            processor.coordinate(x, y, Some(z), None, None, None, i)?;
+        }
+        processor.circularstring_end(0)?;
+        assert_eq!(
+            processor.into_inner().0,
+            vec![
+                (0.0, 0.0, Some(0.0)),
+                (1.0, -1.0, Some(1.0)),
+                (2.0, 0.0, Some(2.0)),
+                (1.0, 1.0, Some(5.0)),
+                (0.0, 0.0, Some(8.0)),
+            ]
+        );
+        Ok(())
+    }
+}
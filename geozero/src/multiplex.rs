@@ -1,7 +1,7 @@
 use crate::error::Result;
 use crate::feature_processor::FeatureProcessor;
-use crate::geometry_processor::GeomProcessor;
-use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::geometry_processor::{GeomProcessor, GeometryType};
+use crate::property_processor::{ColumnInfo, ColumnValue, PropertyProcessor};
 
 #[doc(hidden)]
 pub struct Multiplexer<P1: FeatureProcessor, P2: FeatureProcessor> {
@@ -24,6 +24,10 @@ impl<P1: FeatureProcessor, P2: FeatureProcessor> FeatureProcessor for Multiplexe
         self.p1.dataset_end()?;
         self.p2.dataset_end()
     }
+    fn schema(&mut self, columns: &[ColumnInfo], geometry_type: GeometryType) -> Result<()> {
+        self.p1.schema(columns, geometry_type)?;
+        self.p2.schema(columns, geometry_type)
+    }
     fn feature_begin(&mut self, idx: u64) -> Result<()> {
         self.p1.feature_begin(idx)?;
         self.p2.feature_begin(idx)
@@ -32,6 +36,14 @@ impl<P1: FeatureProcessor, P2: FeatureProcessor> FeatureProcessor for Multiplexe
         self.p1.feature_end(idx)?;
         self.p2.feature_end(idx)
     }
+    fn bytes_read(&mut self, bytes: u64, total: Option<u64>) -> Result<()> {
+        self.p1.bytes_read(bytes, total)?;
+        self.p2.bytes_read(bytes, total)
+    }
+    /// Done when both processors are done
+    fn done(&self) -> bool {
+        self.p1.done() && self.p2.done()
+    }
     fn properties_begin(&mut self) -> Result<()> {
         self.p1.properties_begin()?;
         self.p2.properties_begin()
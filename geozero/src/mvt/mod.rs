@@ -84,3 +84,59 @@ mod wkb {
         }
     }
 }
+
+pub(crate) mod driver {
+    use crate::error::{GeozeroError, Result};
+    use crate::mvt::{Message, Tile};
+    use crate::{Driver, DriverOptions, DynDatasource};
+    use std::io::BufRead;
+
+    pub(crate) const MVT: Driver = Driver {
+        name: "MVT",
+        extensions: &["mvt"],
+        sniff: Some(sniff),
+        open: Some(open),
+        create: None,
+    };
+
+    const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+    /// Tags of the `version`, `name`, `features`, `keys`, `values` and `extent` layer fields
+    const LAYER_TAGS: [u8; 6] = [0x78, 0x0a, 0x12, 0x1a, 0x22, 0x28];
+
+    /// A gzip compressed tile, or a tile starting with a layer (field 3, length delimited)
+    fn sniff(buf: &[u8], _options: &DriverOptions) -> bool {
+        if buf.starts_with(&GZIP_MAGIC) {
+            return true;
+        }
+        let Some(layer) = buf.strip_prefix(&[0x1a]) else {
+            return false;
+        };
+        // Skip the varint encoded layer length
+        let Some(len) = layer.iter().take(10).position(|b| b & 0x80 == 0) else {
+            return false;
+        };
+        match layer.get(len + 1) {
+            Some(tag) => LAYER_TAGS.contains(tag),
+            // Empty layer or incomplete buffer
+            None => true,
+        }
+    }
+
+    fn open<'a>(
+        mut reader: Box<dyn BufRead + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn DynDatasource + 'a>> {
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        if buf.starts_with(&GZIP_MAGIC) {
+            return Err(GeozeroError::Dataset(
+                "gzip compressed MVT tiles are not supported, decompress the tile first"
+                    .to_string(),
+            ));
+        }
+        let tile = Tile::decode(buf.as_slice())
+            .map_err(|e| GeozeroError::Dataset(format!("invalid MVT tile: {e}")))?;
+        Ok(Box::new(tile))
+    }
+}
@@ -9,8 +9,6 @@ pub enum MvtError {
     InvalidKeyIndex(u32),
     #[error("invalid value index {0}")]
     InvalidValueIndex(u32),
-    #[error("unsupported value type for key {0}")]
-    UnsupportedKeyValueType(String),
     #[error("geometry format")]
     GeometryFormat,
     #[error("too few coordinates in line or ring")]
@@ -1,6 +1,10 @@
-use crate::error::Result;
-use crate::mvt::vector_tile::{tile, tile::GeomType};
-use crate::{ColumnValue, FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry};
+use crate::error::{ErrorContext, Result, ResultExt};
+use crate::mvt::vector_tile::{tile, tile::GeomType, Tile};
+use crate::{
+    signed_ring_area, ColumnInfo, ColumnType, ColumnValue, FeatureAccess, FeatureIterator,
+    FeatureProcessor, FeatureProperties, GeomProcessor, GeometryType, GeozeroDatasource,
+    GeozeroGeometry, PropertyProcessor,
+};
 
 use super::{
     mvt_commands::{Command, CommandInteger, ParameterInteger},
@@ -13,21 +17,162 @@ impl GeozeroDatasource for tile::Layer {
     }
 }
 
+impl GeozeroDatasource for Tile {
+    /// Process all layers, each as a separate dataset.
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        for layer in &self.layers {
+            process(layer, processor)?;
+            if processor.done() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl tile::Layer {
+    /// Iterate over the features of the layer.
+    pub fn feature_iter(&self) -> MvtFeatureIter<'_> {
+        MvtFeatureIter {
+            layer: self,
+            features: self.features.iter(),
+        }
+    }
+}
+
+/// Iterator over the features of an MVT layer.
+pub struct MvtFeatureIter<'l> {
+    layer: &'l tile::Layer,
+    features: std::slice::Iter<'l, tile::Feature>,
+}
+
+impl<'l> FeatureIterator for MvtFeatureIter<'l> {
+    type Feature<'a>
+        = MvtFeature<'l>
+    where
+        Self: 'a;
+    fn next_feature(&mut self) -> Result<Option<MvtFeature<'l>>> {
+        self.next().transpose()
+    }
+}
+
+impl<'l> Iterator for MvtFeatureIter<'l> {
+    type Item = Result<MvtFeature<'l>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let layer = self.layer;
+        self.features
+            .next()
+            .map(|feature| Ok(MvtFeature { layer, feature }))
+    }
+}
+
+/// MVT feature with properties resolved from the keys and values of its layer.
+pub struct MvtFeature<'l> {
+    pub layer: &'l tile::Layer,
+    pub feature: &'l tile::Feature,
+}
+
+impl GeozeroGeometry for MvtFeature<'_> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        process_geom(self.feature, processor)
+    }
+}
+
+impl FeatureProperties for MvtFeature<'_> {
+    fn process_properties<P: PropertyProcessor>(&self, processor: &mut P) -> Result<bool> {
+        process_feature_properties(self.layer, self.feature, processor)
+    }
+}
+
+impl FeatureAccess for MvtFeature<'_> {}
+
 /// Process MVT layer.
 pub fn process(layer: &tile::Layer, processor: &mut impl FeatureProcessor) -> Result<()> {
     processor.dataset_begin(Some(&layer.name))?;
+    processor.schema(&layer_schema(layer), GeometryType::Unknown)?;
     for (idx, feature) in layer.features.iter().enumerate() {
-        processor.feature_begin(idx as u64)?;
+        process_layer_feature(layer, feature, idx, processor).with_context(|| {
+            ErrorContext::new()
+                .with_dataset(&layer.name)
+                .with_feature(idx as u64)
+        })?;
+        if processor.done() {
+            break;
+        }
+    }
+    processor.dataset_end()
+}
+
+fn process_layer_feature(
+    layer: &tile::Layer,
+    feature: &tile::Feature,
+    idx: usize,
+    processor: &mut impl FeatureProcessor,
+) -> Result<()> {
+    processor.feature_begin(idx as u64)?;
+
+    process_properties(layer, feature, processor)?;
+
+    processor.geometry_begin()?;
+    process_geom(feature, processor)?;
+    processor.geometry_end()?;
 
-        process_properties(layer, feature, processor)?;
+    processor.feature_end(idx as u64)
+}
 
-        processor.geometry_begin()?;
-        process_geom(feature, processor)?;
-        processor.geometry_end()?;
+/// Property columns of a layer, in the order of the layer keys
+fn layer_schema(layer: &tile::Layer) -> Vec<ColumnInfo> {
+    let mut columns: Vec<ColumnInfo> = layer
+        .keys
+        .iter()
+        .map(|key| ColumnInfo {
+            name: key.clone(),
+            column_type: None,
+            nullable: true,
+        })
+        .collect();
+    let mut typed = vec![false; columns.len()];
+    for feature in &layer.features {
+        for pair in feature.tags.chunks_exact(2) {
+            let (Some(column), Some(value)) = (
+                columns.get_mut(pair[0] as usize),
+                layer.values.get(pair[1] as usize),
+            ) else {
+                continue; // reported when processing the feature
+            };
+            let Some(column_type) = value_column_type(value) else {
+                continue;
+            };
+            if !typed[pair[0] as usize] {
+                typed[pair[0] as usize] = true;
+                column.column_type = Some(column_type);
+            } else if column.column_type != Some(column_type) {
+                // Mixed types
+                column.column_type = None;
+            }
+        }
+    }
+    columns
+}
 
-        processor.feature_end(idx as u64)?;
+fn value_column_type(value: &tile::Value) -> Option<ColumnType> {
+    if value.string_value.is_some() {
+        Some(ColumnType::String)
+    } else if value.float_value.is_some() {
+        Some(ColumnType::Float)
+    } else if value.double_value.is_some() {
+        Some(ColumnType::Double)
+    } else if value.int_value.is_some() {
+        Some(ColumnType::Long)
+    } else if value.uint_value.is_some() {
+        Some(ColumnType::ULong)
+    } else if value.sint_value.is_some() {
+        Some(ColumnType::Long)
+    } else if value.bool_value.is_some() {
+        Some(ColumnType::Bool)
+    } else {
+        None
     }
-    processor.dataset_end()
 }
 
 fn process_properties(
@@ -36,7 +181,16 @@ fn process_properties(
     processor: &mut impl FeatureProcessor,
 ) -> Result<()> {
     processor.properties_begin()?;
-    for (i, pair) in feature.tags.chunks(2).enumerate() {
+    process_feature_properties(layer, feature, processor)?;
+    processor.properties_end()
+}
+
+fn process_feature_properties(
+    layer: &tile::Layer,
+    feature: &tile::Feature,
+    processor: &mut impl PropertyProcessor,
+) -> Result<bool> {
+    for pair in feature.tags.chunks(2) {
         let [key_idx, value_idx] = pair else {
             return Err(MvtError::InvalidFeatureTagsLength(feature.tags.len()).into());
         };
@@ -48,26 +202,32 @@ fn process_properties(
             .values
             .get(*value_idx as usize)
             .ok_or(MvtError::InvalidValueIndex(*value_idx))?;
+        // Key index is the position in the announced schema
+        let i = *key_idx as usize;
 
-        if let Some(ref v) = value.string_value {
-            processor.property(i, key, &ColumnValue::String(v))?;
+        let finish = if let Some(ref v) = value.string_value {
+            processor.property(i, key, &ColumnValue::String(v))?
         } else if let Some(v) = value.float_value {
-            processor.property(i, key, &ColumnValue::Float(v))?;
+            processor.property(i, key, &ColumnValue::Float(v))?
         } else if let Some(v) = value.double_value {
-            processor.property(i, key, &ColumnValue::Double(v))?;
+            processor.property(i, key, &ColumnValue::Double(v))?
         } else if let Some(v) = value.int_value {
-            processor.property(i, key, &ColumnValue::Long(v))?;
+            processor.property(i, key, &ColumnValue::Long(v))?
         } else if let Some(v) = value.uint_value {
-            processor.property(i, key, &ColumnValue::ULong(v))?;
+            processor.property(i, key, &ColumnValue::ULong(v))?
         } else if let Some(v) = value.sint_value {
-            processor.property(i, key, &ColumnValue::Long(v))?;
+            processor.property(i, key, &ColumnValue::Long(v))?
         } else if let Some(v) = value.bool_value {
-            processor.property(i, key, &ColumnValue::Bool(v))?;
+            processor.property(i, key, &ColumnValue::Bool(v))?
         } else {
-            return Err(MvtError::UnsupportedKeyValueType(key.to_string()).into());
+            // Value without any of the typed fields
+            processor.property(i, key, &ColumnValue::Null)?
+        };
+        if finish {
+            return Ok(true);
         }
     }
-    processor.properties_end()
+    Ok(false)
 }
 
 impl GeozeroGeometry for tile::Feature {
@@ -274,24 +434,16 @@ fn process_polygons<P: GeomProcessor>(
     }
 }
 
-// using surveyor's formula
-fn is_area_positive(mut cursor: [i32; 2], first: &[u32], rest: &[u32]) -> bool {
-    let nb = 1 + rest.len() / 2;
-    let mut area = 0_i64;
-    let mut coords = first
-        .iter()
-        .chain(rest)
-        .chain(first.iter())
-        .map(|&x| ParameterInteger(x).value());
-    cursor[0] += coords.next().unwrap();
-    cursor[1] += coords.next().unwrap();
-    for _i in 0..nb {
-        let [x0, y0] = cursor;
-        cursor[0] += coords.next().unwrap();
-        cursor[1] += coords.next().unwrap();
-        area += (x0 as i64) * (cursor[1] as i64) - (y0 as i64) * (cursor[0] as i64);
-    }
-    area > 0
+fn is_area_positive(cursor: [i32; 2], first: &[u32], rest: &[u32]) -> bool {
+    let coords = first
+        .chunks_exact(2)
+        .chain(rest.chunks_exact(2))
+        .scan(cursor, |cursor, delta| {
+            cursor[0] += ParameterInteger(delta[0]).value();
+            cursor[1] += ParameterInteger(delta[1]).value();
+            Some((f64::from(cursor[0]), f64::from(cursor[1])))
+        });
+    signed_ring_area(coords) > 0.0
 }
 
 #[cfg(test)]
@@ -390,6 +542,79 @@ mod test {
         );
     }
 
+    #[test]
+    fn feature_iter() -> Result<()> {
+        use crate::FeatureProperties;
+
+        let mut mvt_layer = tile::Layer {
+            version: 2,
+            name: String::from("points"),
+            extent: Some(4096),
+            ..Default::default()
+        };
+        mvt_layer.keys.push(String::from("name"));
+        mvt_layer.values.push(tile::Value {
+            string_value: Some(String::from("first")),
+            ..Default::default()
+        });
+        mvt_layer.values.push(tile::Value {
+            string_value: Some(String::from("second")),
+            ..Default::default()
+        });
+        for (tags, geometry) in [([0, 0], [9, 50, 34]), ([0, 1], [9, 2410, 3080])] {
+            let mut mvt_feature = tile::Feature {
+                tags: tags.to_vec(),
+                geometry: geometry.to_vec(),
+                ..Default::default()
+            };
+            mvt_feature.set_type(GeomType::Point);
+            mvt_layer.features.push(mvt_feature);
+        }
+
+        let mut features = mvt_layer.feature_iter();
+        let feature = features.next_feature()?.unwrap();
+        assert_eq!(feature.property::<String>("name")?, "first");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&feature.to_json()?).unwrap(),
+            json!({"type": "Point", "coordinates": [25,17]})
+        );
+        let feature = features.next_feature()?.unwrap();
+        assert_eq!(feature.property::<String>("name")?, "second");
+        assert!(features.next_feature()?.is_none());
+
+        let names = mvt_layer
+            .feature_iter()
+            .map(|feature| feature?.property::<String>("name"))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(names, ["first", "second"]);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_value() -> Result<()> {
+        use crate::FeatureProperties;
+
+        let mut mvt_layer = tile::Layer {
+            version: 2,
+            name: String::from("points"),
+            extent: Some(4096),
+            ..Default::default()
+        };
+        mvt_layer.keys.push(String::from("name"));
+        mvt_layer.values.push(tile::Value::default());
+        let mut mvt_feature = tile::Feature {
+            tags: vec![0, 0],
+            geometry: vec![9, 50, 34],
+            ..Default::default()
+        };
+        mvt_feature.set_type(GeomType::Point);
+        mvt_layer.features.push(mvt_feature);
+
+        let feature = mvt_layer.feature_iter().next_feature()?.unwrap();
+        assert_eq!(feature.property::<Option<String>>("name")?, None);
+        Ok(())
+    }
+
     #[test]
     fn point_geom() {
         let mut mvt_feature = tile::Feature::default();
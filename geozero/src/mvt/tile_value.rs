@@ -1,4 +1,5 @@
 use crate::mvt::tile::Value;
+use crate::ColumnValue;
 use std::hash::Hash;
 
 /// A wrapper for the MVT value types.
@@ -72,6 +73,36 @@ impl TryFrom<Value> for TileValue {
     }
 }
 
+/// Convert a property value into a tag value.
+///
+/// Signed integers are encoded as `Sint`, unsigned integers as `Uint`. Date/time, list
+/// and map values are encoded as string in the same format as written by `GeoJsonWriter`.
+/// `Null` and `Binary` values have no tag representation.
+impl TryFrom<&ColumnValue<'_>> for TileValue {
+    type Error = ();
+
+    fn try_from(v: &ColumnValue) -> Result<Self, Self::Error> {
+        Ok(match v {
+            ColumnValue::Byte(v) => Self::Sint(i64::from(*v)),
+            ColumnValue::Short(v) => Self::Sint(i64::from(*v)),
+            ColumnValue::Int(v) => Self::Sint(i64::from(*v)),
+            ColumnValue::Long(v) => Self::Sint(*v),
+            ColumnValue::UByte(v) => Self::Uint(u64::from(*v)),
+            ColumnValue::UShort(v) => Self::Uint(u64::from(*v)),
+            ColumnValue::UInt(v) => Self::Uint(u64::from(*v)),
+            ColumnValue::ULong(v) => Self::Uint(*v),
+            ColumnValue::Float(v) => Self::Float(*v),
+            ColumnValue::Double(v) => Self::Double(*v),
+            ColumnValue::Bool(v) => Self::Bool(*v),
+            ColumnValue::String(v) | ColumnValue::Json(v) | ColumnValue::DateTime(v) => {
+                Self::Str(v.to_string())
+            }
+            ColumnValue::Null | ColumnValue::Binary(_) => Err(())?,
+            _ => Self::Str(v.to_string()),
+        })
+    }
+}
+
 // Treat floats as bits so that we can use as keys.
 // It is up to the users to ensure that the bits are not NaNs, or are consistent.
 
@@ -90,3 +121,92 @@ impl Hash for TileValue {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TileValue::*;
+    use super::*;
+    use crate::OwnedColumnValue;
+
+    fn tag(v: ColumnValue) -> Result<TileValue, ()> {
+        TileValue::try_from(&v)
+    }
+
+    #[test]
+    fn from_column_value() {
+        assert_eq!(tag(ColumnValue::Byte(-1)), Ok(Sint(-1)));
+        assert_eq!(tag(ColumnValue::Short(-2)), Ok(Sint(-2)));
+        assert_eq!(tag(ColumnValue::Int(-3)), Ok(Sint(-3)));
+        assert_eq!(tag(ColumnValue::Long(i64::MIN)), Ok(Sint(i64::MIN)));
+        assert_eq!(tag(ColumnValue::UByte(1)), Ok(Uint(1)));
+        assert_eq!(tag(ColumnValue::UShort(2)), Ok(Uint(2)));
+        assert_eq!(tag(ColumnValue::UInt(3)), Ok(Uint(3)));
+        assert_eq!(tag(ColumnValue::ULong(u64::MAX)), Ok(Uint(u64::MAX)));
+        assert_eq!(tag(ColumnValue::Float(1.5)), Ok(Float(1.5)));
+        assert_eq!(tag(ColumnValue::Double(2.5)), Ok(Double(2.5)));
+        assert_eq!(tag(ColumnValue::Bool(true)), Ok(Bool(true)));
+        assert_eq!(
+            tag(ColumnValue::String("Bern")),
+            Ok(Str("Bern".to_string()))
+        );
+        assert_eq!(
+            tag(ColumnValue::Json(r#"{"a": 1}"#)),
+            Ok(Str(r#"{"a": 1}"#.to_string()))
+        );
+        assert_eq!(
+            tag(ColumnValue::DateTime("2024-05-01T12:30:00Z")),
+            Ok(Str("2024-05-01T12:30:00Z".to_string()))
+        );
+        assert_eq!(tag(ColumnValue::Null), Err(()));
+        assert_eq!(tag(ColumnValue::Binary(&[1, 2])), Err(()));
+    }
+
+    #[test]
+    fn from_nested_value() {
+        let list = [OwnedColumnValue::Long(1), OwnedColumnValue::Null];
+        assert_eq!(
+            tag(ColumnValue::List(&list)),
+            Ok(Str("[1,null]".to_string()))
+        );
+        let map = [
+            (
+                "name".to_string(),
+                OwnedColumnValue::String("Bern".to_string()),
+            ),
+            ("pop".to_string(), OwnedColumnValue::Double(134794.0)),
+        ];
+        assert_eq!(
+            tag(ColumnValue::Map(&map)),
+            Ok(Str(r#"{"name":"Bern","pop":134794.0}"#.to_string()))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-chrono")]
+    fn from_temporal_value() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+        let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        let timestamp = date.and_hms_milli_opt(12, 30, 0, 500).unwrap();
+        assert_eq!(
+            tag(ColumnValue::Date(date)),
+            Ok(Str("2024-05-01".to_string()))
+        );
+        assert_eq!(
+            tag(ColumnValue::Time(timestamp.time())),
+            Ok(Str("12:30:00.500".to_string()))
+        );
+        assert_eq!(
+            tag(ColumnValue::Timestamp(timestamp)),
+            Ok(Str("2024-05-01T12:30:00.500".to_string()))
+        );
+        let timestamp_tz = FixedOffset::east_opt(2 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2024, 5, 1, 12, 30, 0)
+            .unwrap();
+        assert_eq!(
+            tag(ColumnValue::TimestampTz(timestamp_tz)),
+            Ok(Str("2024-05-01T12:30:00+02:00".to_string()))
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! Parallel processing of datasources split into batches.
+use crate::error::Result;
+use crate::{FeatureAccess, FeatureProcessor, FeatureRecorder, GeozeroDatasource};
+use rayon::iter::{ParallelBridge, ParallelIterator};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+
+/// Order in which processed batches are merged.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum MergeOrder {
+    /// Features are merged in input order
+    #[default]
+    Ordered,
+    /// Batches are merged as soon as they are processed, features of a batch stay in order
+    Unordered,
+}
+
+/// Process batches of a datasource on the rayon thread pool and merge them into `processor`.
+///
+/// Batches are read from `batches` on a separate thread and processed into
+/// [`RecordedFeature`](crate::RecordedFeature)s by the worker threads. The recorded features
+/// are replayed into `processor` on the calling thread as one dataset, numbered in output
+/// order. The schema announced by the first merged batch is passed on. Processing stops
+/// at the first error or when `processor` is [done](FeatureProcessor::done).
+///
+/// At most twice the number of pool threads batches are read ahead of the merged batches,
+/// so a slow batch holds back reading instead of buffering the following batches.
+///
+/// Chunkable sources are [`GeoJsonLineReader::batches`](crate::geojson::GeoJsonLineReader::batches),
+/// [`CsvReader::batches`](crate::csv::CsvReader::batches) and [`FeatureBatches`] for
+/// features like WKB rows.
+///
+/// # Usage example:
+///
+/// ```
+/// use geozero::csv::{CsvReader, CsvWriter};
+/// use geozero::{process_parallel, MergeOrder};
+///
+/// let input = "name,geom\nBern,POINT(7.44 46.95)\nGuarda,POINT(10.15 46.77)\n";
+/// let batches = CsvReader::new("geom", input.as_bytes()).batches(1).unwrap();
+/// let mut out: Vec<u8> = Vec::new();
+/// process_parallel(batches, &mut CsvWriter::new(&mut out), MergeOrder::Ordered).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "geometry,name\nPOINT(7.44 46.95),Bern\nPOINT(10.15 46.77),Guarda\n");
+/// ```
+pub fn process_parallel<I, B, P>(batches: I, processor: &mut P, order: MergeOrder) -> Result<()>
+where
+    I: IntoIterator<Item = Result<B>>,
+    I::IntoIter: Send,
+    B: GeozeroDatasource + Send,
+    P: FeatureProcessor,
+{
+    let mut batches = batches.into_iter();
+    let cancelled = AtomicBool::new(false);
+    let max_batches = rayon::current_num_threads() * 2;
+    let (sender, receiver) = mpsc::sync_channel(max_batches);
+    // One permit per batch read but not merged yet
+    let (permit_sender, permits) = mpsc::sync_channel(max_batches);
+    for _ in 0..max_batches {
+        let _ = permit_sender.send(());
+    }
+    std::thread::scope(|scope| {
+        let cancelled = &cancelled;
+        scope.spawn(move || {
+            // Wait for a permit before reading the next batch
+            std::iter::from_fn(move || {
+                if cancelled.load(Ordering::Relaxed) || permits.recv().is_err() {
+                    return None;
+                }
+                batches.next()
+            })
+            .enumerate()
+            .par_bridge()
+            .for_each_with(sender, |sender, (n, batch)| {
+                let recorded = batch.and_then(|mut batch| {
+                    let mut recorder = FeatureRecorder::new();
+                    batch.process(&mut recorder)?;
+                    Ok(recorder)
+                });
+                // The receiver is gone after an error
+                let _ = sender.send((n, recorded));
+            });
+        });
+        let result = merge_batches(receiver, &permit_sender, processor, order);
+        // Stop reading batches after an error or when the processor is done
+        cancelled.store(true, Ordering::Relaxed);
+        drop(permit_sender);
+        result
+    })
+}
+
+fn merge_batches<P: FeatureProcessor>(
+    receiver: mpsc::Receiver<(usize, Result<FeatureRecorder>)>,
+    permits: &mpsc::SyncSender<()>,
+    processor: &mut P,
+    order: MergeOrder,
+) -> Result<()> {
+    processor.dataset_begin(None)?;
+    let mut merger = BatchMerger {
+        processor,
+        next_idx: 0,
+        started: false,
+    };
+    let mut pending = BTreeMap::new();
+    let mut next_batch = 0;
+    for (n, recorded) in receiver {
+        if merger.processor.done() {
+            break;
+        }
+        match order {
+            MergeOrder::Unordered => {
+                merger.merge(&recorded?)?;
+                let _ = permits.send(());
+            }
+            MergeOrder::Ordered => {
+                pending.insert(n, recorded);
+                while let Some(recorded) = pending.remove(&next_batch) {
+                    merger.merge(&recorded?)?;
+                    next_batch += 1;
+                    let _ = permits.send(());
+                }
+            }
+        }
+    }
+    merger.processor.dataset_end()
+}
+
+struct BatchMerger<'a, P: FeatureProcessor> {
+    processor: &'a mut P,
+    next_idx: u64,
+    started: bool,
+}
+
+impl<P: FeatureProcessor> BatchMerger<'_, P> {
+    fn merge(&mut self, recorder: &FeatureRecorder) -> Result<()> {
+        if !self.started {
+            self.started = true;
+            if let Some((columns, geometry_type)) = recorder.schema() {
+                self.processor.schema(columns, geometry_type)?;
+            }
+        }
+        for feature in recorder.features() {
+            feature.process(self.processor, self.next_idx)?;
+            self.next_idx += 1;
+            if self.processor.done() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Iterator splitting features into batches of `size` features, e.g. WKB rows of a query.
+///
+/// # Usage example:
+///
+/// ```
+/// use geozero::wkb::Wkb;
+/// use geozero::wkt::WktWriter;
+/// use geozero::{process_parallel, FeatureBatches, MergeOrder};
+///
+/// let rows = vec![
+///     vec![1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 63, 0, 0, 0, 0, 0, 0, 0, 64],
+///     vec![1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 64, 0, 0, 0, 0, 0, 0, 16, 64],
+/// ];
+/// let batches = FeatureBatches::new(rows.into_iter().map(Wkb), 1);
+/// let mut out: Vec<u8> = Vec::new();
+/// process_parallel(batches, &mut WktWriter::new(&mut out), MergeOrder::Ordered).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(1 2)POINT(3 4)");
+/// ```
+pub struct FeatureBatches<I: Iterator> {
+    features: I,
+    size: usize,
+}
+
+impl<I: Iterator> FeatureBatches<I>
+where
+    I::Item: FeatureAccess,
+{
+    pub fn new<T: IntoIterator<IntoIter = I>>(features: T, size: usize) -> Self {
+        Self {
+            features: features.into_iter(),
+            size: size.max(1),
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for FeatureBatches<I>
+where
+    I::Item: FeatureAccess,
+{
+    type Item = Result<Vec<I::Item>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch: Vec<_> = self.features.by_ref().take(self.size).collect();
+        if batch.is_empty() {
+            None
+        } else {
+            Some(Ok(batch))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "with-csv", feature = "with-geojson"))]
+mod test {
+    use super::*;
+    use crate::csv::CsvReader;
+    use crate::error::GeozeroError;
+    use crate::geojson::{GeoJsonLineReader, GeoJsonWriter};
+    use crate::{FeatureProperties, ProcessToJson};
+    use std::sync::atomic::AtomicUsize;
+
+    fn csv_input() -> String {
+        let mut csv = "id,geom\n".to_string();
+        for i in 0..100 {
+            csv.push_str(&format!("{i},POINT({i} {i})\n"));
+        }
+        csv
+    }
+
+    #[test]
+    fn ordered() -> Result<()> {
+        let input = csv_input();
+        let expected = CsvReader::new("geom", input.as_bytes()).to_json()?;
+        let batches = CsvReader::new("geom", input.as_bytes()).batches(7)?;
+        let mut json: Vec<u8> = Vec::new();
+        process_parallel(
+            batches,
+            &mut GeoJsonWriter::new(&mut json),
+            MergeOrder::Ordered,
+        )?;
+        assert_eq!(std::str::from_utf8(&json).unwrap(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn unordered() -> Result<()> {
+        let input = csv_input();
+        let batches = CsvReader::new("geom", input.as_bytes()).batches(7)?;
+        let mut recorder = FeatureRecorder::new();
+        process_parallel(batches, &mut recorder, MergeOrder::Unordered)?;
+        let mut ids = recorder
+            .features()
+            .iter()
+            .map(|feature| feature.property::<u32>("id"))
+            .collect::<Result<Vec<_>>>()?;
+        ids.sort_unstable();
+        assert_eq!(ids, (0..100).collect::<Vec<_>>());
+        assert_eq!(recorder.schema().unwrap().0[0].name, "id");
+        Ok(())
+    }
+
+    /// Batch with a single feature, the first batch is slow
+    struct SlowBatch<'a> {
+        n: usize,
+        read: &'a AtomicUsize,
+        read_before_first: &'a AtomicUsize,
+    }
+
+    impl GeozeroDatasource for SlowBatch<'_> {
+        fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+            if self.n == 0 {
+                std::thread::sleep(std::time::Duration::from_millis(200));
+                self.read_before_first
+                    .store(self.read.load(Ordering::SeqCst), Ordering::SeqCst);
+            }
+            let n = self.n;
+            crate::geojson::GeoJson(&format!(
+                r#"{{"type": "Feature", "properties": {{"id": {n}}}, "geometry": {{"type": "Point", "coordinates": [{n}, {n}]}}}}"#
+            ))
+            .process(processor)
+        }
+    }
+
+    #[test]
+    fn slow_first_batch() -> Result<()> {
+        let read = AtomicUsize::new(0);
+        let read_before_first = AtomicUsize::new(0);
+        let batches = (0..1000).map(|n| {
+            read.fetch_add(1, Ordering::SeqCst);
+            Ok(SlowBatch {
+                n,
+                read: &read,
+                read_before_first: &read_before_first,
+            })
+        });
+        let mut recorder = FeatureRecorder::new();
+        process_parallel(batches, &mut recorder, MergeOrder::Ordered)?;
+        let ids = recorder
+            .features()
+            .iter()
+            .map(|feature| feature.property::<u32>("id"))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(ids, (0..1000).collect::<Vec<_>>());
+        assert!(read_before_first.load(Ordering::SeqCst) <= rayon::current_num_threads() * 2);
+        Ok(())
+    }
+
+    #[test]
+    fn error() {
+        let mut input = String::new();
+        for i in 0..50 {
+            input.push_str(&format!(
+                r#"{{"type": "Feature", "properties": {{"id": {i}}}, "geometry": {{"type": "Point", "coordinates": [{i}, {i}]}}}}"#
+            ));
+            input.push('\n');
+        }
+        input.push_str("{\"type\": \"Point\"}\n");
+        let batches = GeoJsonLineReader::new(input.as_bytes()).batches(10);
+        let mut recorder = FeatureRecorder::new();
+        let err = process_parallel(batches, &mut recorder, MergeOrder::Ordered).unwrap_err();
+        assert_eq!(err.context().and_then(|context| context.line()), Some(51));
+        assert!(matches!(err.without_context(), GeozeroError::Geometry(_)));
+        assert_eq!(recorder.features().len(), 50);
+    }
+}
@@ -0,0 +1,297 @@
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Progress of dataset processing, reported by [`ProgressProcessor`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Progress {
+    /// Number of processed features
+    pub features: u64,
+    /// Number of consumed input bytes, if reported by the reader
+    pub bytes: Option<u64>,
+    /// Total input size, if known
+    pub total_bytes: Option<u64>,
+    /// Time since the begin of the dataset
+    pub elapsed: Duration,
+}
+
+impl Progress {
+    /// Processed features per second
+    pub fn features_per_sec(&self) -> f64 {
+        self.features as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Consumed input bytes per second
+    pub fn bytes_per_sec(&self) -> Option<f64> {
+        self.bytes
+            .map(|bytes| bytes as f64 / self.elapsed.as_secs_f64().max(f64::EPSILON))
+    }
+
+    /// Consumed fraction of the input between 0.0 and 1.0, if the total size is known
+    pub fn fraction(&self) -> Option<f64> {
+        match (self.bytes, self.total_bytes) {
+            (Some(bytes), Some(total)) if total > 0 => Some((bytes as f64 / total as f64).min(1.0)),
+            _ => None,
+        }
+    }
+}
+
+/// Shared flag for cancelling processing, e.g. from a signal handler or another thread.
+#[derive(Clone, Default, Debug)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of all processors sharing this token
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Processor reporting the progress of a wrapped processor.
+///
+/// `callback` is called every `interval` features and at the end of each dataset. The number
+/// of consumed bytes is reported by readers knowing their input position, the total size by
+/// readers knowing their input size or with [`set_total_bytes`](Self::set_total_bytes).
+///
+/// With a [`CancellationToken`], processing stops with [`GeozeroError::Cancelled`] at the
+/// next feature after cancellation. Readers may wrap it with location information, use
+/// [`GeozeroError::is_cancelled`] for detecting cancellation.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::{GeoJsonLineReader, GeoJsonLineWriter};
+/// use geozero::{GeozeroDatasource, ProgressProcessor};
+///
+/// let input = r#"{"type": "Feature", "properties": {"id": 1}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+/// {"type": "Feature", "properties": {"id": 2}, "geometry": {"type": "Point", "coordinates": [2, 2]}}
+/// "#;
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = ProgressProcessor::new(GeoJsonLineWriter::new(&mut out), |progress| {
+///     println!("{} features, {:?} bytes", progress.features, progress.bytes);
+/// });
+/// processor.set_interval(1);
+/// processor.set_total_bytes(Some(input.len() as u64));
+/// GeoJsonLineReader::new(input.as_bytes()).process(&mut processor).unwrap();
+/// assert_eq!(processor.progress().fraction(), Some(1.0));
+/// # }
+/// ```
+pub struct ProgressProcessor<P, F> {
+    processor: P,
+    callback: F,
+    interval: u64,
+    cancellation: Option<CancellationToken>,
+    total_bytes: Option<u64>,
+    start: Instant,
+    features: u64,
+    bytes: Option<u64>,
+    reader_total_bytes: Option<u64>,
+    /// Number of features at the last report
+    reported: u64,
+}
+
+impl<P, F: FnMut(&Progress)> ProgressProcessor<P, F> {
+    /// Report progress every 1000 features to `callback`
+    pub fn new(processor: P, callback: F) -> Self {
+        ProgressProcessor {
+            processor,
+            callback,
+            interval: 1000,
+            cancellation: None,
+            total_bytes: None,
+            start: Instant::now(),
+            features: 0,
+            bytes: None,
+            reader_total_bytes: None,
+            reported: 0,
+        }
+    }
+
+    /// Set the number of features between reports
+    pub fn set_interval(&mut self, interval: u64) {
+        self.interval = interval.max(1);
+    }
+
+    /// Stop processing when `token` is cancelled
+    pub fn set_cancellation_token(&mut self, token: CancellationToken) {
+        self.cancellation = Some(token);
+    }
+
+    /// Set the total input size, e.g. from file metadata, if unknown to the reader
+    pub fn set_total_bytes(&mut self, total: Option<u64>) {
+        self.total_bytes = total;
+    }
+
+    /// Progress of the current dataset
+    pub fn progress(&self) -> Progress {
+        Progress {
+            features: self.features,
+            bytes: self.bytes,
+            total_bytes: self.reader_total_bytes.or(self.total_bytes),
+            elapsed: self.start.elapsed(),
+        }
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    fn check_cancelled(&self) -> Result<()> {
+        match &self.cancellation {
+            Some(token) if token.is_cancelled() => Err(GeozeroError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
+    fn report(&mut self) {
+        self.reported = self.features;
+        let progress = self.progress();
+        (self.callback)(&progress);
+    }
+}
+
+impl<P: FeatureProcessor, F: FnMut(&Progress)> FeatureProcessor for ProgressProcessor<P, F> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.check_cancelled()?;
+        self.start = Instant::now();
+        self.features = 0;
+        self.bytes = None;
+        self.reader_total_bytes = None;
+        self.reported = 0;
+        self.processor.dataset_begin(name)
+    }
+    fn dataset_end(&mut self) -> Result<()> {
+        self.report();
+        self.processor.dataset_end()
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.check_cancelled()?;
+        self.processor.feature_begin(idx)
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        self.processor.feature_end(idx)?;
+        self.features += 1;
+        if self.features - self.reported >= self.interval {
+            self.report();
+        }
+        Ok(())
+    }
+    fn bytes_read(&mut self, bytes: u64, total: Option<u64>) -> Result<()> {
+        self.check_cancelled()?;
+        self.bytes = Some(bytes);
+        self.reader_total_bytes = total;
+        self.processor.bytes_read(bytes, total)
+    }
+    forward_feature_processor!(
+        processor;
+        schema, done, properties_begin, properties_end, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: GeomProcessor, F> GeomProcessor for ProgressProcessor<P, F> {
+    forward_geom_processor!(processor);
+}
+
+impl<P: PropertyProcessor, F> PropertyProcessor for ProgressProcessor<P, F> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-geojson")]
+mod test {
+    use super::*;
+    use crate::geojson::{GeoJson, GeoJsonLineReader};
+    use crate::{GeozeroDatasource, ProcessorSink};
+
+    fn input() -> String {
+        let mut input = String::new();
+        for i in 0..5 {
+            input.push_str(&format!(
+                r#"{{"type": "Feature", "properties": {{"id": {i}}}, "geometry": {{"type": "Point", "coordinates": [{i}, {i}]}}}}"#
+            ));
+            input.push('\n');
+        }
+        input
+    }
+
+    #[test]
+    fn report() -> Result<()> {
+        let input = input();
+        let line_len = input.lines().next().unwrap().len() as u64 + 1;
+        let mut reports = Vec::new();
+        let mut processor = ProgressProcessor::new(ProcessorSink, |progress: &Progress| {
+            reports.push(progress.clone());
+        });
+        processor.set_interval(2);
+        GeoJsonLineReader::new(input.as_bytes()).process(&mut processor)?;
+        let features: Vec<_> = reports.iter().map(|progress| progress.features).collect();
+        assert_eq!(features, [2, 4, 5]);
+        let bytes: Vec<_> = reports.iter().map(|progress| progress.bytes).collect();
+        assert_eq!(
+            bytes,
+            [Some(2 * line_len), Some(4 * line_len), Some(5 * line_len)]
+        );
+        assert_eq!(reports[2].fraction(), None);
+        assert!(reports[2].features_per_sec() > 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn total_bytes() -> Result<()> {
+        let input = input();
+        let mut fractions = Vec::new();
+        let mut processor = ProgressProcessor::new(ProcessorSink, |progress: &Progress| {
+            fractions.push(progress.fraction());
+        });
+        processor.set_total_bytes(Some(input.len() as u64));
+        GeoJsonLineReader::new(input.as_bytes()).process(&mut processor)?;
+        assert_eq!(fractions, [Some(1.0)]);
+
+        // Readers without input position report feature counts only
+        let collection = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+        ]}"#;
+        let mut processor = ProgressProcessor::new(ProcessorSink, |_: &Progress| {});
+        GeoJson(collection).process(&mut processor)?;
+        assert_eq!(processor.progress().features, 1);
+        assert_eq!(processor.progress().bytes, None);
+        Ok(())
+    }
+
+    #[test]
+    fn cancel() {
+        let input = input();
+        let token = CancellationToken::new();
+        let mut processor = ProgressProcessor::new(ProcessorSink, |progress: &Progress| {
+            if progress.features == 2 {
+                token.cancel();
+            }
+        });
+        processor.set_interval(1);
+        processor.set_cancellation_token(token.clone());
+        let err = GeoJsonLineReader::new(input.as_bytes())
+            .process(&mut processor)
+            .unwrap_err();
+        assert!(err.is_cancelled());
+        assert_eq!(err.context().and_then(|context| context.feature()), Some(2));
+        assert_eq!(processor.progress().features, 2);
+    }
+}
@@ -0,0 +1,23 @@
+//! Coordinate reprojection.
+//!
+//! Projections are computed with [proj4rs](https://docs.rs/proj4rs), a pure-Rust port of PROJ.4.
+//! Transformations between EPSG:4326 and EPSG:3857 use built-in formulas.
+//!
+//! # Usage example:
+//!
+//! Compute the Web Mercator extent of a WGS84 geometry:
+//!
+//! ```
+//! use geozero::{BoundsProcessor, GeozeroGeometry};
+//! use geozero::proj::ReprojectProcessor;
+//! use geozero::wkt::Wkt;
+//!
+//! let mut processor = ReprojectProcessor::with_source(BoundsProcessor::new(), 4326, 3857).unwrap();
+//! Wkt("LINESTRING(0 0,10 -20)").process_geom(&mut processor).unwrap();
+//! let bounds = processor.into_inner().bounds().unwrap();
+//! assert_eq!(bounds.max_x.round(), 1113195.0);
+//! assert_eq!(bounds.min_y.round(), -2273031.0);
+//! ```
+mod reproject;
+
+pub use reproject::*;
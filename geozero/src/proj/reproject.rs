@@ -0,0 +1,331 @@
+use crate::error::{GeozeroError, Result};
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use crate::transform::CoordTransform;
+use proj4rs::Proj;
+use std::f64::consts::PI;
+
+/// Earth radius used by Web Mercator (EPSG:3857)
+const EARTH_RADIUS: f64 = 6_378_137.0;
+/// Maximal latitude of Web Mercator
+const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+
+/// Coordinate transformation between two coordinate reference systems.
+///
+/// Can be used with [`TransformProcessor`](crate::TransformProcessor) when source and target
+/// are known in advance.
+pub struct Reprojection {
+    method: Method,
+}
+
+enum Method {
+    Identity,
+    /// EPSG:4326 -> EPSG:3857
+    LonLatToWebMercator,
+    /// EPSG:3857 -> EPSG:4326
+    WebMercatorToLonLat,
+    Proj {
+        src: Box<Proj>,
+        dst: Box<Proj>,
+    },
+}
+
+impl Reprojection {
+    /// Reprojection between EPSG codes
+    pub fn from_epsg(source: i32, target: i32) -> Result<Self> {
+        let method = match (source, target) {
+            (src, dst) if src == dst => Method::Identity,
+            (4326, 3857) => Method::LonLatToWebMercator,
+            (3857, 4326) => Method::WebMercatorToLonLat,
+            (src, dst) => Method::Proj {
+                src: Box::new(epsg_proj(src)?),
+                dst: Box::new(epsg_proj(dst)?),
+            },
+        };
+        Ok(Reprojection { method })
+    }
+
+    /// Reprojection between CRS given as PROJ.4 strings, e.g. `+proj=longlat +datum=WGS84`
+    pub fn from_proj_strings(source: &str, target: &str) -> Result<Self> {
+        let method = Method::Proj {
+            src: Box::new(Proj::from_proj_string(source)?),
+            dst: Box::new(Proj::from_proj_string(target)?),
+        };
+        Ok(Reprojection { method })
+    }
+}
+
+fn epsg_proj(srid: i32) -> Result<Proj> {
+    let code = u16::try_from(srid).map_err(|_| GeozeroError::Srid(srid))?;
+    Ok(Proj::from_epsg_code(code)?)
+}
+
+impl CoordTransform for Reprojection {
+    fn transform_xy(&mut self, x: f64, y: f64) -> Result<(f64, f64)> {
+        let (x, y, _) = self.transform_xyz(x, y, 0.0)?;
+        Ok((x, y))
+    }
+    fn transform_coord(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+    ) -> Result<(f64, f64, Option<f64>, Option<f64>)> {
+        let (x, y, z_out) = self.transform_xyz(x, y, z.unwrap_or(0.0))?;
+        Ok((x, y, z.map(|_| z_out), m))
+    }
+}
+
+impl Reprojection {
+    fn transform_xyz(&self, x: f64, y: f64, z: f64) -> Result<(f64, f64, f64)> {
+        match &self.method {
+            Method::Identity => Ok((x, y, z)),
+            Method::LonLatToWebMercator => {
+                let lat = y.clamp(-MAX_LATITUDE, MAX_LATITUDE);
+                let x = x.to_radians() * EARTH_RADIUS;
+                let y = (PI / 4.0 + lat.to_radians() / 2.0).tan().ln() * EARTH_RADIUS;
+                Ok((x, y, z))
+            }
+            Method::WebMercatorToLonLat => {
+                let lon = (x / EARTH_RADIUS).to_degrees();
+                let lat = (2.0 * (y / EARTH_RADIUS).exp().atan() - PI / 2.0).to_degrees();
+                Ok((lon, lat, z))
+            }
+            Method::Proj { src, dst } => {
+                // proj4rs expects and returns geographic coordinates in radians
+                let mut point = if src.is_latlong() {
+                    (x.to_radians(), y.to_radians(), z)
+                } else {
+                    (x, y, z)
+                };
+                proj4rs::transform::transform(src, dst, &mut point)?;
+                if dst.is_latlong() {
+                    point.0 = point.0.to_degrees();
+                    point.1 = point.1.to_degrees();
+                }
+                Ok(point)
+            }
+        }
+    }
+}
+
+/// Processor reprojecting coordinates before passing all events to a wrapped processor.
+///
+/// The source CRS is either given explicitly or taken from the SRID announced with
+/// [`GeomProcessor::srid`]. Unknown SRIDs (`None` or 0) keep the last announced source.
+/// Coordinates without any known source SRID fail with an error. The wrapped processor
+/// receives the target SRID.
+pub struct ReprojectProcessor<P> {
+    processor: P,
+    /// Explicit source SRID, overrides announced SRIDs
+    source: Option<i32>,
+    target: i32,
+    /// Reprojection from the current source SRID
+    reprojection: Option<(i32, Reprojection)>,
+}
+
+impl<P> ReprojectProcessor<P> {
+    /// Reproject from the SRID announced by the reader to the `target` EPSG code
+    pub fn new(processor: P, target: i32) -> Self {
+        ReprojectProcessor {
+            processor,
+            source: None,
+            target,
+            reprojection: None,
+        }
+    }
+
+    /// Reproject from the `source` to the `target` EPSG code, ignoring announced SRIDs
+    pub fn with_source(processor: P, source: i32, target: i32) -> Result<Self> {
+        let reprojection = Reprojection::from_epsg(source, target)?;
+        Ok(ReprojectProcessor {
+            processor,
+            source: Some(source),
+            target,
+            reprojection: Some((source, reprojection)),
+        })
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    fn reprojection(&mut self) -> Result<&mut Reprojection> {
+        self.reprojection
+            .as_mut()
+            .map(|(_, reprojection)| reprojection)
+            .ok_or_else(|| {
+                GeozeroError::Geometry(format!(
+                    "unknown source SRID for reprojection to EPSG:{}",
+                    self.target
+                ))
+            })
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for ReprojectProcessor<P> {
+    forward_feature_processor!(processor);
+}
+
+impl<P: GeomProcessor> GeomProcessor for ReprojectProcessor<P> {
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        // Unknown SRIDs (`None` or 0) keep the current source
+        let announced = srid.filter(|srid| *srid != 0);
+        if let (None, Some(srid)) = (self.source, announced) {
+            if self.reprojection.as_ref().map(|(src, _)| *src) != Some(srid) {
+                self.reprojection = Some((srid, Reprojection::from_epsg(srid, self.target)?));
+            }
+        }
+        self.processor.srid(Some(self.target))
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let (x, y) = self.reprojection()?.transform_xy(x, y)?;
+        self.processor.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let (x, y, z, m) = self.reprojection()?.transform_coord(x, y, z, m)?;
+        self.processor.coordinate(x, y, z, m, t, tm, idx)
+    }
+    forward_geom_processor!(
+        processor;
+        dimensions, multi_dim, empty_point, point_begin, point_end, multipoint_begin,
+        multipoint_end, linestring_begin, linestring_end, multilinestring_begin,
+        multilinestring_end, polygon_begin, polygon_end, multipolygon_begin, multipolygon_end,
+        geometrycollection_begin, geometrycollection_end, circularstring_begin, circularstring_end,
+        compoundcurve_begin, compoundcurve_end, curvepolygon_begin, curvepolygon_end,
+        multicurve_begin, multicurve_end, multisurface_begin, multisurface_end, triangle_begin,
+        triangle_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for ReprojectProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_close((x1, y1): (f64, f64), (x2, y2): (f64, f64), eps: f64) {
+        assert!(
+            (x1 - x2).abs() < eps && (y1 - y2).abs() < eps,
+            "({x1} {y1}) != ({x2} {y2})"
+        );
+    }
+
+    #[test]
+    fn web_mercator() -> Result<()> {
+        let mut reprojection = Reprojection::from_epsg(4326, 3857)?;
+        let xy = reprojection.transform_xy(10.0, -20.0)?;
+        assert_close(xy, (1_113_194.907_932_7, -2_273_030.926_987_7), 1e-6);
+        // Clamped to the Web Mercator latitude range
+        let (_, y) = reprojection.transform_xy(0.0, 90.0)?;
+        assert!(y.is_finite());
+
+        let mut reprojection = Reprojection::from_epsg(3857, 4326)?;
+        let xy = reprojection.transform_xy(1_113_194.907_932_7, -2_273_030.926_987_7)?;
+        assert_close(xy, (10.0, -20.0), 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn proj_strings() -> Result<()> {
+        let mut reprojection = Reprojection::from_proj_strings(
+            "+proj=longlat +datum=WGS84",
+            "+proj=utm +zone=32 +datum=WGS84",
+        )?;
+        let (x, y, z, m) = reprojection.transform_coord(9.0, 0.0, Some(5.0), Some(1.0))?;
+        assert_close((x, y), (500_000.0, 0.0), 1e-6);
+        assert_eq!((z, m), (Some(5.0), Some(1.0)));
+
+        let mut reprojection = Reprojection::from_proj_strings(
+            "+proj=utm +zone=32 +datum=WGS84",
+            "+proj=longlat +datum=WGS84",
+        )?;
+        let xy = reprojection.transform_xy(500_000.0, 0.0)?;
+        assert_close(xy, (9.0, 0.0), 1e-9);
+        Ok(())
+    }
+
+    #[derive(Default)]
+    struct CoordCollector {
+        srid: Option<i32>,
+        coords: Vec<(f64, f64)>,
+    }
+
+    impl GeomProcessor for CoordCollector {
+        fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+            self.srid = srid;
+            Ok(())
+        }
+        fn xy(&mut self, x: f64, y: f64, _idx: usize) -> Result<()> {
+            self.coords.push((x, y));
+            Ok(())
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkb")]
+    fn announced_srid() -> Result<()> {
+        use crate::wkb::Ewkb;
+        use crate::GeozeroGeometry;
+
+        // SELECT 'SRID=4326;POINT(10 -20)'::geometry
+        let ewkb = Ewkb(hex::decode("0101000020E6100000000000000000244000000000000034C0").unwrap());
+        let mut processor = ReprojectProcessor::new(CoordCollector::default(), 3857);
+        ewkb.process_geom(&mut processor)?;
+        let collector = processor.into_inner();
+        assert_eq!(collector.srid, Some(3857));
+        assert_close(
+            collector.coords[0],
+            (1_113_194.907_932_7, -2_273_030.926_987_7),
+            1e-6,
+        );
+
+        // Missing SRID keeps the last announced source
+        let ewkb = Ewkb(hex::decode("0101000000000000000000244000000000000034C0").unwrap());
+        let mut processor = ReprojectProcessor::new(CoordCollector::default(), 3857);
+        assert!(ewkb.process_geom(&mut processor).is_err());
+        processor.srid(Some(4326))?;
+        ewkb.process_geom(&mut processor)?;
+        processor.srid(Some(0))?;
+        ewkb.process_geom(&mut processor)?;
+        let collector = processor.into_inner();
+        assert_eq!(collector.coords.len(), 2);
+        assert_close(collector.coords[1], collector.coords[0], 1e-9);
+        Ok(())
+    }
+
+    #[test]
+    fn epsg_codes() -> Result<()> {
+        // Bern, LV95 origin
+        let mut reprojection = Reprojection::from_epsg(2056, 4326)?;
+        let xy = reprojection.transform_xy(2_600_000.0, 1_200_000.0)?;
+        assert_close(xy, (7.438_632, 46.951_082), 1e-5);
+
+        // 9°E 50°N in UTM zone 32N (ETRS89)
+        let mut reprojection = Reprojection::from_epsg(25832, 3857)?;
+        let xy = reprojection.transform_xy(500_000.0, 5_538_630.70)?;
+        assert_close(xy, (1_001_875.417, 6_446_275.841), 0.01);
+
+        let mut reprojection = Reprojection::from_epsg(3857, 25832)?;
+        let xy = reprojection.transform_xy(1_001_875.417, 6_446_275.841)?;
+        assert_close(xy, (500_000.0, 5_538_630.70), 0.01);
+        Ok(())
+    }
+}
@@ -4,7 +4,10 @@ use std::fmt;
 use std::hash::BuildHasher;
 
 /// Feature property value.
+///
+/// Non-exhaustive, because the temporal variants depend on the `with-chrono` feature.
 #[derive(PartialEq, Debug)]
+#[non_exhaustive]
 pub enum ColumnValue<'a> {
     Byte(i8),
     UByte(u8),
@@ -23,6 +26,327 @@ pub enum ColumnValue<'a> {
     /// A datetime stored as an ISO8601-formatted string
     DateTime(&'a str),
     Binary(&'a [u8]),
+    /// A null value
+    Null,
+    /// A list of values
+    List(&'a [OwnedColumnValue]),
+    /// Named values in their original order
+    Map(&'a [(String, OwnedColumnValue)]),
+    /// A calendar date
+    #[cfg(feature = "with-chrono")]
+    Date(chrono::NaiveDate),
+    /// A time of day
+    #[cfg(feature = "with-chrono")]
+    Time(chrono::NaiveTime),
+    /// A date and time without time zone
+    #[cfg(feature = "with-chrono")]
+    Timestamp(chrono::NaiveDateTime),
+    /// A date and time with UTC offset
+    #[cfg(feature = "with-chrono")]
+    TimestampTz(chrono::DateTime<chrono::FixedOffset>),
+}
+
+/// Owned feature property value.
+///
+/// Non-exhaustive, because the temporal variants depend on the `with-chrono` feature.
+#[derive(Clone, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum OwnedColumnValue {
+    Byte(i8),
+    UByte(u8),
+    Bool(bool),
+    Short(i16),
+    UShort(u16),
+    Int(i32),
+    UInt(u32),
+    Long(i64),
+    ULong(u64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    /// A JSON-formatted string
+    Json(String),
+    /// A datetime stored as an ISO8601-formatted string
+    DateTime(String),
+    Binary(Vec<u8>),
+    /// A null value
+    Null,
+    /// A list of values
+    List(Vec<OwnedColumnValue>),
+    /// Named values in their original order
+    Map(Vec<(String, OwnedColumnValue)>),
+    /// A calendar date
+    #[cfg(feature = "with-chrono")]
+    Date(chrono::NaiveDate),
+    /// A time of day
+    #[cfg(feature = "with-chrono")]
+    Time(chrono::NaiveTime),
+    /// A date and time without time zone
+    #[cfg(feature = "with-chrono")]
+    Timestamp(chrono::NaiveDateTime),
+    /// A date and time with UTC offset
+    #[cfg(feature = "with-chrono")]
+    TimestampTz(chrono::DateTime<chrono::FixedOffset>),
+}
+
+/// Feature property type.
+///
+/// Non-exhaustive, because the temporal variants depend on the `with-chrono` feature.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub enum ColumnType {
+    Byte,
+    UByte,
+    Bool,
+    Short,
+    UShort,
+    Int,
+    UInt,
+    Long,
+    ULong,
+    Float,
+    Double,
+    String,
+    Json,
+    DateTime,
+    Binary,
+    List,
+    Map,
+    #[cfg(feature = "with-chrono")]
+    Date,
+    #[cfg(feature = "with-chrono")]
+    Time,
+    #[cfg(feature = "with-chrono")]
+    Timestamp,
+    #[cfg(feature = "with-chrono")]
+    TimestampTz,
+}
+
+/// Feature property column description.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ColumnInfo {
+    /// Name of the column
+    pub name: String,
+    /// Type of the column values, `None` if unknown or varying between features
+    pub column_type: Option<ColumnType>,
+    /// Whether values of this column can be null or missing
+    pub nullable: bool,
+}
+
+impl From<&ColumnValue<'_>> for OwnedColumnValue {
+    fn from(value: &ColumnValue) -> Self {
+        match *value {
+            ColumnValue::Byte(v) => OwnedColumnValue::Byte(v),
+            ColumnValue::UByte(v) => OwnedColumnValue::UByte(v),
+            ColumnValue::Bool(v) => OwnedColumnValue::Bool(v),
+            ColumnValue::Short(v) => OwnedColumnValue::Short(v),
+            ColumnValue::UShort(v) => OwnedColumnValue::UShort(v),
+            ColumnValue::Int(v) => OwnedColumnValue::Int(v),
+            ColumnValue::UInt(v) => OwnedColumnValue::UInt(v),
+            ColumnValue::Long(v) => OwnedColumnValue::Long(v),
+            ColumnValue::ULong(v) => OwnedColumnValue::ULong(v),
+            ColumnValue::Float(v) => OwnedColumnValue::Float(v),
+            ColumnValue::Double(v) => OwnedColumnValue::Double(v),
+            ColumnValue::String(v) => OwnedColumnValue::String(v.to_string()),
+            ColumnValue::Json(v) => OwnedColumnValue::Json(v.to_string()),
+            ColumnValue::DateTime(v) => OwnedColumnValue::DateTime(v.to_string()),
+            ColumnValue::Binary(v) => OwnedColumnValue::Binary(v.to_vec()),
+            ColumnValue::Null => OwnedColumnValue::Null,
+            ColumnValue::List(v) => OwnedColumnValue::List(v.to_vec()),
+            ColumnValue::Map(v) => OwnedColumnValue::Map(v.to_vec()),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Date(v) => OwnedColumnValue::Date(v),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Time(v) => OwnedColumnValue::Time(v),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Timestamp(v) => OwnedColumnValue::Timestamp(v),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::TimestampTz(v) => OwnedColumnValue::TimestampTz(v),
+        }
+    }
+}
+
+impl OwnedColumnValue {
+    /// Borrow as [`ColumnValue`] for passing to a [`PropertyProcessor`].
+    pub fn as_column_value(&self) -> ColumnValue<'_> {
+        match self {
+            OwnedColumnValue::Byte(v) => ColumnValue::Byte(*v),
+            OwnedColumnValue::UByte(v) => ColumnValue::UByte(*v),
+            OwnedColumnValue::Bool(v) => ColumnValue::Bool(*v),
+            OwnedColumnValue::Short(v) => ColumnValue::Short(*v),
+            OwnedColumnValue::UShort(v) => ColumnValue::UShort(*v),
+            OwnedColumnValue::Int(v) => ColumnValue::Int(*v),
+            OwnedColumnValue::UInt(v) => ColumnValue::UInt(*v),
+            OwnedColumnValue::Long(v) => ColumnValue::Long(*v),
+            OwnedColumnValue::ULong(v) => ColumnValue::ULong(*v),
+            OwnedColumnValue::Float(v) => ColumnValue::Float(*v),
+            OwnedColumnValue::Double(v) => ColumnValue::Double(*v),
+            OwnedColumnValue::String(v) => ColumnValue::String(v),
+            OwnedColumnValue::Json(v) => ColumnValue::Json(v),
+            OwnedColumnValue::DateTime(v) => ColumnValue::DateTime(v),
+            OwnedColumnValue::Binary(v) => ColumnValue::Binary(v),
+            OwnedColumnValue::Null => ColumnValue::Null,
+            OwnedColumnValue::List(v) => ColumnValue::List(v),
+            OwnedColumnValue::Map(v) => ColumnValue::Map(v),
+            #[cfg(feature = "with-chrono")]
+            OwnedColumnValue::Date(v) => ColumnValue::Date(*v),
+            #[cfg(feature = "with-chrono")]
+            OwnedColumnValue::Time(v) => ColumnValue::Time(*v),
+            #[cfg(feature = "with-chrono")]
+            OwnedColumnValue::Timestamp(v) => ColumnValue::Timestamp(*v),
+            #[cfg(feature = "with-chrono")]
+            OwnedColumnValue::TimestampTz(v) => ColumnValue::TimestampTz(*v),
+        }
+    }
+}
+
+impl ColumnValue<'_> {
+    /// Type of this value, `None` for `Null` values.
+    pub fn column_type(&self) -> Option<ColumnType> {
+        let column_type = match self {
+            ColumnValue::Byte(_) => ColumnType::Byte,
+            ColumnValue::UByte(_) => ColumnType::UByte,
+            ColumnValue::Bool(_) => ColumnType::Bool,
+            ColumnValue::Short(_) => ColumnType::Short,
+            ColumnValue::UShort(_) => ColumnType::UShort,
+            ColumnValue::Int(_) => ColumnType::Int,
+            ColumnValue::UInt(_) => ColumnType::UInt,
+            ColumnValue::Long(_) => ColumnType::Long,
+            ColumnValue::ULong(_) => ColumnType::ULong,
+            ColumnValue::Float(_) => ColumnType::Float,
+            ColumnValue::Double(_) => ColumnType::Double,
+            ColumnValue::String(_) => ColumnType::String,
+            ColumnValue::Json(_) => ColumnType::Json,
+            ColumnValue::DateTime(_) => ColumnType::DateTime,
+            ColumnValue::Binary(_) => ColumnType::Binary,
+            ColumnValue::Null => return None,
+            ColumnValue::List(_) => ColumnType::List,
+            ColumnValue::Map(_) => ColumnType::Map,
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Date(_) => ColumnType::Date,
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Time(_) => ColumnType::Time,
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Timestamp(_) => ColumnType::Timestamp,
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::TimestampTz(_) => ColumnType::TimestampTz,
+        };
+        Some(column_type)
+    }
+
+    /// Parse `DateTime` and `String` values in ISO 8601 format into a temporal value.
+    ///
+    /// Returns a `TimestampTz`, `Timestamp`, `Date` or `Time` value, whichever format
+    /// matches first. Temporal values are returned unchanged, other values as `None`.
+    #[cfg(feature = "with-chrono")]
+    pub fn parse_datetime(&self) -> Option<ColumnValue<'static>> {
+        if let Ok(v) = chrono::DateTime::<chrono::FixedOffset>::get_value(self) {
+            Some(ColumnValue::TimestampTz(v))
+        } else if let Ok(v) = chrono::NaiveDateTime::get_value(self) {
+            Some(ColumnValue::Timestamp(v))
+        } else if let Ok(v) = chrono::NaiveDate::get_value(self) {
+            Some(ColumnValue::Date(v))
+        } else if let Ok(v) = chrono::NaiveTime::get_value(self) {
+            Some(ColumnValue::Time(v))
+        } else {
+            None
+        }
+    }
+}
+
+/// Convert JSON values, with arrays and objects as `List` and `Map`.
+impl From<&serde_json::Value> for OwnedColumnValue {
+    fn from(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => OwnedColumnValue::Null,
+            serde_json::Value::Bool(v) => OwnedColumnValue::Bool(*v),
+            serde_json::Value::Number(v) => {
+                if let Some(v) = v.as_i64() {
+                    OwnedColumnValue::Long(v)
+                } else if let Some(v) = v.as_u64() {
+                    OwnedColumnValue::ULong(v)
+                } else {
+                    OwnedColumnValue::Double(v.as_f64().unwrap_or(f64::NAN))
+                }
+            }
+            serde_json::Value::String(v) => OwnedColumnValue::String(v.clone()),
+            serde_json::Value::Array(v) => {
+                OwnedColumnValue::List(v.iter().map(Into::into).collect())
+            }
+            serde_json::Value::Object(v) => OwnedColumnValue::Map(
+                v.iter()
+                    .map(|(key, value)| (key.clone(), value.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl ColumnValue<'_> {
+    /// Corresponding JSON value, e.g. for writing `List` and `Map` values.
+    ///
+    /// `Json` values are parsed, `Binary` values are converted to a byte array and
+    /// temporal values to an ISO 8601 string.
+    pub(crate) fn json_value(&self) -> Result<serde_json::Value> {
+        let value = match *self {
+            ColumnValue::Byte(v) => v.into(),
+            ColumnValue::UByte(v) => v.into(),
+            ColumnValue::Bool(v) => v.into(),
+            ColumnValue::Short(v) => v.into(),
+            ColumnValue::UShort(v) => v.into(),
+            ColumnValue::Int(v) => v.into(),
+            ColumnValue::UInt(v) => v.into(),
+            ColumnValue::Long(v) => v.into(),
+            ColumnValue::ULong(v) => v.into(),
+            ColumnValue::Float(v) => v.into(),
+            ColumnValue::Double(v) => v.into(),
+            ColumnValue::String(v) | ColumnValue::DateTime(v) => v.into(),
+            ColumnValue::Json(v) => {
+                serde_json::from_str(v).map_err(|e| GeozeroError::Property(e.to_string()))?
+            }
+            ColumnValue::Binary(v) => v.into(),
+            ColumnValue::Null => serde_json::Value::Null,
+            ColumnValue::List(values) => values
+                .iter()
+                .map(|value| value.as_column_value().json_value())
+                .collect::<Result<_>>()?,
+            ColumnValue::Map(entries) => entries
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), value.as_column_value().json_value()?)))
+                .collect::<Result<serde_json::Map<_, _>>>()?
+                .into(),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Date(_)
+            | ColumnValue::Time(_)
+            | ColumnValue::Timestamp(_)
+            | ColumnValue::TimestampTz(_) => self.to_string().into(),
+        };
+        Ok(value)
+    }
+}
+
+/// Serialize as the corresponding JSON value.
+#[cfg(feature = "with-serde")]
+impl serde::Serialize for ColumnValue<'_> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.json_value()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "with-serde")]
+impl serde::Serialize for OwnedColumnValue {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.as_column_value().serialize(serializer)
+    }
 }
 
 /// Feature property processing trait.
@@ -55,6 +379,9 @@ pub trait PropertyProcessor {
     ///   guaranteed that the set of names in each row is the same. Some input formats, like
     ///   GeoJSON, are schema-less and properties may change in every row. For this reason, it is
     ///   suggested to use the `name` parameter for matching across rows.
+    /// - If the reader announced a schema with
+    ///   [`FeatureProcessor::schema`](crate::FeatureProcessor::schema), `idx` is the position
+    ///   of the column in the announced schema.
     /// - It is not guaranteed that the data type of `name` is consistent across rows. For a given
     ///   `name`, it may be numeric in one row and string in the next.
     fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
@@ -62,6 +389,18 @@ pub trait PropertyProcessor {
     }
 }
 
+impl<P: PropertyProcessor + ?Sized> PropertyProcessor for &mut P {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        (**self).property(idx, name, value)
+    }
+}
+
+impl<P: PropertyProcessor + ?Sized> PropertyProcessor for Box<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        (**self).property(idx, name, value)
+    }
+}
+
 impl fmt::Display for ColumnValue<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -80,6 +419,19 @@ impl fmt::Display for ColumnValue<'_> {
                 write!(f, "{v}")
             }
             ColumnValue::Binary(_v) => write!(f, "[BINARY]"),
+            ColumnValue::Null => Ok(()),
+            ColumnValue::List(_) | ColumnValue::Map(_) => {
+                let json = self.json_value().map_err(|_| fmt::Error)?;
+                write!(f, "{json}")
+            }
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Date(v) => write!(f, "{}", v.format("%Y-%m-%d")),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Time(v) => write!(f, "{}", v.format("%H:%M:%S%.f")),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Timestamp(v) => write!(f, "{}", v.format("%Y-%m-%dT%H:%M:%S%.f")),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::TimestampTz(v) => f.write_str(&v.to_rfc3339()),
         }
     }
 }
@@ -97,12 +449,37 @@ pub struct PropertyReaderIdx<T: PropertyReadType> {
 }
 
 /// Get property value as Rust type.
+///
+/// Values are converted if possible without loss, e.g. `Int` values can be read as `i64` or
+/// `f64` and numeric `String` values (like all CSV values) as numbers.
 pub trait PropertyReadType<T = Self>
 where
     T: PropertyReadType,
 {
     /// Get property value as Rust type.
     fn get_value(v: &ColumnValue) -> Result<T>;
+    /// Value of a missing property.
+    fn missing() -> Result<T> {
+        Err(GeozeroError::ColumnNotFound)
+    }
+}
+
+impl<'a, T: PropertyReadType> PropertyReader<'a, T> {
+    pub fn new(name: &'a str) -> Self {
+        PropertyReader {
+            name,
+            value: T::missing(),
+        }
+    }
+}
+
+impl<T: PropertyReadType> PropertyReaderIdx<T> {
+    pub fn new(idx: usize) -> Self {
+        PropertyReaderIdx {
+            idx,
+            value: T::missing(),
+        }
+    }
 }
 
 impl<T: PropertyReadType> PropertyProcessor for PropertyReader<'_, T> {
@@ -138,11 +515,6 @@ macro_rules! impl_scalar_property_reader {
                 }
             }
         }
-        impl PropertyReadType for $t {
-            fn get_value(v: &ColumnValue) -> Result<$t> {
-                v.into()
-            }
-        }
     };
 }
 
@@ -158,6 +530,94 @@ impl_scalar_property_reader!(u64, ColumnValue::ULong);
 impl_scalar_property_reader!(f32, ColumnValue::Float);
 impl_scalar_property_reader!(f64, ColumnValue::Double);
 
+fn type_error(expected: &str, v: &ColumnValue) -> GeozeroError {
+    GeozeroError::ColumnType(expected.to_string(), format!("{v:?}"))
+}
+
+/// Value of integer types
+fn integer_value(v: &ColumnValue) -> Option<i128> {
+    match *v {
+        ColumnValue::Byte(v) => Some(v.into()),
+        ColumnValue::UByte(v) => Some(v.into()),
+        ColumnValue::Short(v) => Some(v.into()),
+        ColumnValue::UShort(v) => Some(v.into()),
+        ColumnValue::Int(v) => Some(v.into()),
+        ColumnValue::UInt(v) => Some(v.into()),
+        ColumnValue::Long(v) => Some(v.into()),
+        ColumnValue::ULong(v) => Some(v.into()),
+        _ => None,
+    }
+}
+
+/// Value of numeric types, if exactly representable as `f64`
+fn float_value(v: &ColumnValue) -> Option<f64> {
+    match *v {
+        ColumnValue::Float(v) => Some(v.into()),
+        ColumnValue::Double(v) => Some(v),
+        _ => integer_value(v).and_then(|i| {
+            let f = i as f64;
+            (f as i128 == i).then_some(f)
+        }),
+    }
+}
+
+macro_rules! impl_integer_property_read_type {
+    ( $t:ty ) => {
+        impl PropertyReadType for $t {
+            fn get_value(v: &ColumnValue) -> Result<$t> {
+                let value = match v {
+                    ColumnValue::String(s) => s.trim().parse().ok(),
+                    _ => integer_value(v).and_then(|i| <$t>::try_from(i).ok()),
+                };
+                value.ok_or_else(|| type_error(stringify!($t), v))
+            }
+        }
+    };
+}
+
+impl_integer_property_read_type!(i8);
+impl_integer_property_read_type!(u8);
+impl_integer_property_read_type!(i16);
+impl_integer_property_read_type!(u16);
+impl_integer_property_read_type!(i32);
+impl_integer_property_read_type!(u32);
+impl_integer_property_read_type!(i64);
+impl_integer_property_read_type!(u64);
+
+impl PropertyReadType for f64 {
+    fn get_value(v: &ColumnValue) -> Result<f64> {
+        let value = match v {
+            ColumnValue::String(s) => s.trim().parse().ok(),
+            _ => float_value(v),
+        };
+        value.ok_or_else(|| type_error("f64", v))
+    }
+}
+
+impl PropertyReadType for f32 {
+    fn get_value(v: &ColumnValue) -> Result<f32> {
+        let value = match v {
+            ColumnValue::Float(v) => Some(*v),
+            ColumnValue::String(s) => s.trim().parse().ok(),
+            _ => float_value(v).and_then(|f| {
+                let f32_value = f as f32;
+                (f.is_nan() || f64::from(f32_value) == f).then_some(f32_value)
+            }),
+        };
+        value.ok_or_else(|| type_error("f32", v))
+    }
+}
+
+impl PropertyReadType for bool {
+    fn get_value(v: &ColumnValue) -> Result<bool> {
+        match v {
+            ColumnValue::Bool(v) => Ok(*v),
+            ColumnValue::String(s) => s.trim().parse().map_err(|_| type_error("bool", v)),
+            _ => Err(type_error("bool", v)),
+        }
+    }
+}
+
 impl From<&ColumnValue<'_>> for Result<String> {
     fn from(v: &ColumnValue) -> Result<String> {
         Ok(v.to_string())
@@ -170,9 +630,290 @@ impl PropertyReadType for String {
     }
 }
 
+impl PropertyReadType for Vec<u8> {
+    fn get_value(v: &ColumnValue) -> Result<Vec<u8>> {
+        match v {
+            ColumnValue::Binary(v) => Ok(v.to_vec()),
+            _ => Err(type_error("Vec<u8>", v)),
+        }
+    }
+}
+
+impl PropertyReadType for serde_json::Value {
+    fn get_value(v: &ColumnValue) -> Result<serde_json::Value> {
+        let value = match *v {
+            ColumnValue::Byte(v) => v.into(),
+            ColumnValue::UByte(v) => v.into(),
+            ColumnValue::Bool(v) => v.into(),
+            ColumnValue::Short(v) => v.into(),
+            ColumnValue::UShort(v) => v.into(),
+            ColumnValue::Int(v) => v.into(),
+            ColumnValue::UInt(v) => v.into(),
+            ColumnValue::Long(v) => v.into(),
+            ColumnValue::ULong(v) => v.into(),
+            ColumnValue::Float(v) => v.into(),
+            ColumnValue::Double(v) => v.into(),
+            ColumnValue::String(v) | ColumnValue::DateTime(v) => v.into(),
+            ColumnValue::Json(v) => {
+                serde_json::from_str(v).map_err(|e| GeozeroError::Property(e.to_string()))?
+            }
+            ColumnValue::Null => serde_json::Value::Null,
+            ColumnValue::List(_) | ColumnValue::Map(_) => v.json_value()?,
+            ColumnValue::Binary(_) => return Err(type_error("serde_json::Value", v)),
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Date(_)
+            | ColumnValue::Time(_)
+            | ColumnValue::Timestamp(_)
+            | ColumnValue::TimestampTz(_) => v.to_string().into(),
+        };
+        Ok(value)
+    }
+}
+
+/// Missing properties, `Null` values and empty strings are read as `None`.
+impl<T: PropertyReadType> PropertyReadType for Option<T> {
+    fn get_value(v: &ColumnValue) -> Result<Option<T>> {
+        if *v == ColumnValue::Null {
+            return Ok(None);
+        }
+        match T::get_value(v) {
+            Ok(value) => Ok(Some(value)),
+            Err(_) if *v == ColumnValue::String("") => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+    fn missing() -> Result<Option<T>> {
+        Ok(None)
+    }
+}
+
+/// Date/time string of `DateTime` and `String` values
+#[cfg(feature = "with-chrono")]
+fn datetime_str<'a>(v: &ColumnValue<'a>, expected: &str) -> Result<&'a str> {
+    match *v {
+        ColumnValue::DateTime(s) | ColumnValue::String(s) => Ok(s.trim()),
+        _ => Err(type_error(expected, v)),
+    }
+}
+
+/// Parse RFC 3339 / ISO 8601 date and time with offset.
+#[cfg(feature = "with-chrono")]
+impl PropertyReadType for chrono::DateTime<chrono::FixedOffset> {
+    fn get_value(v: &ColumnValue) -> Result<chrono::DateTime<chrono::FixedOffset>> {
+        if let ColumnValue::TimestampTz(v) = v {
+            return Ok(*v);
+        }
+        let s = datetime_str(v, "DateTime")?;
+        chrono::DateTime::parse_from_rfc3339(s).map_err(|_| type_error("DateTime", v))
+    }
+}
+
+/// Parse RFC 3339 / ISO 8601 date and time with offset and convert to UTC.
+#[cfg(feature = "with-chrono")]
+impl PropertyReadType for chrono::DateTime<chrono::Utc> {
+    fn get_value(v: &ColumnValue) -> Result<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::<chrono::FixedOffset>::get_value(v)
+            .map(|datetime| datetime.with_timezone(&chrono::Utc))
+    }
+}
+
+/// Parse date and time without offset, separated by `T` or a space.
+#[cfg(feature = "with-chrono")]
+impl PropertyReadType for chrono::NaiveDateTime {
+    fn get_value(v: &ColumnValue) -> Result<chrono::NaiveDateTime> {
+        if let ColumnValue::Timestamp(v) = v {
+            return Ok(*v);
+        }
+        let s = datetime_str(v, "NaiveDateTime")?;
+        s.parse::<chrono::NaiveDateTime>()
+            .or_else(|_| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f"))
+            .map_err(|_| type_error("NaiveDateTime", v))
+    }
+}
+
+/// Parse `YYYY-MM-DD` date.
+#[cfg(feature = "with-chrono")]
+impl PropertyReadType for chrono::NaiveDate {
+    fn get_value(v: &ColumnValue) -> Result<chrono::NaiveDate> {
+        if let ColumnValue::Date(v) = v {
+            return Ok(*v);
+        }
+        let s = datetime_str(v, "NaiveDate")?;
+        s.parse().map_err(|_| type_error("NaiveDate", v))
+    }
+}
+
+/// Parse `HH:MM:SS` time with optional fraction.
+#[cfg(feature = "with-chrono")]
+impl PropertyReadType for chrono::NaiveTime {
+    fn get_value(v: &ColumnValue) -> Result<chrono::NaiveTime> {
+        if let ColumnValue::Time(v) = v {
+            return Ok(*v);
+        }
+        let s = datetime_str(v, "NaiveTime")?;
+        s.parse().map_err(|_| type_error("NaiveTime", v))
+    }
+}
+
+/// Process Rust value as feature property.
+///
+/// Used by `#[derive(GeozeroFeature)]` for processing struct fields.
+pub trait PropertyWriteType {
+    /// Process value as property `name` with index `idx`.
+    ///
+    /// Returns `true` if processing should be aborted.
+    fn process_property<P: PropertyProcessor>(
+        &self,
+        processor: &mut P,
+        idx: usize,
+        name: &str,
+    ) -> Result<bool>;
+}
+
+macro_rules! impl_scalar_property_write_type {
+    ( $t:ty, $e:path ) => {
+        impl PropertyWriteType for $t {
+            fn process_property<P: PropertyProcessor>(
+                &self,
+                processor: &mut P,
+                idx: usize,
+                name: &str,
+            ) -> Result<bool> {
+                processor.property(idx, name, &$e(*self))
+            }
+        }
+    };
+}
+
+impl_scalar_property_write_type!(i8, ColumnValue::Byte);
+impl_scalar_property_write_type!(u8, ColumnValue::UByte);
+impl_scalar_property_write_type!(bool, ColumnValue::Bool);
+impl_scalar_property_write_type!(i16, ColumnValue::Short);
+impl_scalar_property_write_type!(u16, ColumnValue::UShort);
+impl_scalar_property_write_type!(i32, ColumnValue::Int);
+impl_scalar_property_write_type!(u32, ColumnValue::UInt);
+impl_scalar_property_write_type!(i64, ColumnValue::Long);
+impl_scalar_property_write_type!(u64, ColumnValue::ULong);
+impl_scalar_property_write_type!(f32, ColumnValue::Float);
+impl_scalar_property_write_type!(f64, ColumnValue::Double);
+
+impl PropertyWriteType for str {
+    fn process_property<P: PropertyProcessor>(
+        &self,
+        processor: &mut P,
+        idx: usize,
+        name: &str,
+    ) -> Result<bool> {
+        processor.property(idx, name, &ColumnValue::String(self))
+    }
+}
+
+impl PropertyWriteType for String {
+    fn process_property<P: PropertyProcessor>(
+        &self,
+        processor: &mut P,
+        idx: usize,
+        name: &str,
+    ) -> Result<bool> {
+        self.as_str().process_property(processor, idx, name)
+    }
+}
+
+impl PropertyWriteType for Vec<u8> {
+    fn process_property<P: PropertyProcessor>(
+        &self,
+        processor: &mut P,
+        idx: usize,
+        name: &str,
+    ) -> Result<bool> {
+        processor.property(idx, name, &ColumnValue::Binary(self))
+    }
+}
+
+/// Numbers, strings and booleans are processed as scalar values, arrays and objects as
+/// `List` and `Map`.
+impl PropertyWriteType for serde_json::Value {
+    fn process_property<P: PropertyProcessor>(
+        &self,
+        processor: &mut P,
+        idx: usize,
+        name: &str,
+    ) -> Result<bool> {
+        match self {
+            serde_json::Value::Null => processor.property(idx, name, &ColumnValue::Null),
+            serde_json::Value::Bool(v) => v.process_property(processor, idx, name),
+            serde_json::Value::Number(n) => {
+                if let Some(v) = n.as_i64() {
+                    v.process_property(processor, idx, name)
+                } else if let Some(v) = n.as_u64() {
+                    v.process_property(processor, idx, name)
+                } else if let Some(v) = n.as_f64() {
+                    v.process_property(processor, idx, name)
+                } else {
+                    Ok(false)
+                }
+            }
+            serde_json::Value::String(v) => v.process_property(processor, idx, name),
+            serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+                let value = OwnedColumnValue::from(self);
+                processor.property(idx, name, &value.as_column_value())
+            }
+        }
+    }
+}
+
+/// `None` values are processed as `Null`.
+impl<T: PropertyWriteType> PropertyWriteType for Option<T> {
+    fn process_property<P: PropertyProcessor>(
+        &self,
+        processor: &mut P,
+        idx: usize,
+        name: &str,
+    ) -> Result<bool> {
+        match self {
+            Some(value) => value.process_property(processor, idx, name),
+            None => processor.property(idx, name, &ColumnValue::Null),
+        }
+    }
+}
+
+impl<T: PropertyWriteType + ?Sized> PropertyWriteType for &T {
+    fn process_property<P: PropertyProcessor>(
+        &self,
+        processor: &mut P,
+        idx: usize,
+        name: &str,
+    ) -> Result<bool> {
+        (**self).process_property(processor, idx, name)
+    }
+}
+
+/// Processed as `TimestampTz` value.
+#[cfg(feature = "with-chrono")]
+impl<Tz: chrono::TimeZone> PropertyWriteType for chrono::DateTime<Tz> {
+    fn process_property<P: PropertyProcessor>(
+        &self,
+        processor: &mut P,
+        idx: usize,
+        name: &str,
+    ) -> Result<bool> {
+        processor.property(idx, name, &ColumnValue::TimestampTz(self.fixed_offset()))
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+impl_scalar_property_write_type!(chrono::NaiveDateTime, ColumnValue::Timestamp);
+#[cfg(feature = "with-chrono")]
+impl_scalar_property_write_type!(chrono::NaiveDate, ColumnValue::Date);
+#[cfg(feature = "with-chrono")]
+impl_scalar_property_write_type!(chrono::NaiveTime, ColumnValue::Time);
+
+/// Collect properties as strings, omitting `Null` values.
 impl<S: BuildHasher> PropertyProcessor for HashMap<String, String, S> {
     fn property(&mut self, _idx: usize, colname: &str, colval: &ColumnValue) -> Result<bool> {
-        self.insert(colname.to_string(), colval.to_string());
+        if *colval != ColumnValue::Null {
+            self.insert(colname.to_string(), colval.to_string());
+        }
         Ok(false)
     }
 }
@@ -194,3 +935,188 @@ fn convert_column_value() {
         r#"expected a `ColumnValue::Int` value but found `String("Yes")`"#
     );
 }
+
+#[test]
+fn coerce_column_value() {
+    assert_eq!(i64::get_value(&ColumnValue::Int(42)).unwrap(), 42);
+    assert_eq!(i64::get_value(&ColumnValue::ULong(42)).unwrap(), 42);
+    assert_eq!(u8::get_value(&ColumnValue::Long(255)).unwrap(), 255);
+    assert_eq!(i32::get_value(&ColumnValue::String(" -7 ")).unwrap(), -7);
+    assert_eq!(
+        u8::get_value(&ColumnValue::Long(256))
+            .unwrap_err()
+            .to_string(),
+        "expected a `u8` value but found `Long(256)`"
+    );
+    assert!(i64::get_value(&ColumnValue::Double(1.0)).is_err());
+    assert!(u64::get_value(&ColumnValue::String("1.5")).is_err());
+
+    assert_eq!(f64::get_value(&ColumnValue::Float(1.5)).unwrap(), 1.5);
+    assert_eq!(f64::get_value(&ColumnValue::Long(-3)).unwrap(), -3.0);
+    assert_eq!(
+        f64::get_value(&ColumnValue::String("2.5e3")).unwrap(),
+        2500.0
+    );
+    assert!(f64::get_value(&ColumnValue::ULong(u64::MAX)).is_err());
+    assert_eq!(f32::get_value(&ColumnValue::Double(0.5)).unwrap(), 0.5);
+    assert!(f32::get_value(&ColumnValue::Double(0.1)).is_err());
+
+    assert!(bool::get_value(&ColumnValue::String("true")).unwrap());
+    assert!(bool::get_value(&ColumnValue::Int(1)).is_err());
+
+    assert_eq!(
+        Vec::<u8>::get_value(&ColumnValue::Binary(&[1, 2])).unwrap(),
+        vec![1, 2]
+    );
+    assert_eq!(
+        serde_json::Value::get_value(&ColumnValue::Json(r#"{"a": [1]}"#)).unwrap(),
+        serde_json::json!({"a": [1]})
+    );
+    assert_eq!(
+        serde_json::Value::get_value(&ColumnValue::ULong(7)).unwrap(),
+        serde_json::json!(7)
+    );
+}
+
+#[test]
+fn optional_property() {
+    let mut reader = PropertyReader::<Option<i64>>::new("pop");
+    assert_eq!(reader.value.as_ref().unwrap(), &None);
+    reader
+        .property(0, "pop", &ColumnValue::String("12"))
+        .unwrap();
+    assert_eq!(reader.value.unwrap(), Some(12));
+
+    assert_eq!(
+        Option::<i64>::get_value(&ColumnValue::String("")).unwrap(),
+        None
+    );
+    assert_eq!(
+        Option::<String>::get_value(&ColumnValue::String("")).unwrap(),
+        Some(String::new())
+    );
+    assert!(Option::<i64>::get_value(&ColumnValue::String("x")).is_err());
+    assert!(matches!(
+        PropertyReader::<i64>::new("pop").value,
+        Err(GeozeroError::ColumnNotFound)
+    ));
+}
+
+#[test]
+fn write_property_types() {
+    let mut properties = HashMap::new();
+    assert!(!42u16.process_property(&mut properties, 0, "u16").unwrap());
+    assert!(!"text".process_property(&mut properties, 1, "str").unwrap());
+    assert!(!None::<i32>
+        .process_property(&mut properties, 2, "none")
+        .unwrap());
+    assert!(!serde_json::json!({"a": [1]})
+        .process_property(&mut properties, 3, "json")
+        .unwrap());
+    assert_eq!(properties.len(), 3);
+    assert_eq!(properties["u16"], "42");
+    assert_eq!(properties["str"], "text");
+    assert_eq!(properties["json"], r#"{"a":[1]}"#);
+}
+
+#[test]
+fn structured_values() {
+    let value = OwnedColumnValue::from(&serde_json::json!({"name": "a", "tags": [1, null, 2.5]}));
+    let column_value = value.as_column_value();
+    assert_eq!(column_value.column_type(), Some(ColumnType::Map));
+    assert_eq!(
+        column_value.to_string(),
+        r#"{"name":"a","tags":[1,null,2.5]}"#
+    );
+    assert_eq!(
+        serde_json::Value::get_value(&column_value).unwrap(),
+        serde_json::json!({"name": "a", "tags": [1, null, 2.5]})
+    );
+    assert_eq!(ColumnValue::Null.column_type(), None);
+    assert_eq!(ColumnValue::Null.to_string(), "");
+    assert_eq!(Option::<i32>::get_value(&ColumnValue::Null).unwrap(), None);
+    assert!(i32::get_value(&ColumnValue::Null).is_err());
+}
+
+#[cfg(feature = "with-chrono")]
+#[test]
+fn datetime_property() {
+    use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+    let v = &ColumnValue::DateTime("2024-05-01T12:30:00+02:00");
+    assert_eq!(
+        chrono::DateTime::<Utc>::get_value(v).unwrap(),
+        Utc.with_ymd_and_hms(2024, 5, 1, 10, 30, 0).unwrap()
+    );
+    let v = &ColumnValue::String("2024-05-01 12:30:00.5");
+    assert_eq!(
+        NaiveDateTime::get_value(v).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 5, 1)
+            .unwrap()
+            .and_hms_milli_opt(12, 30, 0, 500)
+            .unwrap()
+    );
+    assert_eq!(
+        NaiveDate::get_value(&ColumnValue::String("2024-05-01"))
+            .unwrap()
+            .month(),
+        5
+    );
+    assert_eq!(
+        NaiveTime::get_value(&ColumnValue::String("08:15:00")).unwrap(),
+        NaiveTime::from_hms_opt(8, 15, 0).unwrap()
+    );
+    assert!(NaiveDate::get_value(&ColumnValue::Int(20240501)).is_err());
+}
+
+#[test]
+#[cfg(feature = "with-chrono")]
+fn temporal_values() {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    let timestamp = date.and_hms_milli_opt(12, 30, 0, 500).unwrap();
+    assert_eq!(ColumnValue::Date(date).to_string(), "2024-05-01");
+    assert_eq!(
+        ColumnValue::Time(timestamp.time()).to_string(),
+        "12:30:00.500"
+    );
+    assert_eq!(
+        ColumnValue::Timestamp(timestamp).to_string(),
+        "2024-05-01T12:30:00.500"
+    );
+
+    let v = ColumnValue::DateTime("2024-05-01T12:30:00+02:00")
+        .parse_datetime()
+        .unwrap();
+    assert_eq!(v.column_type(), Some(ColumnType::TimestampTz));
+    assert_eq!(v.to_string(), "2024-05-01T12:30:00+02:00");
+    assert_eq!(
+        ColumnValue::String("2024-05-01 12:30:00.5").parse_datetime(),
+        Some(ColumnValue::Timestamp(timestamp))
+    );
+    assert_eq!(
+        ColumnValue::String("2024-05-01").parse_datetime(),
+        Some(ColumnValue::Date(date))
+    );
+    assert_eq!(ColumnValue::String("Bern").parse_datetime(), None);
+
+    assert_eq!(
+        NaiveDateTime::get_value(&ColumnValue::Timestamp(timestamp)).unwrap(),
+        timestamp
+    );
+    assert_eq!(
+        String::get_value(&ColumnValue::Date(date)).unwrap(),
+        "2024-05-01"
+    );
+    assert_eq!(
+        NaiveTime::get_value(&ColumnValue::Time(timestamp.time())).unwrap(),
+        timestamp.time()
+    );
+    let owned = OwnedColumnValue::from(&ColumnValue::Date(date));
+    assert_eq!(owned.as_column_value(), ColumnValue::Date(date));
+    assert_eq!(
+        serde_json::Value::get_value(&owned.as_column_value()).unwrap(),
+        serde_json::json!("2024-05-01")
+    );
+}
@@ -0,0 +1,531 @@
+//! Owned features and geometries recorded from processing events.
+use crate::api::{FeatureAccess, FeatureProperties, GeozeroDatasource, GeozeroGeometry};
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::geometry_processor::{CoordDimensions, GeomProcessor, GeometryType};
+use crate::property_processor::{ColumnInfo, ColumnValue, OwnedColumnValue, PropertyProcessor};
+
+/// Recorded geometry event. Coordinate values are stored in a separate buffer.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Event {
+    Srid(Option<i32>),
+    /// x, y; idx
+    Xy(usize),
+    /// x, y and the present values of z, m, t; tm, idx
+    Coordinate {
+        z: bool,
+        m: bool,
+        t: bool,
+        tm: Option<u64>,
+        idx: usize,
+    },
+    EmptyPoint(usize),
+    /// Geometry type, tagged, size, idx
+    Begin(GeometryType, bool, usize, usize),
+    /// Geometry type, tagged, idx
+    End(GeometryType, bool, usize),
+}
+
+/// Owned geometry recorded from [`GeomProcessor`] events.
+///
+/// All events are stored in flat buffers and can be replayed into any processor later,
+/// e.g. after sorting or sending features to another thread. Coordinates are recorded with
+/// all dimensions and reduced to the dimensions requested by the processor on replay.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(all(feature = "with-geojson", feature = "with-wkt"))]
+/// # {
+/// use geozero::{RecordedGeometry, ToWkt};
+/// use geozero::geojson::GeoJson;
+///
+/// let geom = RecordedGeometry::record(&GeoJson(r#"{"type": "Point", "coordinates": [1, 2]}"#)).unwrap();
+/// assert_eq!(geom.to_wkt().unwrap(), "POINT(1 2)");
+/// # }
+/// ```
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct RecordedGeometry {
+    events: Vec<Event>,
+    coords: Vec<f64>,
+}
+
+impl RecordedGeometry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the events of a geometry
+    pub fn record<G: GeozeroGeometry>(geom: &G) -> Result<Self> {
+        let mut recorder = FeatureRecorder::new();
+        geom.process_geom(&mut recorder)?;
+        Ok(recorder.feature.geometry)
+    }
+
+    /// Whether no geometry events were recorded
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Remove all recorded events, keeping the allocated buffers
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.coords.clear();
+    }
+}
+
+impl GeozeroGeometry for RecordedGeometry {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        let dims = processor.dimensions();
+        let multi_dim = processor.multi_dim();
+        let mut coords = self.coords.iter().copied();
+        let mut next = || coords.next().unwrap_or(f64::NAN);
+        for event in &self.events {
+            match *event {
+                Event::Srid(srid) => processor.srid(srid)?,
+                Event::Xy(idx) => {
+                    let (x, y) = (next(), next());
+                    if multi_dim {
+                        processor.coordinate(x, y, None, None, None, None, idx)?;
+                    } else {
+                        processor.xy(x, y, idx)?;
+                    }
+                }
+                Event::Coordinate { z, m, t, tm, idx } => {
+                    let (x, y) = (next(), next());
+                    let z = if z { Some(next()) } else { None };
+                    let m = if m { Some(next()) } else { None };
+                    let t = if t { Some(next()) } else { None };
+                    if multi_dim {
+                        processor.coordinate(
+                            x,
+                            y,
+                            z.filter(|_| dims.z),
+                            m.filter(|_| dims.m),
+                            t.filter(|_| dims.t),
+                            tm.filter(|_| dims.tm),
+                            idx,
+                        )?;
+                    } else {
+                        processor.xy(x, y, idx)?;
+                    }
+                }
+                Event::EmptyPoint(idx) => processor.empty_point(idx)?,
+                Event::Begin(geometry_type, tagged, size, idx) => match geometry_type {
+                    GeometryType::Point => processor.point_begin(idx),
+                    GeometryType::MultiPoint => processor.multipoint_begin(size, idx),
+                    GeometryType::LineString => processor.linestring_begin(tagged, size, idx),
+                    GeometryType::MultiLineString => processor.multilinestring_begin(size, idx),
+                    GeometryType::Polygon => processor.polygon_begin(tagged, size, idx),
+                    GeometryType::MultiPolygon => processor.multipolygon_begin(size, idx),
+                    GeometryType::GeometryCollection => {
+                        processor.geometrycollection_begin(size, idx)
+                    }
+                    GeometryType::CircularString => processor.circularstring_begin(size, idx),
+                    GeometryType::CompoundCurve => processor.compoundcurve_begin(size, idx),
+                    GeometryType::CurvePolygon => processor.curvepolygon_begin(size, idx),
+                    GeometryType::MultiCurve => processor.multicurve_begin(size, idx),
+                    GeometryType::MultiSurface => processor.multisurface_begin(size, idx),
+                    GeometryType::Triangle => processor.triangle_begin(tagged, size, idx),
+                    GeometryType::PolyhedralSurface => processor.polyhedralsurface_begin(size, idx),
+                    GeometryType::Tin => processor.tin_begin(size, idx),
+                    // Not emitted by GeomProcessor
+                    GeometryType::Unknown | GeometryType::Curve | GeometryType::Surface => Ok(()),
+                }?,
+                Event::End(geometry_type, tagged, idx) => match geometry_type {
+                    GeometryType::Point => processor.point_end(idx),
+                    GeometryType::MultiPoint => processor.multipoint_end(idx),
+                    GeometryType::LineString => processor.linestring_end(tagged, idx),
+                    GeometryType::MultiLineString => processor.multilinestring_end(idx),
+                    GeometryType::Polygon => processor.polygon_end(tagged, idx),
+                    GeometryType::MultiPolygon => processor.multipolygon_end(idx),
+                    GeometryType::GeometryCollection => processor.geometrycollection_end(idx),
+                    GeometryType::CircularString => processor.circularstring_end(idx),
+                    GeometryType::CompoundCurve => processor.compoundcurve_end(idx),
+                    GeometryType::CurvePolygon => processor.curvepolygon_end(idx),
+                    GeometryType::MultiCurve => processor.multicurve_end(idx),
+                    GeometryType::MultiSurface => processor.multisurface_end(idx),
+                    GeometryType::Triangle => processor.triangle_end(tagged, idx),
+                    GeometryType::PolyhedralSurface => processor.polyhedralsurface_end(idx),
+                    GeometryType::Tin => processor.tin_end(idx),
+                    GeometryType::Unknown | GeometryType::Curve | GeometryType::Surface => Ok(()),
+                }?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Owned feature recorded from [`PropertyProcessor`] and [`GeomProcessor`] events.
+///
+/// Properties are replayed with their original index. On replay with
+/// [`FeatureAccess::process`], properties are processed before the geometry and
+/// empty properties or geometries are omitted.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct RecordedFeature {
+    properties: Vec<(usize, String, OwnedColumnValue)>,
+    geometry: RecordedGeometry,
+}
+
+impl RecordedFeature {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record properties and geometry of a feature
+    pub fn record<F: FeatureAccess>(feature: &F) -> Result<Self> {
+        let mut recorder = FeatureRecorder::new();
+        let _ = feature.process_properties(&mut recorder)?;
+        feature.process_geom(&mut recorder)?;
+        Ok(recorder.feature)
+    }
+
+    /// Record all features of a datasource
+    pub fn record_all<D: GeozeroDatasource>(datasource: &mut D) -> Result<Vec<Self>> {
+        let mut recorder = FeatureRecorder::new();
+        datasource.process(&mut recorder)?;
+        Ok(recorder.features)
+    }
+
+    /// Recorded properties with their original index
+    pub fn indexed_properties(&self) -> impl Iterator<Item = (usize, &str, ColumnValue<'_>)> {
+        self.properties
+            .iter()
+            .map(|(idx, name, value)| (*idx, name.as_str(), value.as_column_value()))
+    }
+
+    /// Recorded geometry
+    pub fn geometry(&self) -> &RecordedGeometry {
+        &self.geometry
+    }
+
+    /// Remove all recorded properties and geometry events, keeping the allocated buffers
+    pub fn clear(&mut self) {
+        self.properties.clear();
+        self.geometry.clear();
+    }
+}
+
+impl FeatureProperties for RecordedFeature {
+    fn process_properties<P: PropertyProcessor>(&self, processor: &mut P) -> Result<bool> {
+        for (idx, name, value) in &self.properties {
+            if processor.property(*idx, name, &value.as_column_value())? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+impl GeozeroGeometry for RecordedFeature {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        self.geometry.process_geom(processor)
+    }
+}
+
+impl FeatureAccess for RecordedFeature {
+    /// Process feature geometry and properties, omitting empty properties or geometry.
+    fn process<P: FeatureProcessor>(&self, processor: &mut P, idx: u64) -> Result<()> {
+        processor.feature_begin(idx)?;
+        if !self.properties.is_empty() {
+            processor.properties_begin()?;
+            let _ = self.process_properties(processor)?;
+            processor.properties_end()?;
+        }
+        if !self.geometry.is_empty() {
+            processor.geometry_begin()?;
+            self.geometry.process_geom(processor)?;
+            processor.geometry_end()?;
+        }
+        processor.feature_end(idx)
+    }
+}
+
+/// Processor recording features as [`RecordedFeature`].
+///
+/// Events outside of features are discarded, except for the announced schema.
+#[derive(Default)]
+pub struct FeatureRecorder {
+    features: Vec<RecordedFeature>,
+    feature: RecordedFeature,
+    schema: Option<(Vec<ColumnInfo>, GeometryType)>,
+}
+
+impl FeatureRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Recorded features
+    pub fn features(&self) -> &[RecordedFeature] {
+        &self.features
+    }
+
+    /// Return the recorded features
+    pub fn into_features(self) -> Vec<RecordedFeature> {
+        self.features
+    }
+
+    /// Announced property columns and geometry type
+    pub fn schema(&self) -> Option<(&[ColumnInfo], GeometryType)> {
+        self.schema
+            .as_ref()
+            .map(|(columns, geometry_type)| (columns.as_slice(), *geometry_type))
+    }
+
+    /// Remove and return the last recorded feature
+    pub(crate) fn pop_feature(&mut self) -> Option<RecordedFeature> {
+        self.features.pop()
+    }
+
+    fn emit(&mut self, event: Event) -> Result<()> {
+        self.feature.geometry.events.push(event);
+        Ok(())
+    }
+}
+
+impl FeatureProcessor for FeatureRecorder {
+    fn schema(&mut self, columns: &[ColumnInfo], geometry_type: GeometryType) -> Result<()> {
+        self.schema = Some((columns.to_vec(), geometry_type));
+        Ok(())
+    }
+    fn feature_begin(&mut self, _idx: u64) -> Result<()> {
+        self.feature.clear();
+        Ok(())
+    }
+    fn feature_end(&mut self, _idx: u64) -> Result<()> {
+        self.features.push(std::mem::take(&mut self.feature));
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for FeatureRecorder {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.feature
+            .properties
+            .push((idx, name.to_string(), value.into()));
+        Ok(false)
+    }
+}
+
+impl GeomProcessor for FeatureRecorder {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions {
+            z: true,
+            m: true,
+            t: true,
+            tm: true,
+        }
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        self.emit(Event::Srid(srid))
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.feature.geometry.coords.extend([x, y]);
+        self.emit(Event::Xy(idx))
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.feature.geometry.coords.extend([x, y]);
+        self.feature.geometry.coords.extend(z);
+        self.feature.geometry.coords.extend(m);
+        self.feature.geometry.coords.extend(t);
+        self.emit(Event::Coordinate {
+            z: z.is_some(),
+            m: m.is_some(),
+            t: t.is_some(),
+            tm,
+            idx,
+        })
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::EmptyPoint(idx))
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::Point, true, 1, idx))
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::Point, true, idx))
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::MultiPoint, true, size, idx))
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::MultiPoint, true, idx))
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::LineString, tagged, size, idx))
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::LineString, tagged, idx))
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::MultiLineString, true, size, idx))
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::MultiLineString, true, idx))
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::Polygon, tagged, size, idx))
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::Polygon, tagged, idx))
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::MultiPolygon, true, size, idx))
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::MultiPolygon, true, idx))
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(
+            GeometryType::GeometryCollection,
+            true,
+            size,
+            idx,
+        ))
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::GeometryCollection, true, idx))
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::CircularString, true, size, idx))
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::CircularString, true, idx))
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::CompoundCurve, true, size, idx))
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::CompoundCurve, true, idx))
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::CurvePolygon, true, size, idx))
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::CurvePolygon, true, idx))
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::MultiCurve, true, size, idx))
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::MultiCurve, true, idx))
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::MultiSurface, true, size, idx))
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::MultiSurface, true, idx))
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::Triangle, tagged, size, idx))
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::Triangle, tagged, idx))
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(
+            GeometryType::PolyhedralSurface,
+            true,
+            size,
+            idx,
+        ))
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::PolyhedralSurface, true, idx))
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.emit(Event::Begin(GeometryType::Tin, true, size, idx))
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.emit(Event::End(GeometryType::Tin, true, idx))
+    }
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "with-geojson", feature = "with-wkt"))]
+mod test {
+    use super::*;
+    use crate::geojson::{GeoJson, GeoJsonLineReader, GeoJsonWriter};
+    use crate::{FeatureIterator, ToWkt};
+    use serde_json::json;
+
+    #[test]
+    fn geometry() -> Result<()> {
+        let geojson = GeoJson(
+            r#"{"type": "MultiPolygon", "coordinates": [[[[0,0],[1,0],[1,1],[0,0]]],[[[5,5],[6,5],[6,6],[5,5]]]]}"#,
+        );
+        let recorded = RecordedGeometry::record(&geojson)?;
+        assert!(!recorded.is_empty());
+        assert_eq!(recorded.to_wkt()?, geojson.to_wkt()?);
+        assert_eq!(recorded.bbox()?, geojson.bbox()?);
+        Ok(())
+    }
+
+    #[test]
+    fn dimensions() -> Result<()> {
+        let mut recorder = FeatureRecorder::new();
+        recorder.linestring_begin(true, 2, 0)?;
+        recorder.coordinate(1.0, 2.0, Some(3.0), Some(4.0), None, None, 0)?;
+        recorder.coordinate(5.0, 6.0, Some(7.0), Some(8.0), None, None, 1)?;
+        recorder.linestring_end(true, 0)?;
+        let recorded = recorder.feature.geometry;
+        assert_eq!(recorded.to_wkt()?, "LINESTRING(1 2,5 6)");
+        assert_eq!(
+            recorded.to_wkt_ndim(CoordDimensions::xyz())?,
+            "LINESTRING(1 2 3,5 6 7)"
+        );
+        assert_eq!(
+            recorded.to_wkt_ndim(CoordDimensions::xyzm())?,
+            "LINESTRING(1 2 3 4,5 6 7 8)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn features() -> Result<()> {
+        let input = r#"{"type": "Feature", "properties": {"name": "b", "pop": 20}, "geometry": {"type": "Point", "coordinates": [2, 2]}}
+{"type": "Feature", "properties": {"name": "a", "pop": 10}, "geometry": {"type": "Point", "coordinates": [1, 1]}}
+"#;
+        let mut features =
+            RecordedFeature::record_all(&mut GeoJsonLineReader::new(input.as_bytes()))?;
+        assert_eq!(features.len(), 2);
+        features.sort_by_key(|feature| feature.property::<i64>("pop").unwrap());
+        assert_eq!(features[0].property::<String>("name")?, "a");
+        assert_eq!(features[0].to_wkt()?, "POINT(1 1)");
+
+        let mut out: Vec<u8> = Vec::new();
+        features[1].process(&mut GeoJsonWriter::new(&mut out), 0)?;
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "type": "Feature",
+                "properties": {"name": "b", "pop": 20},
+                "geometry": {"type": "Point", "coordinates": [2, 2]}
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn send_to_thread() -> Result<()> {
+        let input = r#"{"type": "Feature", "properties": {"name": "a"}, "geometry": {"type": "Point", "coordinates": [1, 1]}}"#;
+        let mut features = GeoJson(input).feature_iter()?;
+        let feature = RecordedFeature::record(&features.next_feature()?.unwrap())?;
+        let wkt = std::thread::spawn(move || feature.to_wkt())
+            .join()
+            .unwrap()?;
+        assert_eq!(wkt, "POINT(1 1)");
+        Ok(())
+    }
+}
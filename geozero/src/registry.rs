@@ -0,0 +1,380 @@
+use crate::error::{GeozeroError, Result};
+use crate::{Bounds, DynDatasource, FeatureProcessor};
+use std::ffi::OsStr;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+
+/// Open a boxed datasource from a buffered reader.
+pub type OpenFn =
+    for<'a> fn(Box<dyn BufRead + 'a>, &DriverOptions) -> Result<Box<dyn DynDatasource + 'a>>;
+
+/// Create a boxed feature writer.
+pub type CreateFn =
+    for<'a> fn(Box<dyn Write + 'a>, &DriverOptions) -> Result<Box<dyn FeatureProcessor + 'a>>;
+
+/// Reading and/or writing support for a data format.
+#[derive(Clone, Copy, Debug)]
+pub struct Driver {
+    /// Format name, e.g. `"GeoJSON"`
+    pub name: &'static str,
+    /// File extensions handled by this driver, without leading dot
+    pub extensions: &'static [&'static str],
+    /// Detect the format from the first bytes of an input and the driver options
+    pub sniff: Option<fn(&[u8], &DriverOptions) -> bool>,
+    /// Datasource constructor, if the format can be read
+    pub open: Option<OpenFn>,
+    /// Writer constructor, if the format can be written
+    pub create: Option<CreateFn>,
+}
+
+impl Driver {
+    /// Check whether the file extension of `path` is handled by this driver.
+    pub fn matches_path(&self, path: &Path) -> bool {
+        path.extension().and_then(OsStr::to_str).is_some_and(|ext| {
+            self.extensions
+                .iter()
+                .any(|candidate| candidate.eq_ignore_ascii_case(ext))
+        })
+    }
+}
+
+/// Format specific options passed to drivers.
+#[derive(Clone, Default, Debug)]
+pub struct DriverOptions {
+    /// Name of the column holding a WKT geometry (CSV)
+    pub geometry_column: Option<String>,
+    /// Extent of the written data, required for SVG output
+    pub extent: Option<Bounds>,
+}
+
+/// Registry of format drivers.
+///
+/// [`DriverRegistry::new`] contains the drivers of all enabled `with-*` features.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(all(feature = "with-geojson", feature = "with-wkt"))]
+/// # {
+/// use geozero::{DriverOptions, DriverRegistry, GeozeroDatasource};
+///
+/// let registry = DriverRegistry::new();
+/// let input = br#"{"type": "Point", "coordinates": [1, 1]}"#;
+/// let mut ds = registry.open_reader(&input[..], &DriverOptions::default()).unwrap();
+/// let mut out: Vec<u8> = Vec::new();
+/// {
+///     let mut writer = registry
+///         .create_writer("out.wkt", &mut out, &DriverOptions::default())
+///         .unwrap();
+///     ds.process(&mut writer).unwrap();
+/// }
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(1 1)");
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DriverRegistry {
+    drivers: Vec<Driver>,
+}
+
+impl Default for DriverRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DriverRegistry {
+    /// Registry with all built-in drivers of enabled features.
+    pub fn new() -> Self {
+        DriverRegistry {
+            drivers: vec![
+                #[cfg(feature = "with-csv")]
+                crate::csv::driver::CSV,
+                // GeoJSON Lines has to be sniffed before GeoJSON
+                #[cfg(feature = "with-geojson")]
+                crate::geojson::driver::GEOJSON_LINES,
+                #[cfg(feature = "with-geojson")]
+                crate::geojson::driver::GEOJSON,
+                #[cfg(feature = "with-gpx")]
+                crate::gpx::driver::GPX,
+                #[cfg(feature = "with-mvt")]
+                crate::mvt::driver::MVT,
+                #[cfg(feature = "with-svg")]
+                crate::svg::driver::SVG,
+                #[cfg(feature = "with-wkt")]
+                crate::wkt::driver::WKT,
+            ],
+        }
+    }
+
+    /// Registry without any drivers.
+    pub fn empty() -> Self {
+        DriverRegistry {
+            drivers: Vec::new(),
+        }
+    }
+
+    /// Register a driver. Drivers registered later take precedence.
+    pub fn register(&mut self, driver: Driver) {
+        self.drivers.insert(0, driver);
+    }
+
+    /// Registered drivers.
+    pub fn drivers(&self) -> &[Driver] {
+        &self.drivers
+    }
+
+    /// Find driver by (case insensitive) name.
+    pub fn by_name(&self, name: &str) -> Option<&Driver> {
+        self.drivers
+            .iter()
+            .find(|driver| driver.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Find reading driver by file extension.
+    pub fn reader_for_path(&self, path: &Path) -> Option<&Driver> {
+        self.drivers
+            .iter()
+            .find(|driver| driver.open.is_some() && driver.matches_path(path))
+    }
+
+    /// Find writing driver by file extension.
+    pub fn writer_for_path(&self, path: &Path) -> Option<&Driver> {
+        self.drivers
+            .iter()
+            .find(|driver| driver.create.is_some() && driver.matches_path(path))
+    }
+
+    /// Find reading driver by the first bytes of an input.
+    pub fn sniff(&self, buf: &[u8], options: &DriverOptions) -> Option<&Driver> {
+        self.drivers.iter().find(|driver| {
+            driver.open.is_some() && driver.sniff.is_some_and(|sniff| sniff(buf, options))
+        })
+    }
+
+    /// Open file, detecting the format by extension or content.
+    pub fn open_path<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: &DriverOptions,
+    ) -> Result<Box<dyn DynDatasource>> {
+        let path = path.as_ref();
+        let mut reader = BufReader::new(File::open(path)?);
+        let driver = match self.reader_for_path(path) {
+            Some(driver) => driver,
+            None => self.sniff(reader.fill_buf()?, options).ok_or_else(|| {
+                GeozeroError::Dataset(format!("unknown input format `{}`", path.display()))
+            })?,
+        };
+        open(driver, Box::new(reader), options)
+    }
+
+    /// Open reader, detecting the format by content.
+    pub fn open_reader<'a, R: BufRead + 'a>(
+        &self,
+        mut reader: R,
+        options: &DriverOptions,
+    ) -> Result<Box<dyn DynDatasource + 'a>> {
+        let driver = self
+            .sniff(reader.fill_buf()?, options)
+            .ok_or_else(|| GeozeroError::Dataset("unknown input format".to_string()))?;
+        open(driver, Box::new(reader), options)
+    }
+
+    /// Create a writer for the format given by the file extension of `path`.
+    ///
+    /// `path` is only used for format detection, the output is written to `out`.
+    pub fn create_writer<'a, P: AsRef<Path>, W: Write + 'a>(
+        &self,
+        path: P,
+        out: W,
+        options: &DriverOptions,
+    ) -> Result<Box<dyn FeatureProcessor + 'a>> {
+        let path = path.as_ref();
+        let create = self
+            .writer_for_path(path)
+            .and_then(|driver| driver.create)
+            .ok_or_else(|| {
+                GeozeroError::Dataset(format!("unknown output format `{}`", path.display()))
+            })?;
+        create(Box::new(out), options)
+    }
+}
+
+fn open<'a>(
+    driver: &Driver,
+    reader: Box<dyn BufRead + 'a>,
+    options: &DriverOptions,
+) -> Result<Box<dyn DynDatasource + 'a>> {
+    let open = driver.open.ok_or_else(|| {
+        GeozeroError::Dataset(format!("reading {} is not supported", driver.name))
+    })?;
+    open(reader, options)
+}
+
+/// Skip UTF-8 BOM and leading whitespace.
+#[cfg(any(
+    feature = "with-csv",
+    feature = "with-geojson",
+    feature = "with-gpx",
+    feature = "with-wkt"
+))]
+pub(crate) fn trim_start(buf: &[u8]) -> &[u8] {
+    let buf = buf.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(buf);
+    let start = buf
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(buf.len());
+    &buf[start..]
+}
+
+/// Skip trailing whitespace.
+#[cfg(any(feature = "with-csv", feature = "with-geojson"))]
+pub(crate) fn trim_end(buf: &[u8]) -> &[u8] {
+    let end = buf
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(0, |pos| pos + 1);
+    &buf[..end]
+}
+
+#[cfg(test)]
+#[cfg(all(feature = "with-geojson", feature = "with-wkt"))]
+mod test {
+    use super::*;
+    use crate::{FeatureRecorder, GeozeroDatasource, ToWkt};
+
+    fn sniffed(input: &[u8]) -> Option<&'static str> {
+        DriverRegistry::new()
+            .sniff(input, &DriverOptions::default())
+            .map(|driver| driver.name)
+    }
+
+    #[test]
+    fn sniff() {
+        assert_eq!(
+            sniffed(br#"  {"type": "FeatureCollection", "features": []}"#),
+            Some("GeoJSON")
+        );
+        assert_eq!(
+            sniffed(b"{\"type\": \"Point\", \"coordinates\": [1, 1]}\n{\"type\": \"Point\", \"coordinates\": [2, 2]}\n"),
+            Some("GeoJSONL")
+        );
+        assert_eq!(sniffed(b"\nPOINT(1 1)\nPOINT(2 2)"), Some("WKT"));
+        assert_eq!(sniffed(b"point (1 1)"), Some("WKT"));
+        assert_eq!(sniffed(b"name,geometry\n"), None);
+    }
+
+    #[test]
+    fn open_wkt_lines() -> Result<()> {
+        let registry = DriverRegistry::new();
+        let mut ds = registry.open_reader(
+            &b"\nPOINT(1 1)\nLINESTRING(1 2,\t3 4)\r\nPOINT(2 2)"[..],
+            &DriverOptions::default(),
+        )?;
+        let mut recorder = FeatureRecorder::new();
+        ds.process(&mut recorder)?;
+        let wkts = recorder
+            .features()
+            .iter()
+            .map(|feature| feature.geometry().to_wkt())
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(wkts, ["POINT(1 1)", "LINESTRING(1 2,3 4)", "POINT(2 2)"]);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "with-csv")]
+    fn sniff_csv() {
+        let options = DriverOptions {
+            geometry_column: Some("geometry".to_string()),
+            ..Default::default()
+        };
+        let sniffed = |input: &[u8]| {
+            DriverRegistry::new()
+                .sniff(input, &options)
+                .map(|driver| driver.name)
+        };
+        assert_eq!(sniffed(b"name,\"geometry\"\r\na,POINT(1 1)"), Some("CSV"));
+        assert_eq!(sniffed(b"name,geom\na,POINT(1 1)"), None);
+        assert_eq!(sniffed(b"POINT(1 1)"), Some("WKT"));
+    }
+
+    #[test]
+    #[cfg(feature = "with-mvt")]
+    fn sniff_mvt() {
+        let tile = std::fs::read("tests/data/tile.mvt").unwrap();
+        assert_eq!(sniffed(&tile), Some("MVT"));
+        assert_eq!(sniffed(&[0x1f, 0x8b, 0x08, 0x00]), Some("MVT"));
+    }
+
+    #[test]
+    fn by_path() {
+        let registry = DriverRegistry::new();
+        let name = |driver: Option<&Driver>| driver.map(|driver| driver.name);
+        assert_eq!(
+            name(registry.reader_for_path(Path::new("places.GeoJSON"))),
+            Some("GeoJSON")
+        );
+        assert_eq!(
+            name(registry.reader_for_path(Path::new("places.jsonl"))),
+            Some("GeoJSONL")
+        );
+        assert_eq!(name(registry.reader_for_path(Path::new("places"))), None);
+        assert_eq!(
+            name(registry.writer_for_path(Path::new("out.wkt"))),
+            Some("WKT")
+        );
+    }
+
+    #[test]
+    fn open_path() -> Result<()> {
+        let registry = DriverRegistry::new();
+        let mut ds = registry.open_path("tests/data/places.json", &DriverOptions::default())?;
+        let mut out: Vec<u8> = Vec::new();
+        {
+            let mut writer = registry.create_writer("out.jsonl", &mut out, &Default::default())?;
+            ds.process(&mut writer)?;
+        }
+        let out = std::str::from_utf8(&out).unwrap();
+        assert_eq!(out.lines().count(), 1249);
+        assert!(out.starts_with(r#"{"type": "Feature""#));
+        Ok(())
+    }
+
+    #[test]
+    fn boxed_datasources() -> Result<()> {
+        let registry = DriverRegistry::new();
+        let options = DriverOptions::default();
+        let mut datasources = vec![
+            registry.open_reader(&b"POINT(1 2)"[..], &options)?,
+            registry.open_reader(
+                &br#"{"type": "Point", "coordinates": [3, 4]}"#[..],
+                &options,
+            )?,
+        ];
+        let mut out: Vec<u8> = Vec::new();
+        {
+            let mut writer = registry.create_writer("out.wkt", &mut out, &options)?;
+            for ds in &mut datasources {
+                ds.process_dyn(&mut writer)?;
+            }
+        }
+        assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(1 2)POINT(3 4)");
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_format() {
+        let registry = DriverRegistry::new();
+        assert!(registry
+            .open_reader(&b"name,geometry\n"[..], &DriverOptions::default())
+            .is_err());
+        assert!(registry
+            .create_writer("out.xyz", Vec::new(), &DriverOptions::default())
+            .is_err());
+        assert!(DriverRegistry::empty()
+            .open_reader(&b"POINT(1 1)"[..], &DriverOptions::default())
+            .is_err());
+    }
+}
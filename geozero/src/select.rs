@@ -0,0 +1,348 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::{GeomProcessor, GeometryType};
+use crate::property_processor::{ColumnInfo, ColumnValue, OwnedColumnValue, PropertyProcessor};
+use std::collections::{HashMap, HashSet};
+
+/// Processor passing a selection of property columns to a wrapped processor.
+///
+/// In select mode, only the listed columns are passed in the order of the list. Property
+/// values are buffered until `properties_end` for reordering. Properties processed without
+/// `properties_begin` and `properties_end`, e.g. with
+/// [`process_properties`](crate::FeatureProperties::process_properties), are passed
+/// immediately in input order. In exclude mode, all columns except the listed ones
+/// are passed in input order. Columns can additionally be renamed.
+///
+/// An announced schema is reduced to the passed columns and the `idx` of passed properties
+/// is their position in the reduced schema. Without a schema, `idx` is the position in the
+/// selection list, or the number of preceding passed properties of the feature in exclude mode.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::{GeozeroDatasource, SelectProcessor};
+/// use geozero::geojson::{GeoJson, GeoJsonWriter};
+///
+/// let input = r#"{"type": "FeatureCollection", "features": [
+///     {"type": "Feature", "properties": {"id": 1, "name": "Bern", "pop": 134794}, "geometry": {"type": "Point", "coordinates": [7.44, 46.95]}}
+/// ]}"#;
+/// let mut json: Vec<u8> = Vec::new();
+/// let mut processor = SelectProcessor::new(GeoJsonWriter::new(&mut json), ["pop", "name"]);
+/// processor.rename("pop", "population");
+/// GeoJson(input).process(&mut processor).unwrap();
+/// assert!(std::str::from_utf8(&json)
+///     .unwrap()
+///     .contains(r#""properties": {"population": 134794, "name": "Bern"}"#));
+/// # }
+/// ```
+pub struct SelectProcessor<P> {
+    processor: P,
+    columns: Columns,
+    renames: HashMap<String, String>,
+    /// Output index by input column name, if a schema was announced
+    schema_idx: Option<HashMap<String, usize>>,
+    /// Buffered values of selected columns
+    values: Vec<Option<OwnedColumnValue>>,
+    /// Whether values are buffered until `properties_end`
+    buffering: bool,
+    /// Number of passed properties of the current feature
+    passed: usize,
+}
+
+enum Columns {
+    Select(Vec<String>),
+    Exclude(HashSet<String>),
+}
+
+impl<P> SelectProcessor<P> {
+    /// Pass only `columns`, in the given order
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(processor: P, columns: I) -> Self {
+        let columns: Vec<String> = columns.into_iter().map(Into::into).collect();
+        let values = vec![None; columns.len()];
+        Self::with_columns(processor, Columns::Select(columns), values)
+    }
+
+    /// Pass all columns except `columns`
+    pub fn with_exclude<I: IntoIterator<Item = S>, S: Into<String>>(
+        processor: P,
+        columns: I,
+    ) -> Self {
+        let columns = columns.into_iter().map(Into::into).collect();
+        Self::with_columns(processor, Columns::Exclude(columns), Vec::new())
+    }
+
+    fn with_columns(processor: P, columns: Columns, values: Vec<Option<OwnedColumnValue>>) -> Self {
+        SelectProcessor {
+            processor,
+            columns,
+            renames: HashMap::new(),
+            schema_idx: None,
+            values,
+            buffering: false,
+            passed: 0,
+        }
+    }
+
+    /// Pass column `name` as `new_name`
+    pub fn rename(&mut self, name: impl Into<String>, new_name: impl Into<String>) {
+        self.renames.insert(name.into(), new_name.into());
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    fn output_idx(&self, name: &str, default: usize) -> usize {
+        self.schema_idx
+            .as_ref()
+            .and_then(|schema_idx| schema_idx.get(name).copied())
+            .unwrap_or(default)
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for SelectProcessor<P> {
+    fn dataset_begin(&mut self, name: Option<&str>) -> Result<()> {
+        self.schema_idx = None;
+        self.processor.dataset_begin(name)
+    }
+    fn schema(&mut self, columns: &[ColumnInfo], geometry_type: GeometryType) -> Result<()> {
+        let passed: Vec<&ColumnInfo> = match &self.columns {
+            Columns::Select(selected) => selected
+                .iter()
+                .filter_map(|name| columns.iter().find(|column| &column.name == name))
+                .collect(),
+            Columns::Exclude(excluded) => columns
+                .iter()
+                .filter(|column| !excluded.contains(&column.name))
+                .collect(),
+        };
+        self.schema_idx = Some(
+            passed
+                .iter()
+                .enumerate()
+                .map(|(idx, column)| (column.name.clone(), idx))
+                .collect(),
+        );
+        let columns: Vec<ColumnInfo> = passed
+            .into_iter()
+            .map(|column| ColumnInfo {
+                name: self
+                    .renames
+                    .get(&column.name)
+                    .unwrap_or(&column.name)
+                    .clone(),
+                ..column.clone()
+            })
+            .collect();
+        self.processor.schema(&columns, geometry_type)
+    }
+    fn properties_begin(&mut self) -> Result<()> {
+        self.passed = 0;
+        self.values.fill(None);
+        self.buffering = matches!(self.columns, Columns::Select(_));
+        self.processor.properties_begin()
+    }
+    fn properties_end(&mut self) -> Result<()> {
+        self.buffering = false;
+        if let Columns::Select(selected) = &self.columns {
+            for (pos, value) in self.values.iter_mut().enumerate() {
+                let Some(value) = value.take() else {
+                    continue;
+                };
+                let name = &selected[pos];
+                let idx = self
+                    .schema_idx
+                    .as_ref()
+                    .and_then(|schema_idx| schema_idx.get(name).copied())
+                    .unwrap_or(pos);
+                let name = self.renames.get(name).unwrap_or(name);
+                if self
+                    .processor
+                    .property(idx, name, &value.as_column_value())?
+                {
+                    break;
+                }
+            }
+        }
+        self.processor.properties_end()
+    }
+    forward_feature_processor!(
+        processor;
+        dataset_end, feature_begin, feature_end, bytes_read, done, geometry_begin, geometry_end,
+    );
+}
+
+impl<P: GeomProcessor> GeomProcessor for SelectProcessor<P> {
+    forward_geom_processor!(processor);
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for SelectProcessor<P> {
+    fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        match &self.columns {
+            Columns::Select(selected) => {
+                let Some(pos) = selected.iter().position(|selected| selected == name) else {
+                    return Ok(false);
+                };
+                if self.buffering {
+                    self.values[pos] = Some(value.into());
+                    return Ok(false);
+                }
+                let idx = self.output_idx(name, pos);
+                let name = self.renames.get(name).map_or(name, String::as_str);
+                self.processor.property(idx, name, value)
+            }
+            Columns::Exclude(excluded) => {
+                if excluded.contains(name) {
+                    return Ok(false);
+                }
+                let idx = self.output_idx(name, self.passed);
+                self.passed += 1;
+                let name = self.renames.get(name).map_or(name, String::as_str);
+                self.processor.property(idx, name, value)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-geojson")]
+mod test {
+    use super::*;
+    use crate::geojson::{GeoJson, GeoJsonLineReader};
+    use crate::{FeatureProperties, FeatureRecorder, GeozeroDatasource, RecordedFeature};
+    use serde_json::json;
+
+    fn input() -> String {
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                {
+                    "type": "Feature",
+                    "properties": {"id": 1, "name": "a", "pop": 10},
+                    "geometry": {"type": "Point", "coordinates": [1, 1]}
+                },
+                {
+                    "type": "Feature",
+                    "properties": {"id": 2, "name": "b"},
+                    "geometry": {"type": "Point", "coordinates": [2, 2]}
+                }
+            ]
+        })
+        .to_string()
+    }
+
+    fn schema(recorder: &FeatureRecorder) -> Vec<&str> {
+        recorder
+            .schema()
+            .map(|(columns, _)| columns.iter().map(|column| column.name.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Properties of all recorded features
+    fn properties(recorder: &FeatureRecorder) -> Vec<(usize, String, String)> {
+        recorder
+            .features()
+            .iter()
+            .flat_map(|feature| feature.indexed_properties())
+            .map(|(idx, name, value)| (idx, name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn props(properties: &[(usize, &str, &str)]) -> Vec<(usize, String, String)> {
+        properties
+            .iter()
+            .map(|(idx, name, value)| (*idx, name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn select() -> Result<()> {
+        let mut processor =
+            SelectProcessor::new(FeatureRecorder::new(), ["pop", "name", "missing"]);
+        processor.rename("name", "NAME");
+        GeoJson(&input()).process(&mut processor)?;
+        let recorder = processor.into_inner();
+        assert_eq!(schema(&recorder), ["pop", "NAME"]);
+        assert_eq!(
+            properties(&recorder),
+            props(&[(0, "pop", "10"), (1, "NAME", "a"), (1, "NAME", "b")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn exclude() -> Result<()> {
+        let mut processor = SelectProcessor::with_exclude(FeatureRecorder::new(), ["id"]);
+        processor.rename("pop", "population");
+        GeoJson(&input()).process(&mut processor)?;
+        let recorder = processor.into_inner();
+        assert_eq!(schema(&recorder), ["name", "population"]);
+        assert_eq!(
+            properties(&recorder),
+            props(&[(0, "name", "a"), (1, "population", "10"), (0, "name", "b")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn without_schema() -> Result<()> {
+        let input = r#"{"type": "Feature", "properties": {"id": 1, "name": "a", "pop": 10}, "geometry": null}
+{"type": "Feature", "properties": {"id": 2, "pop": 20}, "geometry": null}
+"#;
+        let mut processor = SelectProcessor::new(FeatureRecorder::new(), ["pop", "name"]);
+        GeoJsonLineReader::new(input.as_bytes()).process(&mut processor)?;
+        assert_eq!(
+            properties(&processor.into_inner()),
+            props(&[(0, "pop", "10"), (1, "name", "a"), (0, "pop", "20")])
+        );
+
+        let mut processor = SelectProcessor::with_exclude(FeatureRecorder::new(), ["name"]);
+        GeoJsonLineReader::new(input.as_bytes()).process(&mut processor)?;
+        assert_eq!(
+            properties(&processor.into_inner()),
+            props(&[
+                (0, "id", "1"),
+                (1, "pop", "10"),
+                (0, "id", "2"),
+                (1, "pop", "20")
+            ])
+        );
+        Ok(())
+    }
+
+    /// Stops after the first property
+    struct FirstProperty(Vec<String>);
+
+    impl PropertyProcessor for FirstProperty {
+        fn property(&mut self, _idx: usize, name: &str, _value: &ColumnValue) -> Result<bool> {
+            self.0.push(name.to_string());
+            Ok(true)
+        }
+    }
+
+    #[test]
+    fn process_properties() -> Result<()> {
+        let features = RecordedFeature::record_all(&mut GeoJson(&input()))?;
+        let mut processor = SelectProcessor::new(FeatureRecorder::new(), ["pop", "name"]);
+        processor.rename("name", "NAME");
+        for (idx, feature) in (0..).zip(&features) {
+            processor.feature_begin(idx)?;
+            assert!(!feature.process_properties(&mut processor)?);
+            processor.feature_end(idx)?;
+        }
+        // Passed in input order
+        assert_eq!(
+            properties(&processor.into_inner()),
+            props(&[(1, "NAME", "a"), (0, "pop", "10"), (1, "NAME", "b")])
+        );
+
+        let mut processor = SelectProcessor::new(FirstProperty(Vec::new()), ["pop", "name"]);
+        assert!(features[0].process_properties(&mut processor)?);
+        assert_eq!(processor.into_inner().0, ["name"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,457 @@
+use crate::coord_buffer::{process_linestring, Coord};
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Simplification algorithm of [`SimplifyProcessor`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SimplifyAlgorithm {
+    /// Ramer-Douglas-Peucker: remove points closer than the tolerance to the simplified line
+    DouglasPeucker,
+    /// Visvalingam-Whyatt: remove points with an effective triangle area below the squared
+    /// tolerance
+    VisvalingamWhyatt,
+}
+
+/// Web Mercator distance of `pixels` at `zoom` with tiles of `tile_size` pixels.
+///
+/// Useful as zoom dependent simplification tolerance for EPSG:3857 coordinates.
+pub fn mercator_tolerance(zoom: u8, tile_size: u32, pixels: f64) -> f64 {
+    const WORLD_SIZE: f64 = 40_075_016.685_578_49;
+    pixels * WORLD_SIZE / (f64::from(tile_size) * 2f64.powi(i32::from(zoom)))
+}
+
+/// Processor simplifying lines and polygon rings before passing them to a wrapped processor.
+///
+/// Each LineString is buffered and emitted with the simplified coordinates. Lines keep at least
+/// 2 and rings at least 4 coordinates. The tolerance can be changed between features, e.g. when
+/// producing multiple zoom levels. A tolerance of 0 disables simplification.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::{GeozeroGeometry, SimplifyAlgorithm, SimplifyProcessor};
+/// use geozero::wkt::{Wkt, WktWriter};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor =
+///     SimplifyProcessor::new(WktWriter::new(&mut out), SimplifyAlgorithm::DouglasPeucker, 1.0);
+/// Wkt("LINESTRING(0 0,5 4,11 5.5,17.3 3.2,27.8 0.1)")
+///     .process_geom(&mut processor)
+///     .unwrap();
+/// assert_eq!(
+///     std::str::from_utf8(&out).unwrap(),
+///     "LINESTRING(0 0,5 4,11 5.5,27.8 0.1)"
+/// );
+/// # }
+/// ```
+pub struct SimplifyProcessor<P> {
+    processor: P,
+    algorithm: SimplifyAlgorithm,
+    tolerance: f64,
+    in_polygon: bool,
+    in_compoundcurve: bool,
+    line: Option<Vec<Coord>>,
+}
+
+impl<P: GeomProcessor> SimplifyProcessor<P> {
+    pub fn new(processor: P, algorithm: SimplifyAlgorithm, tolerance: f64) -> Self {
+        SimplifyProcessor {
+            processor,
+            algorithm,
+            tolerance,
+            in_polygon: false,
+            in_compoundcurve: false,
+            line: None,
+        }
+    }
+
+    /// Current simplification tolerance
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Set simplification tolerance for following geometries
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.tolerance = tolerance;
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    fn simplify(&self, coords: Vec<Coord>, min_size: usize) -> Vec<Coord> {
+        if coords.len() <= min_size || self.tolerance <= 0.0 {
+            return coords;
+        }
+        let points: Vec<(f64, f64)> = coords.iter().map(Coord::xy).collect();
+        let significance = match self.algorithm {
+            SimplifyAlgorithm::DouglasPeucker => douglas_peucker(&points),
+            SimplifyAlgorithm::VisvalingamWhyatt => visvalingam_whyatt(&points),
+        };
+        let mut keep: Vec<bool> = match self.algorithm {
+            SimplifyAlgorithm::DouglasPeucker => {
+                significance.iter().map(|s| *s > self.tolerance).collect()
+            }
+            SimplifyAlgorithm::VisvalingamWhyatt => {
+                let min_area = self.tolerance * self.tolerance;
+                significance.iter().map(|s| *s >= min_area).collect()
+            }
+        };
+        if keep.iter().filter(|k| **k).count() < min_size {
+            // Keep the most significant points
+            let mut order: Vec<usize> = (0..points.len()).collect();
+            order.sort_by(|a, b| significance[*b].total_cmp(&significance[*a]));
+            for idx in order.into_iter().take(min_size) {
+                keep[idx] = true;
+            }
+        }
+        coords
+            .into_iter()
+            .zip(keep)
+            .filter_map(|(coord, keep)| keep.then_some(coord))
+            .collect()
+    }
+}
+
+/// Distance of each point to the line simplified up to this point.
+///
+/// Points are kept by Douglas-Peucker, if their value is larger than the tolerance.
+fn douglas_peucker(points: &[(f64, f64)]) -> Vec<f64> {
+    let mut significance = vec![0.0; points.len()];
+    let last = points.len() - 1;
+    significance[0] = f64::INFINITY;
+    significance[last] = f64::INFINITY;
+    let mut segments = vec![(0, last, f64::INFINITY)];
+    while let Some((first, last, parent)) = segments.pop() {
+        if last <= first + 1 {
+            continue;
+        }
+        let (mut max_idx, mut max_dist) = (first + 1, -1.0);
+        for idx in first + 1..last {
+            let dist = segment_distance(points[idx], points[first], points[last]);
+            if dist > max_dist {
+                max_idx = idx;
+                max_dist = dist;
+            }
+        }
+        // Points of a removed segment are removed as well
+        let dist = max_dist.min(parent);
+        significance[max_idx] = dist;
+        segments.push((first, max_idx, dist));
+        segments.push((max_idx, last, dist));
+    }
+    significance
+}
+
+fn segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / len2).clamp(0.0, 1.0)
+    };
+    (p.0 - (a.0 + t * dx)).hypot(p.1 - (a.1 + t * dy))
+}
+
+fn triangle_area(a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> f64 {
+    ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)).abs() / 2.0
+}
+
+/// Candidate for removal with smallest area first
+#[derive(PartialEq)]
+struct VwPoint {
+    area: f64,
+    idx: usize,
+}
+
+impl Eq for VwPoint {}
+
+impl PartialOrd for VwPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for VwPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .area
+            .total_cmp(&self.area)
+            .then(other.idx.cmp(&self.idx))
+    }
+}
+
+/// Effective area of each point when it is removed.
+///
+/// Points are kept by Visvalingam-Whyatt, if their value is not smaller than the minimal area.
+fn visvalingam_whyatt(points: &[(f64, f64)]) -> Vec<f64> {
+    let n = points.len();
+    let mut significance = vec![0.0; n];
+    significance[0] = f64::INFINITY;
+    significance[n - 1] = f64::INFINITY;
+    let mut prev: Vec<usize> = (0..n).map(|idx| idx.saturating_sub(1)).collect();
+    let mut next: Vec<usize> = (1..=n).collect();
+    let mut areas = vec![f64::INFINITY; n];
+    let mut heap = BinaryHeap::with_capacity(n);
+    for idx in 1..n - 1 {
+        areas[idx] = triangle_area(points[idx - 1], points[idx], points[idx + 1]);
+        heap.push(VwPoint {
+            area: areas[idx],
+            idx,
+        });
+    }
+    let mut removed = vec![false; n];
+    let mut max_area: f64 = 0.0;
+    while let Some(VwPoint { area, idx }) = heap.pop() {
+        if removed[idx] || area.to_bits() != areas[idx].to_bits() {
+            // Outdated entry
+            continue;
+        }
+        removed[idx] = true;
+        // Removing a point never decreases the significance of already removed points
+        max_area = max_area.max(area);
+        significance[idx] = max_area;
+        let (p, nx) = (prev[idx], next[idx]);
+        next[p] = nx;
+        prev[nx] = p;
+        for neighbour in [p, nx] {
+            if neighbour == 0 || neighbour == n - 1 {
+                continue;
+            }
+            areas[neighbour] = triangle_area(
+                points[prev[neighbour]],
+                points[neighbour],
+                points[next[neighbour]],
+            );
+            heap.push(VwPoint {
+                area: areas[neighbour],
+                idx: neighbour,
+            });
+        }
+    }
+    significance
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for SimplifyProcessor<P> {
+    forward_feature_processor!(processor);
+}
+
+impl<P: GeomProcessor> GeomProcessor for SimplifyProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        if let Some(line) = &mut self.line {
+            line.push(Coord::Xy(x, y));
+            Ok(())
+        } else {
+            self.processor.xy(x, y, idx)
+        }
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if let Some(line) = &mut self.line {
+            line.push(Coord::Coordinate { x, y, z, m, t, tm });
+            Ok(())
+        } else {
+            self.processor.coordinate(x, y, z, m, t, tm, idx)
+        }
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.line = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        let line = self.line.take().unwrap_or_default();
+        // Untagged lines of (curve) polygons are rings, unless they are part of a compound curve
+        let min_size = if self.in_polygon && !self.in_compoundcurve && !tagged {
+            4
+        } else {
+            2
+        };
+        let line = self.simplify(line, min_size);
+        process_linestring(&mut self.processor, &line, tagged, idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.processor.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.processor.polygon_end(tagged, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.processor.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.processor.triangle_end(tagged, idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.in_compoundcurve = true;
+        self.processor.compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.in_compoundcurve = false;
+        self.processor.compoundcurve_end(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.in_polygon = true;
+        self.processor.curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.in_polygon = false;
+        self.processor.curvepolygon_end(idx)
+    }
+    forward_geom_processor!(
+        processor;
+        dimensions, multi_dim, srid, empty_point, point_begin, point_end, multipoint_begin,
+        multipoint_end, multilinestring_begin, multilinestring_end, multipolygon_begin,
+        multipolygon_end, geometrycollection_begin, geometrycollection_end, circularstring_begin,
+        circularstring_end, multicurve_begin, multicurve_end, multisurface_begin, multisurface_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for SimplifyProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{Wkt, WktWriter};
+    use crate::GeozeroGeometry;
+
+    fn simplified(wkt: &str, algorithm: SimplifyAlgorithm, tolerance: f64) -> Result<String> {
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = SimplifyProcessor::new(WktWriter::new(&mut out), algorithm, tolerance);
+        Wkt(wkt).process_geom(&mut processor)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn douglas_peucker() -> Result<()> {
+        let wkt = "LINESTRING(0 0,5 4,11 5.5,17.3 3.2,27.8 0.1)";
+        assert_eq!(
+            simplified(wkt, SimplifyAlgorithm::DouglasPeucker, 1.0)?,
+            "LINESTRING(0 0,5 4,11 5.5,27.8 0.1)"
+        );
+        assert_eq!(
+            simplified(wkt, SimplifyAlgorithm::DouglasPeucker, 10.0)?,
+            "LINESTRING(0 0,27.8 0.1)"
+        );
+        assert_eq!(
+            simplified(wkt, SimplifyAlgorithm::DouglasPeucker, 0.0)?,
+            wkt
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn visvalingam_whyatt() -> Result<()> {
+        let wkt = "LINESTRING(5 2,3 8,6 20,7 25,10 10)";
+        assert_eq!(
+            simplified(wkt, SimplifyAlgorithm::VisvalingamWhyatt, 30f64.sqrt())?,
+            "LINESTRING(5 2,7 25,10 10)"
+        );
+        let wkt =
+            "LINESTRING(0 0,1 0.2,2 -0.1,3 5,4 6,5 7,6 8.1,7 9,8 9,9 9,10 7.2,11 3,12 2.9,13 3.1)";
+        assert_eq!(
+            simplified(wkt, SimplifyAlgorithm::VisvalingamWhyatt, 0.6)?,
+            "LINESTRING(0 0,2 -0.1,3 5,7 9,9 9,10 7.2,11 3,13 3.1)"
+        );
+        assert_eq!(
+            simplified(wkt, SimplifyAlgorithm::VisvalingamWhyatt, 2.0)?,
+            "LINESTRING(0 0,2 -0.1,3 5,7 9,9 9,11 3,13 3.1)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn minimal_ring() -> Result<()> {
+        let wkt = "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0)),((0 0,1 0,1 1,0 0)))";
+        for algorithm in [
+            SimplifyAlgorithm::DouglasPeucker,
+            SimplifyAlgorithm::VisvalingamWhyatt,
+        ] {
+            assert_eq!(
+                simplified(wkt, algorithm, 100.0)?,
+                "MULTIPOLYGON(((0 0,10 0,10 10,0 0)),((0 0,1 0,1 1,0 0)))"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkb")]
+    fn minimal_curve_ring() -> Result<()> {
+        use crate::wkb::Ewkb;
+        // CURVEPOLYGON((0 0,10 0,10 10,0 10,0 0),COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,3 0,3 -1,0 -1,0 0)))
+        let ewkb = hex::decode("010A00000002000000010200000005000000000000000000000000000000000000000000000000002440000000000000000000000000000024400000000000002440000000000000000000000000000024400000000000000000000000000000000001090000000200000001080000000300000000000000000000000000000000000000000000000000F03F000000000000F03F0000000000000040000000000000000001020000000500000000000000000000400000000000000000000000000000084000000000000000000000000000000840000000000000F0BF0000000000000000000000000000F0BF00000000000000000000000000000000").unwrap();
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = SimplifyProcessor::new(
+            WktWriter::new(&mut out),
+            SimplifyAlgorithm::DouglasPeucker,
+            100.0,
+        );
+        Ewkb(ewkb).process_geom(&mut processor)?;
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "CURVEPOLYGON((0 0,10 0,10 10,0 0),COMPOUNDCURVE(CIRCULARSTRING(0 0,1 1,2 0),(2 0,0 0)))"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn zoom_tolerance() {
+        assert!((mercator_tolerance(0, 256, 1.0) - 156543.03392804097).abs() < 1e-6);
+        assert!((mercator_tolerance(10, 4096, 0.5) - 4.777314267823516).abs() < 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "with-geo")]
+    fn douglas_peucker_geo() -> Result<()> {
+        use crate::geo_types::GeoWriter;
+        use crate::ToGeo;
+        use geo::Simplify;
+
+        let wkt =
+            "LINESTRING(0 0,1 0.2,2 -0.1,3 5,4 6,5 7,6 8.1,7 9,8 9,9 9,10 7.2,11 3,12 2.9,13 3.1)";
+        let line = match Wkt(wkt).to_geo()? {
+            geo_types::Geometry::LineString(line) => line,
+            _ => unreachable!(),
+        };
+        for tolerance in [0.1, 0.6, 1.0, 2.0] {
+            let mut processor = SimplifyProcessor::new(
+                GeoWriter::new(),
+                SimplifyAlgorithm::DouglasPeucker,
+                tolerance,
+            );
+            Wkt(wkt).process_geom(&mut processor)?;
+            assert_eq!(
+                processor.into_inner().take_geometry(),
+                Some(line.simplify(&tolerance).into())
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+use crate::coord_buffer::{process_linestring, process_polygon, Coord};
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+
+/// Grid of [`SnapProcessor`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SnapGrid {
+    /// Grid cell size, e.g. `0.5`
+    Size(f64),
+    /// Number of decimal places
+    Decimals(u8),
+}
+
+impl SnapGrid {
+    /// Round value to the nearest grid value
+    pub fn snap(&self, value: f64) -> f64 {
+        match *self {
+            SnapGrid::Size(size) if size > 0.0 => (value / size).round() * size,
+            SnapGrid::Size(_) => value,
+            SnapGrid::Decimals(decimals) => {
+                let factor = 10f64.powi(i32::from(decimals));
+                (value * factor).round() / factor
+            }
+        }
+    }
+}
+
+/// Push coordinate, if its position differs from the previous one
+fn push_distinct(line: &mut Vec<Coord>, coord: Coord) {
+    let position = |coord: &Coord| match *coord {
+        Coord::Xy(x, y) => (x, y, None),
+        Coord::Coordinate { x, y, z, .. } => (x, y, z),
+    };
+    if line.last().map(position) != Some(position(&coord)) {
+        line.push(coord);
+    }
+}
+
+/// Processor snapping coordinates to a grid before passing them to a wrapped processor.
+///
+/// X, Y and Z values are rounded to the [`SnapGrid`], M values are unchanged. Consecutive
+/// vertices of lines and rings with identical snapped positions are emitted only once.
+/// Optionally, lines with less than 2 and rings with less than 4 remaining vertices are dropped.
+/// A polygon with a collapsed exterior ring is dropped as a whole. Collapsed geometries, which
+/// can't be dropped without changing the size of a parent, are emitted as empty geometries.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::{GeozeroGeometry, SnapGrid, SnapProcessor};
+/// use geozero::wkt::{Wkt, WktWriter};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = SnapProcessor::new(WktWriter::new(&mut out), SnapGrid::Decimals(2));
+/// Wkt("LINESTRING(8.123456 47.123456,8.124 47.1232,9.5 47.2)")
+///     .process_geom(&mut processor)
+///     .unwrap();
+/// assert_eq!(
+///     std::str::from_utf8(&out).unwrap(),
+///     "LINESTRING(8.12 47.12,9.5 47.2)"
+/// );
+/// # }
+/// ```
+pub struct SnapProcessor<P> {
+    processor: P,
+    grid: SnapGrid,
+    drop_collapsed: bool,
+    line: Option<Vec<Coord>>,
+    /// Lines of the current MultiLineString
+    lines: Option<Vec<Vec<Coord>>>,
+    /// Rings of the current Polygon
+    rings: Option<Vec<Vec<Coord>>>,
+    /// Polygons of the current MultiPolygon
+    polygons: Option<Vec<Vec<Vec<Coord>>>>,
+}
+
+impl<P: GeomProcessor> SnapProcessor<P> {
+    pub fn new(processor: P, grid: SnapGrid) -> Self {
+        SnapProcessor {
+            processor,
+            grid,
+            drop_collapsed: false,
+            line: None,
+            lines: None,
+            rings: None,
+            polygons: None,
+        }
+    }
+
+    /// Drop lines and rings collapsed by snapping
+    pub fn set_drop_collapsed(&mut self, drop_collapsed: bool) {
+        self.drop_collapsed = drop_collapsed;
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for SnapProcessor<P> {
+    forward_feature_processor!(processor);
+}
+
+impl<P: GeomProcessor> GeomProcessor for SnapProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let (x, y) = (self.grid.snap(x), self.grid.snap(y));
+        if let Some(line) = &mut self.line {
+            push_distinct(line, Coord::Xy(x, y));
+            Ok(())
+        } else {
+            self.processor.xy(x, y, idx)
+        }
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let (x, y) = (self.grid.snap(x), self.grid.snap(y));
+        let z = z.map(|z| self.grid.snap(z));
+        if let Some(line) = &mut self.line {
+            push_distinct(line, Coord::Coordinate { x, y, z, m, t, tm });
+            Ok(())
+        } else {
+            self.processor.coordinate(x, y, z, m, t, tm, idx)
+        }
+    }
+    fn linestring_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.line = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        let mut line = self.line.take().unwrap_or_default();
+        if let (false, Some(rings)) = (tagged, &mut self.rings) {
+            rings.push(line);
+            Ok(())
+        } else if let (false, Some(lines)) = (tagged, &mut self.lines) {
+            lines.push(line);
+            Ok(())
+        } else {
+            if tagged && self.drop_collapsed && line.len() < 2 {
+                line.clear();
+            }
+            process_linestring(&mut self.processor, &line, tagged, idx)
+        }
+    }
+    fn multilinestring_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.lines = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        let mut lines = self.lines.take().unwrap_or_default();
+        if self.drop_collapsed {
+            lines.retain(|line| line.len() >= 2);
+        }
+        self.processor.multilinestring_begin(lines.len(), idx)?;
+        for (i, line) in lines.iter().enumerate() {
+            process_linestring(&mut self.processor, line, false, i)?;
+        }
+        self.processor.multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, _tagged: bool, size: usize, _idx: usize) -> Result<()> {
+        self.rings = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        let mut rings = self.rings.take().unwrap_or_default();
+        if self.drop_collapsed {
+            if rings.first().is_some_and(|exterior| exterior.len() < 4) {
+                rings.clear();
+            }
+            rings.retain(|ring| ring.len() >= 4);
+        }
+        match (tagged, &mut self.polygons) {
+            (false, Some(polygons)) => {
+                polygons.push(rings);
+                Ok(())
+            }
+            _ => process_polygon(&mut self.processor, &rings, tagged, idx),
+        }
+    }
+    fn multipolygon_begin(&mut self, size: usize, _idx: usize) -> Result<()> {
+        self.polygons = Some(Vec::with_capacity(size));
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        let mut polygons = self.polygons.take().unwrap_or_default();
+        if self.drop_collapsed {
+            polygons.retain(|rings| !rings.is_empty());
+        }
+        self.processor.multipolygon_begin(polygons.len(), idx)?;
+        for (i, rings) in polygons.iter().enumerate() {
+            process_polygon(&mut self.processor, rings, false, i)?;
+        }
+        self.processor.multipolygon_end(idx)
+    }
+    forward_geom_processor!(
+        processor;
+        dimensions, multi_dim, srid, empty_point, point_begin, point_end, multipoint_begin,
+        multipoint_end, geometrycollection_begin, geometrycollection_end, circularstring_begin,
+        circularstring_end, compoundcurve_begin, compoundcurve_end, curvepolygon_begin,
+        curvepolygon_end, multicurve_begin, multicurve_end, multisurface_begin, multisurface_end,
+        triangle_begin, triangle_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin,
+        tin_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for SnapProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{Wkt, WktWriter};
+    use crate::GeozeroGeometry;
+
+    fn snapped(wkt: &str, grid: SnapGrid, drop_collapsed: bool) -> Result<String> {
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = SnapProcessor::new(WktWriter::new(&mut out), grid);
+        processor.set_drop_collapsed(drop_collapsed);
+        Wkt(wkt).process_geom(&mut processor)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn grid() {
+        assert_eq!(SnapGrid::Size(0.5).snap(1.26), 1.5);
+        assert_eq!(SnapGrid::Size(10.0).snap(-14.9), -10.0);
+        assert_eq!(SnapGrid::Decimals(3).snap(0.1 + 0.2), 0.3);
+        assert_eq!(SnapGrid::Decimals(0).snap(2.5), 3.0);
+        assert_eq!(SnapGrid::Size(0.0).snap(2.5), 2.5);
+    }
+
+    #[test]
+    fn points() -> Result<()> {
+        assert_eq!(
+            snapped("MULTIPOINT(1.1 1.1,0.9 0.9)", SnapGrid::Size(1.0), true)?,
+            "MULTIPOINT(1 1,1 1)"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn collapsed() -> Result<()> {
+        let wkt = "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(1 1,1.2 1,1.2 1.2,1 1)),((20 20,20.2 20,20.2 20.2,20 20)))";
+        assert_eq!(
+            snapped(wkt, SnapGrid::Size(1.0), false)?,
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(1 1)),((20 20)))"
+        );
+        assert_eq!(
+            snapped(wkt, SnapGrid::Size(1.0), true)?,
+            "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0)))"
+        );
+        assert_eq!(
+            snapped(
+                "MULTILINESTRING((0 0,0.1 0.1),(0 0,5 5))",
+                SnapGrid::Size(1.0),
+                true
+            )?,
+            "MULTILINESTRING((0 0,5 5))"
+        );
+        assert_eq!(
+            snapped(
+                "GEOMETRYCOLLECTION(LINESTRING(0 0,0.1 0.1),POLYGON((0 0,0.1 0,0.1 0.1,0 0)))",
+                SnapGrid::Size(1.0),
+                true
+            )?,
+            "GEOMETRYCOLLECTION(LINESTRING EMPTY,POLYGON EMPTY)"
+        );
+        Ok(())
+    }
+}
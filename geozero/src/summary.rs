@@ -0,0 +1,562 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::geometry_processor::{CoordDimensions, GeomProcessor, GeometryType};
+use crate::property_processor::{ColumnInfo, ColumnType, ColumnValue, PropertyProcessor};
+use crate::{Bounds, BoundsProcessor};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Statistics of a dataset collected by [`SummaryProcessor`].
+#[derive(Clone, Default, Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
+pub struct DatasetSummary {
+    /// Number of features
+    pub feature_count: u64,
+    /// Number of top-level geometries per geometry type
+    pub geometry_types: BTreeMap<GeometryType, u64>,
+    /// Number of processed coordinates
+    pub vertex_count: u64,
+    /// Dimensions of coordinates with at least one value
+    pub dimensions: CoordDimensions,
+    /// SRIDs announced by the reader
+    pub srids: BTreeSet<i32>,
+    /// Extent of all geometries, `None` if no coordinate was processed
+    pub extent: Option<Bounds>,
+    /// Property statistics in order of appearance
+    pub properties: Vec<PropertySummary>,
+}
+
+impl DatasetSummary {
+    /// Statistics of property `name`
+    pub fn property(&self, name: &str) -> Option<&PropertySummary> {
+        self.properties
+            .iter()
+            .find(|property| property.name == name)
+    }
+}
+
+/// Statistics of a feature property.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize))]
+pub struct PropertySummary {
+    /// Property name
+    pub name: String,
+    /// Type of all values, `None` if no value was processed
+    ///
+    /// Mixed integer types are reported as `Long`, mixed numeric types as `Double` and
+    /// other mixed types as `String`.
+    pub column_type: Option<ColumnType>,
+    /// Number of features with a value
+    pub count: u64,
+    /// Number of features with a null or missing value
+    pub missing_count: u64,
+    /// Minimal value of numeric, boolean, string and datetime properties
+    ///
+    /// Values of mixed type `String` columns are compared as strings.
+    pub min: Option<SummaryValue>,
+    /// Maximal value of numeric, boolean, string and datetime properties
+    pub max: Option<SummaryValue>,
+}
+
+/// Minimal or maximal property value.
+#[derive(Clone, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize), serde(untagged))]
+pub enum SummaryValue {
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl SummaryValue {
+    fn from_column_value(value: &ColumnValue) -> Option<Self> {
+        let value = match value {
+            ColumnValue::Bool(v) => SummaryValue::Bool(*v),
+            ColumnValue::Byte(v) => SummaryValue::Number(f64::from(*v)),
+            ColumnValue::UByte(v) => SummaryValue::Number(f64::from(*v)),
+            ColumnValue::Short(v) => SummaryValue::Number(f64::from(*v)),
+            ColumnValue::UShort(v) => SummaryValue::Number(f64::from(*v)),
+            ColumnValue::Int(v) => SummaryValue::Number(f64::from(*v)),
+            ColumnValue::UInt(v) => SummaryValue::Number(f64::from(*v)),
+            ColumnValue::Long(v) => SummaryValue::Number(*v as f64),
+            ColumnValue::ULong(v) => SummaryValue::Number(*v as f64),
+            ColumnValue::Float(v) => SummaryValue::Number(f64::from(*v)),
+            ColumnValue::Double(v) => SummaryValue::Number(*v),
+            ColumnValue::String(v) | ColumnValue::DateTime(v) => {
+                SummaryValue::String(v.to_string())
+            }
+            ColumnValue::Json(_)
+            | ColumnValue::Binary(_)
+            | ColumnValue::Null
+            | ColumnValue::List(_)
+            | ColumnValue::Map(_) => return None,
+            #[cfg(feature = "with-chrono")]
+            ColumnValue::Date(_)
+            | ColumnValue::Time(_)
+            | ColumnValue::Timestamp(_)
+            | ColumnValue::TimestampTz(_) => SummaryValue::String(value.to_string()),
+        };
+        match value {
+            SummaryValue::Number(v) if v.is_nan() => None,
+            value => Some(value),
+        }
+    }
+
+    fn into_string(self) -> Self {
+        match self {
+            SummaryValue::Bool(v) => SummaryValue::String(v.to_string()),
+            SummaryValue::Number(v) => SummaryValue::String(v.to_string()),
+            value => value,
+        }
+    }
+}
+
+fn is_integer(column_type: ColumnType) -> bool {
+    matches!(
+        column_type,
+        ColumnType::Byte
+            | ColumnType::UByte
+            | ColumnType::Short
+            | ColumnType::UShort
+            | ColumnType::Int
+            | ColumnType::UInt
+            | ColumnType::Long
+            | ColumnType::ULong
+    )
+}
+
+fn merge_column_types(a: ColumnType, b: ColumnType) -> ColumnType {
+    let is_float = |t| matches!(t, ColumnType::Float | ColumnType::Double);
+    if a == b {
+        a
+    } else if is_integer(a) && is_integer(b) {
+        ColumnType::Long
+    } else if (is_integer(a) || is_float(a)) && (is_integer(b) || is_float(b)) {
+        ColumnType::Double
+    } else {
+        ColumnType::String
+    }
+}
+
+impl PropertySummary {
+    fn new(name: &str) -> Self {
+        PropertySummary {
+            name: name.to_string(),
+            column_type: None,
+            count: 0,
+            missing_count: 0,
+            min: None,
+            max: None,
+        }
+    }
+
+    fn add(&mut self, value: &ColumnValue) {
+        // Null values are counted as missing
+        let Some(column_type) = value.column_type() else {
+            return;
+        };
+        self.column_type = Some(match self.column_type {
+            Some(current) => merge_column_types(current, column_type),
+            None => column_type,
+        });
+        self.count += 1;
+        if let Some(mut value) = SummaryValue::from_column_value(value) {
+            if self.column_type == Some(ColumnType::String) {
+                // Mixed types are compared as strings
+                value = value.into_string();
+                self.min = self.min.take().map(SummaryValue::into_string);
+                self.max = self.max.take().map(SummaryValue::into_string);
+            }
+            match &self.min {
+                Some(min) if *min <= value => {}
+                _ => self.min = Some(value.clone()),
+            }
+            match &self.max {
+                Some(max) if *max >= value => {}
+                _ => self.max = Some(value),
+            }
+        }
+    }
+}
+
+/// Processor collecting dataset statistics in one pass.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-geojson")]
+/// # {
+/// use geozero::geojson::GeoJson;
+/// use geozero::{ColumnType, GeometryType, GeozeroDatasource};
+///
+/// let mut geojson = GeoJson(
+///     r#"{"type": "FeatureCollection", "features": [
+///     {"type": "Feature", "properties": {"name": "a", "pop": 10}, "geometry": {"type": "Point", "coordinates": [1, 1]}},
+///     {"type": "Feature", "properties": {"pop": 2.5}, "geometry": {"type": "Point", "coordinates": [3, 2]}}
+///     ]}"#,
+/// );
+/// let summary = geojson.summary().unwrap();
+/// assert_eq!(summary.feature_count, 2);
+/// assert_eq!(summary.geometry_types[&GeometryType::Point], 2);
+/// let pop = summary.property("pop").unwrap();
+/// assert_eq!(pop.column_type, Some(ColumnType::Double));
+/// assert_eq!(summary.property("name").unwrap().missing_count, 1);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct SummaryProcessor {
+    summary: DatasetSummary,
+    bounds: BoundsProcessor,
+    property_idx: HashMap<String, usize>,
+    depth: usize,
+}
+
+impl SummaryProcessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Statistics of all processed features
+    pub fn summary(&self) -> DatasetSummary {
+        let mut summary = self.summary.clone();
+        summary.extent = self.bounds.bounds();
+        for property in &mut summary.properties {
+            property.missing_count = summary.feature_count.saturating_sub(property.count);
+        }
+        summary
+    }
+
+    fn property_summary(&mut self, name: &str) -> &mut PropertySummary {
+        let idx = match self.property_idx.get(name) {
+            Some(idx) => *idx,
+            None => {
+                let idx = self.summary.properties.len();
+                self.summary.properties.push(PropertySummary::new(name));
+                self.property_idx.insert(name.to_string(), idx);
+                idx
+            }
+        };
+        &mut self.summary.properties[idx]
+    }
+
+    fn geom_begin(&mut self, geometry_type: GeometryType) {
+        if self.depth == 0 {
+            *self
+                .summary
+                .geometry_types
+                .entry(geometry_type)
+                .or_insert(0) += 1;
+        }
+        self.depth += 1;
+    }
+
+    fn geom_end(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+}
+
+impl FeatureProcessor for SummaryProcessor {
+    fn schema(&mut self, columns: &[ColumnInfo], _geometry_type: GeometryType) -> Result<()> {
+        for column in columns {
+            self.property_summary(&column.name);
+        }
+        Ok(())
+    }
+    fn feature_begin(&mut self, _idx: u64) -> Result<()> {
+        self.summary.feature_count += 1;
+        Ok(())
+    }
+}
+
+impl GeomProcessor for SummaryProcessor {
+    fn dimensions(&self) -> CoordDimensions {
+        CoordDimensions {
+            z: true,
+            m: true,
+            t: true,
+            tm: true,
+        }
+    }
+    fn srid(&mut self, srid: Option<i32>) -> Result<()> {
+        if let Some(srid) = srid {
+            self.summary.srids.insert(srid);
+        }
+        Ok(())
+    }
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.summary.vertex_count += 1;
+        self.bounds.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.summary.vertex_count += 1;
+        let dims = &mut self.summary.dimensions;
+        dims.z |= z.is_some();
+        dims.m |= m.is_some();
+        dims.t |= t.is_some();
+        dims.tm |= tm.is_some();
+        self.bounds.coordinate(x, y, z, m, t, tm, idx)
+    }
+    fn empty_point(&mut self, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::Point);
+        self.geom_end();
+        Ok(())
+    }
+    fn point_begin(&mut self, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::Point);
+        Ok(())
+    }
+    fn point_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn multipoint_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::MultiPoint);
+        Ok(())
+    }
+    fn multipoint_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn linestring_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::LineString);
+        Ok(())
+    }
+    fn linestring_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn multilinestring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::MultiLineString);
+        Ok(())
+    }
+    fn multilinestring_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn polygon_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::Polygon);
+        Ok(())
+    }
+    fn polygon_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn multipolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::MultiPolygon);
+        Ok(())
+    }
+    fn multipolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn geometrycollection_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::GeometryCollection);
+        Ok(())
+    }
+    fn geometrycollection_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn circularstring_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::CircularString);
+        Ok(())
+    }
+    fn circularstring_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn compoundcurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::CompoundCurve);
+        Ok(())
+    }
+    fn compoundcurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn curvepolygon_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::CurvePolygon);
+        Ok(())
+    }
+    fn curvepolygon_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn multicurve_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::MultiCurve);
+        Ok(())
+    }
+    fn multicurve_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn multisurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::MultiSurface);
+        Ok(())
+    }
+    fn multisurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn triangle_begin(&mut self, _tagged: bool, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::Triangle);
+        Ok(())
+    }
+    fn triangle_end(&mut self, _tagged: bool, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn polyhedralsurface_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::PolyhedralSurface);
+        Ok(())
+    }
+    fn polyhedralsurface_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+    fn tin_begin(&mut self, _size: usize, _idx: usize) -> Result<()> {
+        self.geom_begin(GeometryType::Tin);
+        Ok(())
+    }
+    fn tin_end(&mut self, _idx: usize) -> Result<()> {
+        self.geom_end();
+        Ok(())
+    }
+}
+
+impl PropertyProcessor for SummaryProcessor {
+    fn property(&mut self, _idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.property_summary(name).add(value);
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-geojson")]
+mod test {
+    use super::*;
+    use crate::geojson::GeoJson;
+    use crate::GeozeroDatasource;
+
+    #[test]
+    fn geojson_summary() -> Result<()> {
+        let mut geojson = GeoJson(
+            r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"name": "b", "pop": 10, "capital": true},
+             "geometry": {"type": "Point", "coordinates": [1, 1, 5]}},
+            {"type": "Feature", "properties": {"name": "a", "pop": 12},
+             "geometry": {"type": "LineString", "coordinates": [[0, 0], [2, 3]]}},
+            {"type": "Feature", "properties": {"name": "c", "pop": -1.5},
+             "geometry": {"type": "GeometryCollection", "geometries": [
+                {"type": "Point", "coordinates": [1, 1]},
+                {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]}]}}
+            ]}"#,
+        );
+        let summary = geojson.summary()?;
+        assert_eq!(summary.feature_count, 3);
+        assert_eq!(
+            summary
+                .geometry_types
+                .clone()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![
+                (GeometryType::Point, 1),
+                (GeometryType::LineString, 1),
+                (GeometryType::GeometryCollection, 1)
+            ]
+        );
+        assert_eq!(summary.vertex_count, 8);
+        assert!(summary.dimensions.z && !summary.dimensions.m);
+        let extent = summary.extent.unwrap();
+        assert_eq!(
+            (extent.min_x, extent.min_y, extent.max_x, extent.max_y),
+            (0.0, 0.0, 2.0, 3.0)
+        );
+        assert_eq!(extent.z, Some((5.0, 5.0)));
+
+        let names: Vec<_> = summary.properties.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["capital", "name", "pop"]);
+        let name = summary.property("name").unwrap();
+        assert_eq!(name.column_type, Some(ColumnType::String));
+        assert_eq!(name.min, Some(SummaryValue::String("a".to_string())));
+        assert_eq!(name.max, Some(SummaryValue::String("c".to_string())));
+        let pop = summary.property("pop").unwrap();
+        assert_eq!(pop.column_type, Some(ColumnType::Double));
+        assert_eq!(pop.min, Some(SummaryValue::Number(-1.5)));
+        assert_eq!(pop.max, Some(SummaryValue::Number(12.0)));
+        let capital = summary.property("capital").unwrap();
+        assert_eq!((capital.count, capital.missing_count), (1, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn mixed_types() -> Result<()> {
+        let mut geojson = GeoJson(
+            r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {"pop": 10}, "geometry": null},
+            {"type": "Feature", "properties": {"pop": "n/a"}, "geometry": null},
+            {"type": "Feature", "properties": {"pop": 2.5}, "geometry": null},
+            {"type": "Feature", "properties": {"pop": true}, "geometry": null}
+            ]}"#,
+        );
+        let summary = geojson.summary()?;
+        let pop = summary.property("pop").unwrap();
+        assert_eq!(pop.column_type, Some(ColumnType::String));
+        assert_eq!(pop.min, Some(SummaryValue::String("10".to_string())));
+        assert_eq!(pop.max, Some(SummaryValue::String("true".to_string())));
+        assert!(format!("{summary:?}").contains("feature_count: 4"));
+        Ok(())
+    }
+
+    #[test]
+    fn column_types() {
+        assert_eq!(
+            merge_column_types(ColumnType::Int, ColumnType::UByte),
+            ColumnType::Long
+        );
+        assert_eq!(
+            merge_column_types(ColumnType::Long, ColumnType::Float),
+            ColumnType::Double
+        );
+        assert_eq!(
+            merge_column_types(ColumnType::Bool, ColumnType::Int),
+            ColumnType::String
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "with-serde")]
+    fn serialize() -> Result<()> {
+        let mut geojson = GeoJson(
+            r#"{"type": "Feature", "properties": {"id": 1}, "geometry": {"type": "Point", "coordinates": [1, 2]}}"#,
+        );
+        let summary = geojson.summary()?;
+        assert_eq!(
+            serde_json::to_value(&summary).unwrap(),
+            serde_json::json!({
+                "feature_count": 1,
+                "geometry_types": {"Point": 1},
+                "vertex_count": 1,
+                "dimensions": {"z": false, "m": false, "t": false, "tm": false},
+                "srids": [],
+                "extent": {"min_x": 1.0, "min_y": 2.0, "max_x": 1.0, "max_y": 2.0, "z": null, "m": null},
+                "properties": [{
+                    "name": "id",
+                    "column_type": "Long",
+                    "count": 1,
+                    "missing_count": 0,
+                    "min": 1.0,
+                    "max": 1.0
+                }]
+            })
+        );
+        Ok(())
+    }
+}
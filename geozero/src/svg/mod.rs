@@ -99,3 +99,39 @@ mod wkb {
         }
     }
 }
+
+pub(crate) mod driver {
+    use crate::error::{GeozeroError, Result};
+    use crate::svg::SvgWriter;
+    use crate::{Driver, DriverOptions, FeatureProcessor};
+    use std::io::Write;
+
+    pub(crate) const SVG: Driver = Driver {
+        name: "SVG",
+        extensions: &["svg"],
+        sniff: None,
+        open: None,
+        create: Some(create),
+    };
+
+    fn create<'a>(
+        out: Box<dyn Write + 'a>,
+        options: &DriverOptions,
+    ) -> Result<Box<dyn FeatureProcessor + 'a>> {
+        // The extent is written into the SVG header before the first feature
+        let extent = options
+            .extent
+            .ok_or_else(|| GeozeroError::Dataset("SVG output requires an extent".to_string()))?;
+        let mut writer = SvgWriter::new(out, true);
+        // TODO: get image size as opts
+        writer.set_dimensions(
+            extent.min_x,
+            extent.min_y,
+            extent.max_x,
+            extent.max_y,
+            800,
+            600,
+        );
+        Ok(Box::new(writer))
+    }
+}
@@ -0,0 +1,184 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+
+/// Coordinate transformation applied by [`TransformProcessor`].
+///
+/// Implemented for closures `FnMut(f64, f64) -> (f64, f64)`.
+pub trait CoordTransform {
+    /// Transform x/y coordinates
+    fn transform_xy(&mut self, x: f64, y: f64) -> Result<(f64, f64)>;
+    /// Transform a coordinate with optional Z and M values
+    ///
+    /// The default implementation transforms x/y and keeps Z and M unchanged.
+    fn transform_coord(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+    ) -> Result<(f64, f64, Option<f64>, Option<f64>)> {
+        let (x, y) = self.transform_xy(x, y)?;
+        Ok((x, y, z, m))
+    }
+}
+
+impl<F: FnMut(f64, f64) -> (f64, f64)> CoordTransform for F {
+    fn transform_xy(&mut self, x: f64, y: f64) -> Result<(f64, f64)> {
+        Ok(self(x, y))
+    }
+}
+
+impl CoordTransform for Box<dyn CoordTransform> {
+    fn transform_xy(&mut self, x: f64, y: f64) -> Result<(f64, f64)> {
+        self.as_mut().transform_xy(x, y)
+    }
+    fn transform_coord(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+    ) -> Result<(f64, f64, Option<f64>, Option<f64>)> {
+        self.as_mut().transform_coord(x, y, z, m)
+    }
+}
+
+/// Processor applying a coordinate transformation before passing all events to a wrapped processor.
+///
+/// # Usage example:
+///
+/// Swap axis order while converting to WKT:
+///
+/// ```
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::{GeozeroGeometry, TransformProcessor};
+/// use geozero::wkt::{Wkt, WktWriter};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = TransformProcessor::new(WktWriter::new(&mut out), |x, y| (y, x));
+/// Wkt("LINESTRING(1 2,3 4)").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "LINESTRING(2 1,4 3)");
+/// # }
+/// ```
+pub struct TransformProcessor<P, F: CoordTransform> {
+    processor: P,
+    transform: F,
+}
+
+impl<P, F: CoordTransform> TransformProcessor<P, F> {
+    pub fn new(processor: P, transform: F) -> Self {
+        TransformProcessor {
+            processor,
+            transform,
+        }
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+}
+
+impl<P: FeatureProcessor, F: CoordTransform> FeatureProcessor for TransformProcessor<P, F> {
+    forward_feature_processor!(processor);
+}
+
+impl<P: GeomProcessor, F: CoordTransform> GeomProcessor for TransformProcessor<P, F> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        let (x, y) = self.transform.transform_xy(x, y)?;
+        self.processor.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        let (x, y, z, m) = self.transform.transform_coord(x, y, z, m)?;
+        self.processor.coordinate(x, y, z, m, t, tm, idx)
+    }
+    forward_geom_processor!(
+        processor;
+        dimensions, multi_dim, srid, empty_point, point_begin, point_end, multipoint_begin,
+        multipoint_end, linestring_begin, linestring_end, multilinestring_begin,
+        multilinestring_end, polygon_begin, polygon_end, multipolygon_begin, multipolygon_end,
+        geometrycollection_begin, geometrycollection_end, circularstring_begin, circularstring_end,
+        compoundcurve_begin, compoundcurve_end, curvepolygon_begin, curvepolygon_end,
+        multicurve_begin, multicurve_end, multisurface_begin, multisurface_end, triangle_begin,
+        triangle_end, polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+impl<P: PropertyProcessor, F: CoordTransform> PropertyProcessor for TransformProcessor<P, F> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{Wkt, WktWriter};
+    use crate::{CoordDimensions, GeozeroGeometry};
+
+    struct Feet2Meter;
+
+    impl CoordTransform for Feet2Meter {
+        fn transform_xy(&mut self, x: f64, y: f64) -> Result<(f64, f64)> {
+            Ok((x, y))
+        }
+        fn transform_coord(
+            &mut self,
+            x: f64,
+            y: f64,
+            z: Option<f64>,
+            m: Option<f64>,
+        ) -> Result<(f64, f64, Option<f64>, Option<f64>)> {
+            Ok((x, y, z.map(|z| z * 0.3048), m))
+        }
+    }
+
+    fn transform_wkt<F: CoordTransform>(wkt: &str, transform: F) -> Result<String> {
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = TransformProcessor::new(WktWriter::new(&mut out), transform);
+        Wkt(wkt).process_geom(&mut processor)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn closure() -> Result<()> {
+        let wkt = transform_wkt(
+            "POLYGON((0 0,0 3,3 3,3 0,0 0),(0.2 0.2,0.2 2,2 2,2 0.2,0.2 0.2))",
+            |x, y| (x * 10.0, y + 1.0),
+        )?;
+        assert_eq!(
+            wkt,
+            "POLYGON((0 1,0 4,30 4,30 1,0 1),(2 1.2,2 3,20 3,20 1.2,2 1.2))"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn trait_object() -> Result<()> {
+        let transform: Box<dyn CoordTransform> = Box::new(Feet2Meter);
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = TransformProcessor::new(
+            WktWriter::with_dims(&mut out, CoordDimensions::xyz()),
+            transform,
+        );
+        processor.point_begin(0)?;
+        processor.coordinate(1.0, 2.0, Some(10.0), None, None, None, 0)?;
+        processor.point_end(0)?;
+        assert_eq!(std::str::from_utf8(&out).unwrap(), "POINT(1 2 3.048)");
+        Ok(())
+    }
+}
@@ -0,0 +1,583 @@
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::{GeomProcessor, GeometryType};
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+use std::fmt;
+use thiserror::Error;
+
+/// Geometry problem detected by [`ValidationProcessor`].
+#[derive(Error, Clone, PartialEq, Debug)]
+pub enum ValidationError {
+    #[error("ring is not closed")]
+    UnclosedRing,
+    #[error("ring with {0} coordinates, at least 4 required")]
+    TooFewRingCoordinates(usize),
+    #[error("linestring with {0} coordinate, at least 2 required")]
+    TooFewLineCoordinates(usize),
+    #[error("NaN or infinite coordinate value")]
+    NonFiniteCoordinate,
+    #[error("invalid nesting: {0}")]
+    InvalidNesting(String),
+    #[error("size {expected} announced, but {actual} parts emitted")]
+    SizeMismatch { expected: usize, actual: usize },
+}
+
+/// Geometry problem with its location in the processed stream.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ValidationIssue {
+    /// Index of the feature, `None` for geometries processed outside of features
+    pub feature: Option<u64>,
+    /// Type of the geometry or part containing the problem
+    pub geometry_type: GeometryType,
+    /// Part indices from the outermost geometry, e.g. `[0, 2]` for the third ring of the first
+    /// polygon in a MultiPolygon. Coordinate problems include the coordinate index.
+    pub path: Vec<usize>,
+    pub error: ValidationError,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(idx) = self.feature {
+            write!(f, "feature {idx}, ")?;
+        }
+        write!(
+            f,
+            "{:?} {:?}: {}",
+            self.geometry_type, self.path, self.error
+        )
+    }
+}
+
+/// Open geometry or geometry part
+struct Part {
+    geometry_type: GeometryType,
+    size: usize,
+    /// Number of emitted children (coordinates or parts)
+    children: usize,
+    /// LineString being a polygon ring
+    ring: bool,
+    first: Option<(f64, f64)>,
+    last: Option<(f64, f64)>,
+}
+
+/// Processor checking geometries while passing all events to a wrapped processor.
+///
+/// Detected problems are collected as [`ValidationIssue`] with feature index and part path:
+/// unclosed rings, rings with less than 4 coordinates, linestrings with a single coordinate,
+/// NaN or infinite coordinate values, mismatched begin/end events and announced sizes not
+/// matching the number of emitted parts. Empty linestrings are valid.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::{GeozeroGeometry, ProcessorSink, ValidationError, ValidationProcessor};
+/// use geozero::wkt::Wkt;
+///
+/// let mut processor = ValidationProcessor::new(ProcessorSink);
+/// Wkt("POLYGON((0 0,10 0,10 10,0 0),(1 1,2 1,2 2))")
+///     .process_geom(&mut processor)
+///     .unwrap();
+/// let issue = &processor.issues()[0];
+/// assert_eq!(issue.path, vec![1]);
+/// assert_eq!(issue.error, ValidationError::TooFewRingCoordinates(3));
+/// assert_eq!(issue.to_string(), "LineString [1]: ring with 3 coordinates, at least 4 required");
+/// # }
+/// ```
+pub struct ValidationProcessor<P> {
+    processor: P,
+    feature: Option<u64>,
+    parts: Vec<Part>,
+    issues: Vec<ValidationIssue>,
+}
+
+impl<P> ValidationProcessor<P> {
+    pub fn new(processor: P) -> Self {
+        ValidationProcessor {
+            processor,
+            feature: None,
+            parts: Vec::new(),
+            issues: Vec::new(),
+        }
+    }
+
+    /// Problems found so far
+    pub fn issues(&self) -> &[ValidationIssue] {
+        &self.issues
+    }
+
+    /// Return and reset problems found so far
+    pub fn take_issues(&mut self) -> Vec<ValidationIssue> {
+        std::mem::take(&mut self.issues)
+    }
+
+    /// `true` if no problems were found
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    /// Part indices of the first `depth` open parts
+    fn path(&self, depth: usize) -> Vec<usize> {
+        self.parts[..depth]
+            .iter()
+            .map(|part| part.children.saturating_sub(1))
+            .collect()
+    }
+
+    fn report(&mut self, geometry_type: GeometryType, path: Vec<usize>, error: ValidationError) {
+        self.issues.push(ValidationIssue {
+            feature: self.feature,
+            geometry_type,
+            path,
+            error,
+        });
+    }
+
+    fn coord(&mut self, x: f64, y: f64, zm: [Option<f64>; 2]) {
+        let finite = x.is_finite() && y.is_finite() && zm.iter().flatten().all(|v| v.is_finite());
+        let Some(part) = self.parts.last_mut() else {
+            if !finite {
+                self.report(
+                    GeometryType::Unknown,
+                    Vec::new(),
+                    ValidationError::NonFiniteCoordinate,
+                );
+            }
+            return;
+        };
+        part.children += 1;
+        if part.first.is_none() {
+            part.first = Some((x, y));
+        }
+        part.last = Some((x, y));
+        if !finite {
+            let geometry_type = part.geometry_type;
+            let path = self.path(self.parts.len());
+            self.report(geometry_type, path, ValidationError::NonFiniteCoordinate);
+        }
+    }
+
+    fn begin(&mut self, geometry_type: GeometryType, size: usize) {
+        let mut ring = false;
+        if let Some(parent) = self.parts.last_mut() {
+            parent.children += 1;
+            ring = geometry_type == GeometryType::LineString
+                && matches!(
+                    parent.geometry_type,
+                    GeometryType::Polygon | GeometryType::Triangle | GeometryType::CurvePolygon
+                );
+            if matches!(
+                parent.geometry_type,
+                GeometryType::Point | GeometryType::MultiPoint | GeometryType::LineString
+            ) {
+                let parent_type = parent.geometry_type;
+                let path = self.path(self.parts.len() - 1);
+                self.report(
+                    parent_type,
+                    path,
+                    ValidationError::InvalidNesting(format!(
+                        "{geometry_type:?} inside {parent_type:?}"
+                    )),
+                );
+            }
+        }
+        self.parts.push(Part {
+            geometry_type,
+            size,
+            children: 0,
+            ring,
+            first: None,
+            last: None,
+        });
+    }
+
+    fn end(&mut self, geometry_type: GeometryType) {
+        let path = self.path(self.parts.len().saturating_sub(1));
+        let Some(part) = self.parts.pop() else {
+            self.report(
+                geometry_type,
+                path,
+                ValidationError::InvalidNesting(format!("{geometry_type:?} ended without begin")),
+            );
+            return;
+        };
+        if part.geometry_type != geometry_type {
+            self.report(
+                part.geometry_type,
+                path.clone(),
+                ValidationError::InvalidNesting(format!(
+                    "{:?} ended by {geometry_type:?} end",
+                    part.geometry_type
+                )),
+            );
+        }
+        if part.size != part.children {
+            self.report(
+                part.geometry_type,
+                path.clone(),
+                ValidationError::SizeMismatch {
+                    expected: part.size,
+                    actual: part.children,
+                },
+            );
+        }
+        if part.ring {
+            if part.children < 4 {
+                self.report(
+                    part.geometry_type,
+                    path.clone(),
+                    ValidationError::TooFewRingCoordinates(part.children),
+                );
+            }
+            if part.children > 1 && part.first != part.last {
+                self.report(part.geometry_type, path, ValidationError::UnclosedRing);
+            }
+        } else if part.geometry_type == GeometryType::LineString && part.children == 1 {
+            self.report(
+                part.geometry_type,
+                path,
+                ValidationError::TooFewLineCoordinates(part.children),
+            );
+        }
+    }
+
+    /// Report and discard geometries not ended
+    fn end_all(&mut self) {
+        while let Some(part) = self.parts.last() {
+            let geometry_type = part.geometry_type;
+            let path = self.path(self.parts.len() - 1);
+            self.report(
+                geometry_type,
+                path,
+                ValidationError::InvalidNesting(format!("{geometry_type:?} not ended")),
+            );
+            self.parts.pop();
+        }
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for ValidationProcessor<P> {
+    fn dataset_end(&mut self) -> Result<()> {
+        self.end_all();
+        self.processor.dataset_end()
+    }
+    fn feature_begin(&mut self, idx: u64) -> Result<()> {
+        self.feature = Some(idx);
+        self.processor.feature_begin(idx)
+    }
+    fn feature_end(&mut self, idx: u64) -> Result<()> {
+        self.end_all();
+        self.feature = None;
+        self.processor.feature_end(idx)
+    }
+    fn geometry_end(&mut self) -> Result<()> {
+        self.end_all();
+        self.processor.geometry_end()
+    }
+    forward_feature_processor!(
+        processor;
+        dataset_begin, schema, bytes_read, done, properties_begin, properties_end, geometry_begin,
+    );
+}
+
+impl<P: GeomProcessor> GeomProcessor for ValidationProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        self.coord(x, y, [None, None]);
+        self.processor.xy(x, y, idx)
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        self.coord(x, y, [z, m]);
+        self.processor.coordinate(x, y, z, m, t, tm, idx)
+    }
+    fn empty_point(&mut self, idx: usize) -> Result<()> {
+        if let Some(part) = self.parts.last_mut() {
+            part.children += 1;
+        }
+        self.processor.empty_point(idx)
+    }
+    fn point_begin(&mut self, idx: usize) -> Result<()> {
+        self.begin(GeometryType::Point, 1);
+        self.processor.point_begin(idx)
+    }
+    fn point_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::Point);
+        self.processor.point_end(idx)
+    }
+    fn multipoint_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiPoint, size);
+        self.processor.multipoint_begin(size, idx)
+    }
+    fn multipoint_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiPoint);
+        self.processor.multipoint_end(idx)
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::LineString, size);
+        self.processor.linestring_begin(tagged, size, idx)
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end(GeometryType::LineString);
+        self.processor.linestring_end(tagged, idx)
+    }
+    fn multilinestring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiLineString, size);
+        self.processor.multilinestring_begin(size, idx)
+    }
+    fn multilinestring_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiLineString);
+        self.processor.multilinestring_end(idx)
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::Polygon, size);
+        self.processor.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end(GeometryType::Polygon);
+        self.processor.polygon_end(tagged, idx)
+    }
+    fn multipolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiPolygon, size);
+        self.processor.multipolygon_begin(size, idx)
+    }
+    fn multipolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiPolygon);
+        self.processor.multipolygon_end(idx)
+    }
+    fn geometrycollection_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::GeometryCollection, size);
+        self.processor.geometrycollection_begin(size, idx)
+    }
+    fn geometrycollection_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::GeometryCollection);
+        self.processor.geometrycollection_end(idx)
+    }
+    fn circularstring_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::CircularString, size);
+        self.processor.circularstring_begin(size, idx)
+    }
+    fn circularstring_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::CircularString);
+        self.processor.circularstring_end(idx)
+    }
+    fn compoundcurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::CompoundCurve, size);
+        self.processor.compoundcurve_begin(size, idx)
+    }
+    fn compoundcurve_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::CompoundCurve);
+        self.processor.compoundcurve_end(idx)
+    }
+    fn curvepolygon_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::CurvePolygon, size);
+        self.processor.curvepolygon_begin(size, idx)
+    }
+    fn curvepolygon_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::CurvePolygon);
+        self.processor.curvepolygon_end(idx)
+    }
+    fn multicurve_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiCurve, size);
+        self.processor.multicurve_begin(size, idx)
+    }
+    fn multicurve_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiCurve);
+        self.processor.multicurve_end(idx)
+    }
+    fn multisurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::MultiSurface, size);
+        self.processor.multisurface_begin(size, idx)
+    }
+    fn multisurface_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::MultiSurface);
+        self.processor.multisurface_end(idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::Triangle, size);
+        self.processor.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.end(GeometryType::Triangle);
+        self.processor.triangle_end(tagged, idx)
+    }
+    fn polyhedralsurface_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::PolyhedralSurface, size);
+        self.processor.polyhedralsurface_begin(size, idx)
+    }
+    fn polyhedralsurface_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::PolyhedralSurface);
+        self.processor.polyhedralsurface_end(idx)
+    }
+    fn tin_begin(&mut self, size: usize, idx: usize) -> Result<()> {
+        self.begin(GeometryType::Tin, size);
+        self.processor.tin_begin(size, idx)
+    }
+    fn tin_end(&mut self, idx: usize) -> Result<()> {
+        self.end(GeometryType::Tin);
+        self.processor.tin_end(idx)
+    }
+    forward_geom_processor!(processor; dimensions, multi_dim, srid);
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for ValidationProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ProcessorSink;
+
+    fn errors(
+        processor: &ValidationProcessor<ProcessorSink>,
+    ) -> Vec<(Vec<usize>, ValidationError)> {
+        processor
+            .issues()
+            .iter()
+            .map(|issue| (issue.path.clone(), issue.error.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn line_coordinates() -> Result<()> {
+        let mut processor = ValidationProcessor::new(ProcessorSink);
+        processor.multilinestring_begin(3, 0)?;
+        processor.linestring_begin(false, 2, 0)?;
+        processor.xy(0.0, 0.0, 0)?;
+        processor.xy(f64::NAN, 1.0, 1)?;
+        processor.linestring_end(false, 0)?;
+        processor.linestring_begin(false, 1, 1)?;
+        processor.coordinate(0.0, 0.0, Some(f64::INFINITY), None, None, None, 0)?;
+        processor.linestring_end(false, 1)?;
+        processor.linestring_begin(false, 0, 2)?;
+        processor.linestring_end(false, 2)?;
+        processor.multilinestring_end(0)?;
+        assert_eq!(
+            errors(&processor),
+            vec![
+                (vec![0, 1], ValidationError::NonFiniteCoordinate),
+                (vec![1, 0], ValidationError::NonFiniteCoordinate),
+                (vec![1], ValidationError::TooFewLineCoordinates(1)),
+            ]
+        );
+        assert_eq!(
+            processor.issues()[0].to_string(),
+            "LineString [0, 1]: NaN or infinite coordinate value"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn nesting() -> Result<()> {
+        let mut processor = ValidationProcessor::new(ProcessorSink);
+        processor.geometry_begin()?;
+        processor.polygon_begin(true, 2, 0)?;
+        processor.linestring_begin(false, 4, 0)?;
+        for (x, y) in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)] {
+            processor.xy(x, y, 0)?;
+        }
+        processor.polygon_end(true, 0)?;
+        processor.geometry_end()?;
+        assert_eq!(
+            errors(&processor),
+            vec![
+                (
+                    vec![0],
+                    ValidationError::InvalidNesting("LineString ended by Polygon end".to_string())
+                ),
+                (
+                    vec![],
+                    ValidationError::InvalidNesting("Polygon not ended".to_string())
+                ),
+            ]
+        );
+        processor.take_issues();
+
+        processor.point_end(0)?;
+        assert_eq!(
+            errors(&processor),
+            vec![(
+                vec![],
+                ValidationError::InvalidNesting("Point ended without begin".to_string())
+            )]
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "with-wkt")]
+    fn rings() -> Result<()> {
+        use crate::wkt::{Wkt, WktWriter};
+        use crate::GeozeroGeometry;
+
+        let wkt = "MULTIPOLYGON(((0 0,1 0,1 1,0 0)),((0 0,1 0,1 1,0 1)),((0 0,1 0,0 0)))";
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = ValidationProcessor::new(WktWriter::new(&mut out));
+        Wkt(wkt).process_geom(&mut processor)?;
+        let issues: Vec<_> = processor
+            .issues()
+            .iter()
+            .map(|issue| (issue.path.clone(), issue.error.clone()))
+            .collect();
+        assert_eq!(
+            issues,
+            vec![
+                (vec![1, 0], ValidationError::UnclosedRing),
+                (vec![2, 0], ValidationError::TooFewRingCoordinates(3)),
+            ]
+        );
+        assert!(!processor.is_valid());
+        // Events are passed through unchanged
+        assert_eq!(std::str::from_utf8(&out).unwrap(), wkt);
+
+        let mut processor = ValidationProcessor::new(ProcessorSink);
+        Wkt("POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,2 1,2 2,1 1))").process_geom(&mut processor)?;
+        assert!(processor.is_valid());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "with-geojson")]
+    fn features() -> Result<()> {
+        use crate::geojson::GeoJson;
+        use crate::GeozeroDatasource;
+
+        let geojson = r#"{"type": "FeatureCollection", "features": [
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]}},
+            {"type": "Feature", "properties": {}, "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]], [[0, 0], [1, 0], [1, 1], [0, 1]]]}}
+        ]}"#;
+        let mut processor = ValidationProcessor::new(ProcessorSink);
+        GeoJson(geojson).process(&mut processor)?;
+        assert_eq!(
+            processor.issues(),
+            &[ValidationIssue {
+                feature: Some(1),
+                geometry_type: GeometryType::LineString,
+                path: vec![1],
+                error: ValidationError::UnclosedRing,
+            }]
+        );
+        assert_eq!(
+            processor.issues()[0].to_string(),
+            "feature 1, LineString [1]: ring is not closed"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use crate::coord_buffer::{process_linestring, Coord};
+use crate::error::Result;
+use crate::feature_processor::FeatureProcessor;
+use crate::forward::{forward_feature_processor, forward_geom_processor};
+use crate::geometry_processor::GeomProcessor;
+use crate::property_processor::{ColumnValue, PropertyProcessor};
+
+/// Ring orientation applied by [`WindingProcessor`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WindingOrder {
+    /// Exterior rings counter-clockwise, interior rings clockwise (RFC 7946 GeoJSON)
+    Rfc7946,
+    /// Exterior rings clockwise in tile coordinates with the y axis pointing down (MVT)
+    ///
+    /// The exterior ring has a positive area as calculated by the surveyor's formula. Use
+    /// [`WindingOrder::Shapefile`] for coordinates with the y axis pointing up, like input of a
+    /// scaling `MvtWriter`.
+    Mvt,
+    /// Exterior rings clockwise, interior rings counter-clockwise (Shapefile)
+    Shapefile,
+}
+
+impl WindingOrder {
+    /// Required sign of the signed ring area
+    fn positive_area(&self, exterior: bool) -> bool {
+        match self {
+            WindingOrder::Rfc7946 | WindingOrder::Mvt => exterior,
+            WindingOrder::Shapefile => !exterior,
+        }
+    }
+}
+
+/// Twice the signed area of a ring, positive for counter-clockwise rings (y axis pointing up).
+pub fn signed_ring_area(coords: impl IntoIterator<Item = (f64, f64)>) -> f64 {
+    let mut coords = coords.into_iter();
+    let Some(first) = coords.next() else {
+        return 0.0;
+    };
+    let mut area = 0.0;
+    let mut prev = first;
+    for (x, y) in coords.chain(std::iter::once(first)) {
+        area += prev.0 * y - x * prev.1;
+        prev = (x, y);
+    }
+    area
+}
+
+/// Processor emitting polygon rings with a given orientation to a wrapped processor.
+///
+/// Each polygon and triangle ring is buffered and reversed, if its orientation doesn't match
+/// the [`WindingOrder`]. The first ring of a polygon is the exterior ring. Other events are
+/// passed through unchanged.
+///
+/// # Usage example:
+///
+/// ```
+/// # #[cfg(feature = "with-wkt")]
+/// # {
+/// use geozero::{GeozeroGeometry, WindingOrder, WindingProcessor};
+/// use geozero::wkt::{Wkt, WktWriter};
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// let mut processor = WindingProcessor::new(WktWriter::new(&mut out), WindingOrder::Rfc7946);
+/// Wkt("POLYGON((0 0,0 1,1 1,1 0,0 0))").process_geom(&mut processor).unwrap();
+/// assert_eq!(std::str::from_utf8(&out).unwrap(), "POLYGON((0 0,1 0,1 1,0 1,0 0))");
+/// # }
+/// ```
+pub struct WindingProcessor<P> {
+    processor: P,
+    order: WindingOrder,
+    /// Number of rings of the current polygon
+    ring_count: Option<usize>,
+    ring: Option<Vec<Coord>>,
+}
+
+impl<P: GeomProcessor> WindingProcessor<P> {
+    pub fn new(processor: P, order: WindingOrder) -> Self {
+        WindingProcessor {
+            processor,
+            order,
+            ring_count: None,
+            ring: None,
+        }
+    }
+
+    /// Return the wrapped processor
+    pub fn into_inner(self) -> P {
+        self.processor
+    }
+
+    fn emit_ring(&mut self, mut ring: Vec<Coord>, idx: usize) -> Result<()> {
+        let exterior = self.ring_count == Some(0);
+        if let Some(count) = &mut self.ring_count {
+            *count += 1;
+        }
+        let area = signed_ring_area(ring.iter().map(Coord::xy));
+        if area != 0.0 && (area > 0.0) != self.order.positive_area(exterior) {
+            ring.reverse();
+        }
+        process_linestring(&mut self.processor, &ring, false, idx)
+    }
+}
+
+impl<P: FeatureProcessor> FeatureProcessor for WindingProcessor<P> {
+    forward_feature_processor!(processor);
+}
+
+impl<P: GeomProcessor> GeomProcessor for WindingProcessor<P> {
+    fn xy(&mut self, x: f64, y: f64, idx: usize) -> Result<()> {
+        if let Some(ring) = &mut self.ring {
+            ring.push(Coord::Xy(x, y));
+            Ok(())
+        } else {
+            self.processor.xy(x, y, idx)
+        }
+    }
+    fn coordinate(
+        &mut self,
+        x: f64,
+        y: f64,
+        z: Option<f64>,
+        m: Option<f64>,
+        t: Option<f64>,
+        tm: Option<u64>,
+        idx: usize,
+    ) -> Result<()> {
+        if let Some(ring) = &mut self.ring {
+            ring.push(Coord::Coordinate { x, y, z, m, t, tm });
+            Ok(())
+        } else {
+            self.processor.coordinate(x, y, z, m, t, tm, idx)
+        }
+    }
+    fn linestring_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        if self.ring_count.is_some() && !tagged {
+            self.ring = Some(Vec::with_capacity(size));
+            Ok(())
+        } else {
+            self.processor.linestring_begin(tagged, size, idx)
+        }
+    }
+    fn linestring_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        if let Some(ring) = self.ring.take() {
+            self.emit_ring(ring, idx)
+        } else {
+            self.processor.linestring_end(tagged, idx)
+        }
+    }
+    fn polygon_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.ring_count = Some(0);
+        self.processor.polygon_begin(tagged, size, idx)
+    }
+    fn polygon_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.ring_count = None;
+        self.processor.polygon_end(tagged, idx)
+    }
+    fn triangle_begin(&mut self, tagged: bool, size: usize, idx: usize) -> Result<()> {
+        self.ring_count = Some(0);
+        self.processor.triangle_begin(tagged, size, idx)
+    }
+    fn triangle_end(&mut self, tagged: bool, idx: usize) -> Result<()> {
+        self.ring_count = None;
+        self.processor.triangle_end(tagged, idx)
+    }
+    forward_geom_processor!(
+        processor;
+        dimensions, multi_dim, srid, empty_point, point_begin, point_end, multipoint_begin,
+        multipoint_end, multilinestring_begin, multilinestring_end, multipolygon_begin,
+        multipolygon_end, geometrycollection_begin, geometrycollection_end, circularstring_begin,
+        circularstring_end, compoundcurve_begin, compoundcurve_end, curvepolygon_begin,
+        curvepolygon_end, multicurve_begin, multicurve_end, multisurface_begin, multisurface_end,
+        polyhedralsurface_begin, polyhedralsurface_end, tin_begin, tin_end,
+    );
+}
+
+impl<P: PropertyProcessor> PropertyProcessor for WindingProcessor<P> {
+    fn property(&mut self, idx: usize, name: &str, value: &ColumnValue) -> Result<bool> {
+        self.processor.property(idx, name, value)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "with-wkt")]
+mod test {
+    use super::*;
+    use crate::wkt::{Wkt, WktWriter};
+    use crate::GeozeroGeometry;
+
+    fn oriented(wkt: &str, order: WindingOrder) -> Result<String> {
+        let mut out: Vec<u8> = Vec::new();
+        let mut processor = WindingProcessor::new(WktWriter::new(&mut out), order);
+        Wkt(wkt).process_geom(&mut processor)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn area() {
+        assert_eq!(
+            signed_ring_area([(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (0.0, 0.0)]),
+            2.0
+        );
+        assert_eq!(signed_ring_area([(0.0, 0.0), (0.0, 1.0), (1.0, 1.0)]), -1.0);
+        assert_eq!(signed_ring_area([]), 0.0);
+    }
+
+    #[test]
+    fn polygon_with_hole() -> Result<()> {
+        let ccw = "POLYGON((0 0,10 0,10 10,0 10,0 0),(1 1,1 2,2 2,2 1,1 1))";
+        let cw = "POLYGON((0 0,0 10,10 10,10 0,0 0),(1 1,2 1,2 2,1 2,1 1))";
+        assert_eq!(oriented(ccw, WindingOrder::Rfc7946)?, ccw);
+        assert_eq!(oriented(cw, WindingOrder::Rfc7946)?, ccw);
+        assert_eq!(oriented(ccw, WindingOrder::Mvt)?, ccw);
+        assert_eq!(oriented(ccw, WindingOrder::Shapefile)?, cw);
+        assert_eq!(oriented(cw, WindingOrder::Shapefile)?, cw);
+        Ok(())
+    }
+
+    #[test]
+    fn passthrough() -> Result<()> {
+        let wkt = "GEOMETRYCOLLECTION(LINESTRING(0 0,0 1,1 1,0 0),MULTIPOLYGON(((0 0,0 1,1 1,0 0)),((5 5,5 6,6 6,5 5))))";
+        assert_eq!(
+            oriented(wkt, WindingOrder::Rfc7946)?,
+            "GEOMETRYCOLLECTION(LINESTRING(0 0,0 1,1 1,0 0),MULTIPOLYGON(((0 0,1 1,0 1,0 0)),((5 5,6 6,5 6,5 5))))"
+        );
+        Ok(())
+    }
+}
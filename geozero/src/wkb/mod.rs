@@ -62,6 +62,14 @@ pub(crate) mod conversion {
         ) -> Result<Vec<u8>> {
             self.to_wkb_dialect(WkbDialect::Geopackage, dims, srid, envelope)
         }
+        /// Convert to GeoPackage WKB with an envelope computed from the geometry.
+        ///
+        /// The envelope includes Z and M ranges, if requested in `dims` and available.
+        fn to_gpkg_wkb_with_envelope(
+            &self,
+            dims: CoordDimensions,
+            srid: Option<i32>,
+        ) -> Result<Vec<u8>>;
         /// Convert to Spatialite WKB.
         fn to_spatialite_wkb(
             &self,
@@ -95,5 +103,40 @@ pub(crate) mod conversion {
             self.process_geom(&mut writer)?;
             Ok(wkb)
         }
+        fn to_gpkg_wkb_with_envelope(
+            &self,
+            dims: CoordDimensions,
+            srid: Option<i32>,
+        ) -> Result<Vec<u8>> {
+            let bounds = self.bbox()?;
+            let mut envelope = Vec::new();
+            let mut envelope_dims = CoordDimensions::xy();
+            if let Some(bounds) = &bounds {
+                // [minx, maxx, miny, maxy(, minz, maxz)(, minm, maxm)]
+                envelope.extend([bounds.min_x, bounds.max_x, bounds.min_y, bounds.max_y]);
+                if let Some((minz, maxz)) = bounds.z.filter(|_| dims.z) {
+                    envelope.extend([minz, maxz]);
+                    envelope_dims.z = true;
+                }
+                if let Some((minm, maxm)) = bounds.m.filter(|_| dims.m) {
+                    envelope.extend([minm, maxm]);
+                    envelope_dims.m = true;
+                }
+            }
+            let mut wkb: Vec<u8> = Vec::new();
+            let mut writer = WkbWriter::with_extended_opts(
+                &mut wkb,
+                WkbDialect::Geopackage,
+                dims,
+                dims,
+                srid,
+                envelope,
+                envelope_dims,
+                false,
+                bounds.is_none(),
+            );
+            self.process_geom(&mut writer)?;
+            Ok(wkb)
+        }
     }
 }
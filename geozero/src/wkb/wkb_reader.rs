@@ -1,6 +1,6 @@
-use crate::error::{GeozeroError, Result};
+use crate::error::{ErrorContext, GeozeroError, Result, ResultExt};
 use crate::wkb::{WKBGeometryType, WkbDialect};
-use crate::{GeomProcessor, GeozeroGeometry};
+use crate::{FeatureAccess, FeatureProperties, GeomProcessor, GeozeroGeometry, PropertyProcessor};
 use scroll::ctx::{FromCtx, SizeWith};
 use scroll::{Endian, IOread};
 use std::io::Read;
@@ -15,7 +15,7 @@ pub struct Wkb<B: AsRef<[u8]>>(pub B);
 
 impl<B: AsRef<[u8]>> GeozeroGeometry for Wkb<B> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
-        process_wkb_geom(&mut self.0.as_ref(), processor)
+        process_slice(self.0.as_ref(), |raw| process_wkb_geom(raw, processor))
     }
 }
 
@@ -30,7 +30,7 @@ pub struct Ewkb<B: AsRef<[u8]>>(pub B);
 
 impl<B: AsRef<[u8]>> GeozeroGeometry for Ewkb<B> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
-        process_ewkb_geom(&mut self.0.as_ref(), processor)
+        process_slice(self.0.as_ref(), |raw| process_ewkb_geom(raw, processor))
     }
 }
 
@@ -39,7 +39,7 @@ pub struct GpkgWkb<B: AsRef<[u8]>>(pub B);
 
 impl<B: AsRef<[u8]>> GeozeroGeometry for GpkgWkb<B> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
-        process_gpkg_geom(&mut self.0.as_ref(), processor)
+        process_slice(self.0.as_ref(), |raw| process_gpkg_geom(raw, processor))
     }
 }
 
@@ -48,7 +48,9 @@ pub struct SpatiaLiteWkb<B: AsRef<[u8]>>(pub B);
 
 impl<B: AsRef<[u8]>> GeozeroGeometry for SpatiaLiteWkb<B> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
-        process_spatialite_geom(&mut self.0.as_ref(), processor)
+        process_slice(self.0.as_ref(), |raw| {
+            process_spatialite_geom(raw, processor)
+        })
     }
 }
 
@@ -57,10 +59,40 @@ pub struct MySQLWkb<B: AsRef<[u8]>>(pub B);
 
 impl<B: AsRef<[u8]>> GeozeroGeometry for MySQLWkb<B> {
     fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
-        process_mysql_geom(&mut self.0.as_ref(), processor)
+        process_slice(self.0.as_ref(), |raw| process_mysql_geom(raw, processor))
     }
 }
 
+macro_rules! impl_wkb_feature_access {
+    ( $t:ident ) => {
+        /// WKB geometries are features without properties, e.g. for processing WKB rows
+        /// as [`GeozeroDatasource`](crate::GeozeroDatasource).
+        impl<B: AsRef<[u8]>> FeatureProperties for $t<B> {
+            fn process_properties<P: PropertyProcessor>(&self, _processor: &mut P) -> Result<bool> {
+                Ok(false)
+            }
+        }
+
+        impl<B: AsRef<[u8]>> FeatureAccess for $t<B> {}
+    };
+}
+
+impl_wkb_feature_access!(Wkb);
+impl_wkb_feature_access!(Ewkb);
+impl_wkb_feature_access!(GpkgWkb);
+impl_wkb_feature_access!(SpatiaLiteWkb);
+impl_wkb_feature_access!(MySQLWkb);
+
+/// Process a WKB buffer, adding the byte offset to errors.
+fn process_slice<F>(buf: &[u8], process: F) -> Result<()>
+where
+    F: FnOnce(&mut &[u8]) -> Result<()>,
+{
+    let mut raw = buf;
+    process(&mut raw)
+        .with_context(|| ErrorContext::new().with_byte_offset((buf.len() - raw.len()) as u64))
+}
+
 /// Process WKB geometry.
 pub fn process_wkb_geom<R: Read, P: GeomProcessor>(raw: &mut R, processor: &mut P) -> Result<()> {
     let info = read_wkb_header(raw)?;
@@ -1002,6 +1034,18 @@ mod test {
         assert_eq!(err.to_string(), "I/O error `failed to fill whole buffer`");
     }
 
+    #[test]
+    fn error_context() {
+        // LINESTRING(1 2,3 <missing>)
+        let wkb = Wkb(hex::decode(
+            "010200000002000000000000000000f03f00000000000000400000000000000840",
+        )
+        .unwrap());
+        let err = wkb.to_wkt().unwrap_err();
+        assert_eq!(err.context().unwrap().byte_offset(), Some(33));
+        assert!(matches!(err.without_context(), GeozeroError::IoError(_)));
+    }
+
     #[test]
     fn conversions() {
         let wkb = Ewkb(hex::decode("0101000000000000000000244000000000000034C0").unwrap());
@@ -599,6 +599,78 @@ mod test {
                   "47500003e6100000000000000000f03f0000000000003640000000000000084000000000000036400107000000020000000101000000000000000000f03f00000000000008400103000000010000000400000000000000000035400000000000003540000000000000364000000000000035400000000000003540000000000000364000000000000035400000000000003540");
     }
 
+    /// Envelope indicator, empty flag and envelope values of a GeoPackage WKB header
+    fn gpkg_header(wkb: &[u8]) -> (u8, bool, Vec<f64>) {
+        let flags = wkb[3];
+        let indicator = (flags >> 1) & 0b111;
+        let len = [0, 4, 6, 6, 8][indicator as usize];
+        let envelope = wkb[8..8 + len * 8]
+            .chunks(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+            .collect();
+        (indicator, flags & 0b0001_0000 != 0, envelope)
+    }
+
+    #[test]
+    fn gpkg_computed_envelope() {
+        use crate::wkb::{self, GpkgWkb};
+        use crate::ToWkt;
+
+        // SELECT 'POLYGON Z((0 0 5,4 0 6,4 3 -1,0 0 5))'::geometry
+        let geom = wkb::Ewkb(hex::decode("0103000080010000000400000000000000000000000000000000000000000000000000144000000000000010400000000000000000000000000000184000000000000010400000000000000840000000000000F0BF000000000000000000000000000000000000000000001440").unwrap());
+        let wkb = geom.to_gpkg_wkb_with_envelope(DIM_XYZ, Some(4326)).unwrap();
+        assert_eq!(
+            gpkg_header(&wkb),
+            (2, false, vec![0.0, 4.0, 0.0, 3.0, -1.0, 6.0])
+        );
+        assert_eq!(
+            GpkgWkb(wkb).to_wkt_ndim(DIM_XYZ).unwrap(),
+            "POLYGON((0 0 5,4 0 6,4 3 -1,0 0 5))"
+        );
+        // Z range is omitted, if not requested
+        let wkb = geom.to_gpkg_wkb_with_envelope(DIM_XY, None).unwrap();
+        assert_eq!(gpkg_header(&wkb), (1, false, vec![0.0, 4.0, 0.0, 3.0]));
+
+        // SELECT 'LINESTRING M(1 2 10,3 4 20)'::geometry
+        let geom = wkb::Ewkb(hex::decode("010200004002000000000000000000F03F00000000000000400000000000002440000000000000084000000000000010400000000000003440").unwrap());
+        let dims = CoordDimensions {
+            m: true,
+            ..Default::default()
+        };
+        let wkb = geom.to_gpkg_wkb_with_envelope(dims, None).unwrap();
+        assert_eq!(
+            gpkg_header(&wkb),
+            (3, false, vec![1.0, 3.0, 2.0, 4.0, 10.0, 20.0])
+        );
+
+        // SELECT 'POINT ZM(1 2 3 4)'::geometry
+        let geom = wkb::Ewkb(
+            hex::decode(
+                "01010000C0000000000000F03F000000000000004000000000000008400000000000001040",
+            )
+            .unwrap(),
+        );
+        let wkb = geom.to_gpkg_wkb_with_envelope(DIM_XYZM, None).unwrap();
+        assert_eq!(
+            gpkg_header(&wkb),
+            (4, false, vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0])
+        );
+
+        let geom = crate::wkt::Wkt("MULTIPOLYGON(((0 0,2 0,2 2,0 0)),((10 -5,12 -5,12 1,10 -5)))");
+        let wkb = geom.to_gpkg_wkb_with_envelope(DIM_XY, None).unwrap();
+        assert_eq!(gpkg_header(&wkb), (1, false, vec![0.0, 12.0, -5.0, 2.0]));
+        assert_eq!(
+            GpkgWkb(wkb).to_wkt().unwrap(),
+            "MULTIPOLYGON(((0 0,2 0,2 2,0 0)),((10 -5,12 -5,12 1,10 -5)))"
+        );
+
+        // SELECT 'LINESTRING EMPTY'::geometry
+        let geom = wkb::Ewkb(hex::decode("010200000000000000").unwrap());
+        let wkb = geom.to_gpkg_wkb_with_envelope(DIM_XYZ, None).unwrap();
+        assert_eq!(gpkg_header(&wkb), (0, true, vec![]));
+        assert_eq!(GpkgWkb(wkb).to_wkt().unwrap(), "LINESTRING EMPTY");
+    }
+
     #[test]
     fn spatialite_geometries() {
         // SELECT HEX(ST_GeomFromText('POINT(1.1 1.1)', 4326));
@@ -666,6 +738,11 @@ mod test {
             &wkb,
             &hex::decode("47500003E61000009A9999999999F13F9A9999999999F13F9A9999999999F13F9A9999999999F13F01010000009A9999999999F13F9A9999999999F13F").unwrap()
         );
+        let wkb = geom.to_gpkg_wkb_with_envelope(DIM_XY, Some(4326)).unwrap();
+        assert_eq!(
+            &wkb,
+            &hex::decode("47500003E61000009A9999999999F13F9A9999999999F13F9A9999999999F13F9A9999999999F13F01010000009A9999999999F13F9A9999999999F13F").unwrap()
+        );
 
         let geom: geo_types::Geometry<f64> = geo_types::Point::new(1.1, 1.1).into();
         let wkb = geom
@@ -122,6 +122,55 @@ mod wkb {
     }
 }
 
+pub(crate) mod driver {
+    use crate::error::Result;
+    use crate::registry::trim_start;
+    use crate::wkt::{WktLineReader, WktWriter};
+    use crate::{Driver, DriverOptions, DynDatasource, FeatureProcessor};
+    use std::io::{BufRead, Write};
+
+    pub(crate) const WKT: Driver = Driver {
+        name: "WKT",
+        extensions: &["wkt"],
+        sniff: Some(sniff),
+        open: Some(open),
+        create: Some(create),
+    };
+
+    const KEYWORDS: [&[u8]; 7] = [
+        b"POINT",
+        b"LINESTRING",
+        b"POLYGON",
+        b"MULTIPOINT",
+        b"MULTILINESTRING",
+        b"MULTIPOLYGON",
+        b"GEOMETRYCOLLECTION",
+    ];
+
+    fn sniff(buf: &[u8], _options: &DriverOptions) -> bool {
+        let buf = trim_start(buf);
+        KEYWORDS.iter().any(|keyword| {
+            buf.get(..keyword.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(keyword))
+        })
+    }
+
+    /// One geometry per line
+    fn open<'a>(
+        reader: Box<dyn BufRead + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn DynDatasource + 'a>> {
+        Ok(Box::new(WktLineReader::new(reader)))
+    }
+
+    fn create<'a>(
+        out: Box<dyn Write + 'a>,
+        _options: &DriverOptions,
+    ) -> Result<Box<dyn FeatureProcessor + 'a>> {
+        Ok(Box::new(WktWriter::new(out)))
+    }
+}
+
 /// WKB dialect.
 #[derive(Default, PartialEq, Debug, Clone, Copy)]
 pub enum WktDialect {
@@ -1,7 +1,11 @@
-use crate::error::{GeozeroError, Result};
-use crate::{FeatureProcessor, GeomProcessor, GeozeroDatasource, GeozeroGeometry};
-
-use std::io::Read;
+use crate::error::{ErrorContext, GeozeroError, Result, ResultExt};
+use crate::{
+    FeatureAccess, FeatureIterator, FeatureProcessor, FeatureProperties, GeomProcessor,
+    GeozeroDatasource, GeozeroGeometry, PropertyProcessor,
+};
+
+use std::io::{BufRead, BufReader, Lines, Read};
+use std::str::FromStr;
 use wkt::types::{Coord, LineString, Polygon};
 use wkt::Geometry;
 
@@ -62,6 +66,9 @@ pub struct EwktString(pub String);
 pub struct EwktStr<'a>(pub &'a str);
 
 /// Wkt Reader.
+///
+/// Reads the whole input as a single WKT geometry, which may span multiple lines.
+/// Use [WktLineReader] for reading one geometry per line.
 pub struct WktReader<R: Read>(pub R);
 
 impl<R: Read> GeozeroDatasource for WktReader<R> {
@@ -70,15 +77,124 @@ impl<R: Read> GeozeroDatasource for WktReader<R> {
     }
 }
 
+/// Line delimited WKT Reader: One geometry per line.
+///
+/// Each geometry is processed as a feature without properties. Empty lines are skipped.
+pub struct WktLineReader<R: Read>(pub R);
+
+impl<R: Read> WktLineReader<R> {
+    pub fn new(read: R) -> Self {
+        Self(read)
+    }
+
+    /// Iterate over the geometries, reading one line at a time.
+    pub fn feature_iter(self) -> WktLineFeatureIter<R> {
+        WktLineFeatureIter(BufReader::new(self.0).lines())
+    }
+}
+
+impl<R: Read> GeozeroDatasource for WktLineReader<R> {
+    fn process<P: FeatureProcessor>(&mut self, processor: &mut P) -> Result<()> {
+        read_wkt_lines(&mut self.0, processor)
+    }
+}
+
+/// Iterator over line delimited WKT geometries.
+pub struct WktLineFeatureIter<R: Read>(Lines<BufReader<R>>);
+
+impl<R: Read> FeatureIterator for WktLineFeatureIter<R> {
+    type Feature<'a>
+        = Geometry<f64>
+    where
+        Self: 'a;
+    fn next_feature(&mut self) -> Result<Option<Geometry<f64>>> {
+        for line in self.0.by_ref() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            return parse_wkt(&line).map(Some);
+        }
+        Ok(None)
+    }
+}
+
+impl<R: Read> Iterator for WktLineFeatureIter<R> {
+    type Item = Result<Geometry<f64>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_feature().transpose()
+    }
+}
+
+impl GeozeroGeometry for Geometry<f64> {
+    fn process_geom<P: GeomProcessor>(&self, processor: &mut P) -> Result<()> {
+        process_wkt_geom(self, processor)
+    }
+}
+
+/// WKT geometries are features without properties.
+impl FeatureProperties for Geometry<f64> {
+    fn process_properties<P: PropertyProcessor>(&self, _processor: &mut P) -> Result<bool> {
+        Ok(false)
+    }
+}
+
+impl FeatureAccess for Geometry<f64> {}
+
 /// Read and process WKT geometry.
 pub fn read_wkt<R: Read, P: GeomProcessor>(reader: &mut R, processor: &mut P) -> Result<()> {
-    use std::str::FromStr;
     // PERF: it would be good to avoid copying data into this string when we already
     // have a string as input. Maybe the wkt crate needs a from_reader implementation.
     let mut wkt_string = String::new();
     reader.read_to_string(&mut wkt_string)?;
-    let wkt = wkt::Wkt::from_str(&wkt_string).map_err(|e| GeozeroError::Geometry(e.to_string()))?;
-    process_wkt_geom(&wkt.item, processor)
+    process_wkt_geom(&parse_wkt(&wkt_string)?, processor)
+}
+
+/// Read and process line delimited WKT (one geometry per line).
+pub fn read_wkt_lines(reader: impl Read, processor: &mut impl FeatureProcessor) -> Result<()> {
+    let mut buf_reader = BufReader::new(reader);
+    let mut line = String::new();
+    let mut bytes = 0;
+    let mut idx = 0;
+
+    processor.dataset_begin(None)?;
+    for line_idx in 0.. {
+        line.clear();
+        let len = buf_reader.read_line(&mut line)?;
+        if len == 0 {
+            break;
+        }
+        bytes += len as u64;
+        let context = || {
+            ErrorContext::new()
+                .with_feature(idx)
+                .with_line(line_idx + 1)
+        };
+        processor.bytes_read(bytes, None).with_context(context)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        process_line(processor, idx, &line).with_context(context)?;
+        idx += 1;
+        if processor.done() {
+            break;
+        }
+    }
+    processor.dataset_end()
+}
+
+fn process_line(processor: &mut impl FeatureProcessor, idx: u64, line: &str) -> Result<()> {
+    let geometry = parse_wkt(line)?;
+    processor.feature_begin(idx)?;
+    processor.geometry_begin()?;
+    process_wkt_geom(&geometry, processor)?;
+    processor.geometry_end()?;
+    processor.feature_end(idx)
+}
+
+fn parse_wkt(wkt_str: &str) -> Result<Geometry<f64>> {
+    let wkt = wkt::Wkt::from_str(wkt_str).map_err(|e| GeozeroError::Geometry(e.to_string()))?;
+    Ok(wkt.item)
 }
 
 /// Process WKT geometry
@@ -186,7 +302,8 @@ fn process_polygon<P: GeomProcessor>(
 mod test {
     use super::*;
     use crate::geo_types::conversion::ToGeo;
-    use crate::ToWkt;
+    use crate::wkt::WktWriter;
+    use crate::{RecordedFeature, ToWkt};
     use geo_types::{line_string, point, polygon};
 
     #[test]
@@ -199,6 +316,66 @@ mod test {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn feature_iter() -> Result<()> {
+        let input = "POINT(1.0 2.0)\n\nLINESTRING(1 2,3 4)\n";
+        let mut features = WktLineReader::new(input.as_bytes()).feature_iter();
+        let feature = features.next_feature()?.unwrap();
+        assert_eq!(feature.to_geo()?, point!(x: 1.0, y: 2.0).into());
+        let feature = features.next_feature()?.unwrap();
+        assert_eq!(feature.to_wkt()?, "LINESTRING(1 2,3 4)");
+        assert!(features.next_feature()?.is_none());
+
+        let geometries = WktLineReader::new(input.as_bytes())
+            .feature_iter()
+            .take(1)
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(geometries.len(), 1);
+
+        let mut features = WktLineReader::new("POINT(1.0)".as_bytes()).feature_iter();
+        assert!(features.next_feature().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn line_reader() -> Result<()> {
+        let input = "POINT(1.0 2.0)\n\nLINESTRING(1 2,3 4)\n";
+        let features = RecordedFeature::record_all(&mut WktLineReader::new(input.as_bytes()))?;
+        let wkts = features
+            .iter()
+            .map(|feature| feature.geometry().to_wkt())
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(wkts, ["POINT(1 2)", "LINESTRING(1 2,3 4)"]);
+        // Both APIs read the same features
+        let geometries = WktLineReader::new(input.as_bytes())
+            .feature_iter()
+            .map(|geom| geom?.to_wkt())
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(geometries, wkts);
+
+        let err = RecordedFeature::record_all(&mut WktLineReader::new(
+            "POINT(1 2)\n\nPOINT(1)\n".as_bytes(),
+        ))
+        .unwrap_err();
+        assert_eq!(
+            err.context(),
+            Some(&ErrorContext::new().with_feature(1).with_line(3))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn multi_line_geometry() -> Result<()> {
+        let input = "POLYGON((0 0,\n1 0,\n1 1,0 0))\n";
+        let mut out: Vec<u8> = Vec::new();
+        WktReader(input.as_bytes()).process(&mut WktWriter::new(&mut out))?;
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            "POLYGON((0 0,1 0,1 1,0 0))"
+        );
+        Ok(())
+    }
+
     #[test]
     fn multi_point() {
         // Both of these are failing
@@ -106,3 +106,103 @@ async fn geos_query() -> Result<(), sqlx::Error> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn process_table() -> geozero::error::Result<()> {
+    use geozero::{gpkg, ColumnType, FeatureProperties, FeatureRecorder, GeometryType, ToWkt};
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect("sqlite://tests/data/gpkg_test.gpkg")
+        .await
+        .unwrap();
+
+    let mut recorder = FeatureRecorder::new();
+    gpkg::process_table(&pool, "pt2d", &mut recorder).await?;
+
+    let (columns, geometry_type) = recorder.schema().unwrap();
+    assert_eq!(geometry_type, GeometryType::Point);
+    let columns: Vec<_> = columns
+        .iter()
+        .map(|col| (col.name.as_str(), col.column_type, col.nullable))
+        .collect();
+    assert_eq!(
+        columns,
+        vec![
+            ("id", Some(ColumnType::Long), false),
+            ("name", Some(ColumnType::String), false)
+        ]
+    );
+
+    let features = recorder.features();
+    assert_eq!(features.len(), 4);
+    assert_eq!(features[0].property::<String>("name")?, "alpha");
+    assert_eq!(features[0].to_wkt()?, "POINT(1.1 1.1)");
+    assert_eq!(features[3].property::<String>("name")?, "delta");
+    assert!(features[3].geometry().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[cfg(feature = "with-chrono")]
+async fn process_table_dates() -> geozero::error::Result<()> {
+    use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+    use geozero::{gpkg, ColumnType, FeatureProperties, FeatureRecorder};
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    for sql in [
+        "CREATE TABLE gpkg_geometry_columns (table_name TEXT, column_name TEXT, geometry_type_name TEXT)",
+        "INSERT INTO gpkg_geometry_columns VALUES ('events', 'geom', 'POINT')",
+        "CREATE TABLE events (fid INTEGER PRIMARY KEY, day DATE, updated DATETIME, geom BLOB)",
+        "INSERT INTO events (day, updated) VALUES
+            ('2024-05-17', '2024-05-17T10:30:00.000Z'),
+            ('unknown', '2024-05-17T10:30:00'),
+            (NULL, NULL)",
+    ] {
+        sqlx::query(sql).execute(&pool).await.unwrap();
+    }
+
+    let mut recorder = FeatureRecorder::new();
+    gpkg::process_table(&pool, "events", &mut recorder).await?;
+
+    let (columns, _) = recorder.schema().unwrap();
+    let columns: Vec<_> = columns
+        .iter()
+        .map(|col| (col.name.as_str(), col.column_type))
+        .collect();
+    assert_eq!(
+        columns,
+        vec![
+            ("fid", Some(ColumnType::Long)),
+            ("day", Some(ColumnType::Date)),
+            ("updated", Some(ColumnType::TimestampTz))
+        ]
+    );
+
+    let features = recorder.features();
+    assert_eq!(
+        features[0].property::<NaiveDate>("day")?,
+        NaiveDate::from_ymd_opt(2024, 5, 17).unwrap()
+    );
+    assert_eq!(
+        features[0].property::<chrono::DateTime<Utc>>("updated")?,
+        Utc.with_ymd_and_hms(2024, 5, 17, 10, 30, 0).unwrap()
+    );
+    // Values without time zone or not in ISO 8601 format
+    assert_eq!(
+        features[1].property::<NaiveDateTime>("updated")?,
+        NaiveDate::from_ymd_opt(2024, 5, 17)
+            .unwrap()
+            .and_hms_opt(10, 30, 0)
+            .unwrap()
+    );
+    assert_eq!(features[1].property::<String>("day")?, "unknown");
+    assert_eq!(features[2].property::<Option<NaiveDate>>("day")?, None);
+
+    Ok(())
+}